@@ -1,7 +1,8 @@
 use crate::pixelcolor::{
     raw::{RawData, RawU1},
-    PixelColor,
+    ColorMath, PixelColor,
 };
+use core::ops::{BitAnd, BitOr, BitXor};
 
 /// Binary color.
 ///
@@ -119,6 +120,39 @@ impl PixelColor for BinaryColor {
     type Raw = RawU1;
 }
 
+impl ColorMath for BinaryColor {
+    fn combine_channels<F>(self, other: Self, mut f: F) -> Self
+    where
+        F: FnMut(u8, u8, u8) -> u8,
+    {
+        BinaryColor::from(f(self.map_color(0, 1), other.map_color(0, 1), 1) != 0)
+    }
+}
+
+impl BitAnd for BinaryColor {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        BinaryColor::from(self.is_on() && rhs.is_on())
+    }
+}
+
+impl BitOr for BinaryColor {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        BinaryColor::from(self.is_on() || rhs.is_on())
+    }
+}
+
+impl BitXor for BinaryColor {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        BinaryColor::from(self != rhs)
+    }
+}
+
 impl From<RawU1> for BinaryColor {
     fn from(data: RawU1) -> Self {
         if data.into_inner() != 0 {
@@ -173,6 +207,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn bit_operations() {
+        use BinaryColor::{Off, On};
+
+        assert_eq!(On & On, On);
+        assert_eq!(On & Off, Off);
+        assert_eq!(On | Off, On);
+        assert_eq!(Off | Off, Off);
+        assert_eq!(On ^ Off, On);
+        assert_eq!(On ^ On, Off);
+    }
+
     #[test]
     fn from_data() {
         assert_eq!(BinaryColor::from(RawU1::new(0)), BinaryColor::Off);
@@ -0,0 +1,394 @@
+use crate::pixelcolor::{
+    conversion::convert_channel,
+    interpolation::{linear_to_srgb, SRGB_TO_LINEAR},
+    BinaryColor, Gray2, Gray4, Gray8, GrayColor, IntoStorage, PixelColor, Rgb888, RgbColor,
+};
+
+/// Color conversion strategy.
+///
+/// The `From` and `Into` implementations for the color types in this crate convert colors by
+/// scaling each channel. Types that implement `ColorConversion` provide alternative conversions
+/// from colors of type `I` to colors of type `O`, which can be used directly or by passing them to
+/// [`color_converted_with`] to convert all colors drawn to a draw target.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{
+///     BinaryColor, ColorConversion, Gray8, GrayColor, LumaWeights, Rgb888, RgbColor, Threshold,
+/// };
+///
+/// let gray: Gray8 = LumaWeights::Bt601.convert(Rgb888::GREEN);
+/// assert_eq!(gray.luma(), 150);
+///
+/// let threshold = Threshold::new(128, LumaWeights::Bt709);
+/// assert_eq!(threshold.convert(Rgb888::GREEN), BinaryColor::On);
+/// assert_eq!(threshold.convert(Rgb888::RED), BinaryColor::Off);
+/// ```
+///
+/// [`color_converted_with`]: https://docs.rs/embedded-graphics/latest/embedded_graphics/draw_target/trait.DrawTargetExt.html#tymethod.color_converted_with
+pub trait ColorConversion<I, O> {
+    /// Converts a color.
+    fn convert(&self, color: I) -> O;
+}
+
+/// Functions and closures can be used as color conversions.
+impl<I, O, F> ColorConversion<I, O> for F
+where
+    F: Fn(I) -> O,
+{
+    fn convert(&self, color: I) -> O {
+        self(color)
+    }
+}
+
+/// Default color conversion.
+///
+/// Converts colors by using their `Into` implementation.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct DefaultConversion;
+
+impl<I, O> ColorConversion<I, O> for DefaultConversion
+where
+    I: Into<O>,
+{
+    fn convert(&self, color: I) -> O {
+        color.into()
+    }
+}
+
+/// Luma weights.
+///
+/// Converts RGB colors to grayscale by calculating a weighted sum of the gamma encoded color
+/// channels.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum LumaWeights {
+    /// Weights defined in ITU-R BT.601 (`0.299 R + 0.587 G + 0.114 B`).
+    Bt601,
+
+    /// Weights defined in ITU-R BT.709 (`0.2126 R + 0.7152 G + 0.0722 B`).
+    Bt709,
+}
+
+impl LumaWeights {
+    /// Returns the luma of a color.
+    pub fn luma(self, color: Rgb888) -> u8 {
+        // Weights scaled by 2^16.
+        let (r, g, b) = match self {
+            LumaWeights::Bt601 => (19595, 38470, 7471),
+            LumaWeights::Bt709 => (13933, 46871, 4732),
+        };
+
+        let sum = r * u32::from(color.r()) + g * u32::from(color.g()) + b * u32::from(color.b());
+
+        ((sum + 0x8000) >> 16) as u8
+    }
+}
+
+impl Default for LumaWeights {
+    fn default() -> Self {
+        LumaWeights::Bt709
+    }
+}
+
+/// Gamma correct color conversion.
+///
+/// The sRGB transfer function is taken into account when channels are converted, which keeps the
+/// perceived brightness of colors intact:
+///
+/// * RGB colors are converted to other RGB color types by choosing the channel values with the
+///   nearest linear intensity instead of the nearest gamma encoded value.
+/// * RGB colors are converted to grayscale colors by calculating the relative luminance from the
+///   linear channel intensities.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{
+///     ColorConversion, GammaCorrect, Gray8, GrayColor, Rgb565, Rgb888, RgbColor,
+/// };
+///
+/// let color = Rgb888::new(0, 47, 136);
+///
+/// assert_eq!(Rgb565::from(color), Rgb565::new(0, 12, 17));
+/// let converted: Rgb565 = GammaCorrect.convert(color);
+/// assert_eq!(converted, Rgb565::new(0, 11, 16));
+///
+/// let gray: Gray8 = GammaCorrect.convert(Rgb888::BLUE);
+/// assert_eq!(gray.luma(), 76);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct GammaCorrect;
+
+impl GammaCorrect {
+    /// Returns the gamma encoded relative luminance of a color.
+    pub fn luminance(self, color: Rgb888) -> u8 {
+        let r = i64::from(SRGB_TO_LINEAR[usize::from(color.r())]);
+        let g = i64::from(SRGB_TO_LINEAR[usize::from(color.g())]);
+        let b = i64::from(SRGB_TO_LINEAR[usize::from(color.b())]);
+
+        linear_to_srgb((871 * r + 2929 * g + 296 * b + 2048) / 4096)
+    }
+}
+
+/// Converts a channel value to the value with the nearest linear intensity in a lower bit depth.
+///
+/// The returned value is scaled back to 8 bits.
+fn downscale_channel(value: u8, to_max: u8) -> u8 {
+    let lower_value = (u16::from(value) * u16::from(to_max) / 255) as u8;
+    if lower_value == to_max {
+        return 255;
+    }
+
+    let lower = convert_channel(lower_value, to_max, 255);
+    let upper = convert_channel(lower_value + 1, to_max, 255);
+
+    let target = SRGB_TO_LINEAR[usize::from(value)];
+    let lower_distance = target - SRGB_TO_LINEAR[usize::from(lower)];
+    let upper_distance = SRGB_TO_LINEAR[usize::from(upper)] - target;
+
+    if lower_distance <= upper_distance {
+        lower
+    } else {
+        upper
+    }
+}
+
+impl<I, O> ColorConversion<I, O> for GammaCorrect
+where
+    I: Into<Rgb888>,
+    O: RgbColor + From<Rgb888>,
+{
+    fn convert(&self, color: I) -> O {
+        let color = color.into();
+
+        O::from(Rgb888::new(
+            downscale_channel(color.r(), O::MAX_R),
+            downscale_channel(color.g(), O::MAX_G),
+            downscale_channel(color.b(), O::MAX_B),
+        ))
+    }
+}
+
+/// Threshold conversion to `BinaryColor`.
+///
+/// Colors with a luma greater than or equal to the threshold level are converted to
+/// `BinaryColor::On` and all other colors to `BinaryColor::Off`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Threshold {
+    level: u8,
+    weights: LumaWeights,
+}
+
+impl Threshold {
+    /// Creates a new threshold conversion.
+    pub const fn new(level: u8, weights: LumaWeights) -> Self {
+        Self { level, weights }
+    }
+}
+
+impl Default for Threshold {
+    fn default() -> Self {
+        Self::new(128, LumaWeights::default())
+    }
+}
+
+impl<I> ColorConversion<I, BinaryColor> for Threshold
+where
+    I: Into<Rgb888>,
+{
+    fn convert(&self, color: I) -> BinaryColor {
+        BinaryColor::from(self.weights.luma(color.into()) >= self.level)
+    }
+}
+
+/// Lookup table color conversion.
+///
+/// Converts colors by using their raw storage value as an index into a table of precomputed
+/// colors. Lookup tables can be used with all color types that are stored in up to 8 bits, like
+/// `BinaryColor`, `Gray4` or `Rgb332`.
+///
+/// # Panics
+///
+/// Converting a color panics if its storage value is larger than or equal to the length of the
+/// table. Tables with `2^bpp` entries, for example 16 entries for `Gray4`, can be used to convert
+/// all colors of a type.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{
+///     BinaryColor, ColorConversion, Gray2, LookupTable, Rgb565, RgbColor,
+/// };
+///
+/// // Night mode palette which maps 4 gray levels to shades of red.
+/// const NIGHT_MODE: LookupTable<Rgb565> = LookupTable::new(&[
+///     Rgb565::new(0, 0, 0),
+///     Rgb565::new(10, 0, 0),
+///     Rgb565::new(20, 0, 0),
+///     Rgb565::new(31, 0, 0),
+/// ]);
+///
+/// assert_eq!(NIGHT_MODE.convert(Gray2::new(2)), Rgb565::new(20, 0, 0));
+///
+/// let invert = LookupTable::new(&[BinaryColor::On, BinaryColor::Off]);
+/// assert_eq!(invert.convert(BinaryColor::On), BinaryColor::Off);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct LookupTable<'a, O> {
+    colors: &'a [O],
+}
+
+impl<'a, O> LookupTable<'a, O> {
+    /// Creates a new lookup table.
+    pub const fn new(colors: &'a [O]) -> Self {
+        Self { colors }
+    }
+}
+
+impl<I, O> ColorConversion<I, O> for LookupTable<'_, O>
+where
+    I: PixelColor + IntoStorage<Storage = u8>,
+    O: Copy,
+{
+    fn convert(&self, color: I) -> O {
+        self.colors[usize::from(color.into_storage())]
+    }
+}
+
+/// Macro to implement conversions from RGB to grayscale colors.
+macro_rules! impl_gray_conversion {
+    ($($gray_type:ident),+) => {
+        $(
+            impl<I> ColorConversion<I, $gray_type> for LumaWeights
+            where
+                I: Into<Rgb888>,
+            {
+                fn convert(&self, color: I) -> $gray_type {
+                    let luma = self.luma(color.into());
+
+                    $gray_type::new(convert_channel(luma, 255, $gray_type::WHITE.luma()))
+                }
+            }
+
+            impl<I> ColorConversion<I, $gray_type> for GammaCorrect
+            where
+                I: Into<Rgb888>,
+            {
+                fn convert(&self, color: I) -> $gray_type {
+                    let luma = self.luminance(color.into());
+
+                    $gray_type::new(convert_channel(luma, 255, $gray_type::WHITE.luma()))
+                }
+            }
+        )+
+    };
+}
+
+impl_gray_conversion!(Gray2, Gray4, Gray8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::{Bgr555, Rgb332, Rgb565, WebColors};
+
+    #[test]
+    fn default_conversion() {
+        let color: Rgb888 = DefaultConversion.convert(Rgb565::CYAN);
+        assert_eq!(color, Rgb888::CYAN);
+    }
+
+    #[test]
+    fn closure() {
+        let invert = |color: Rgb888| Rgb888::new(!color.r(), !color.g(), !color.b());
+
+        assert_eq!(invert.convert(Rgb888::CYAN), Rgb888::RED);
+    }
+
+    #[test]
+    fn lookup_table() {
+        let table = LookupTable::new(&[Rgb565::BLACK, Rgb565::RED, Rgb565::GREEN]);
+
+        assert_eq!(table.convert(BinaryColor::Off), Rgb565::BLACK);
+        assert_eq!(table.convert(Gray2::new(2)), Rgb565::GREEN);
+    }
+
+    #[test]
+    #[should_panic]
+    fn lookup_table_out_of_bounds() {
+        let table = LookupTable::new(&[Rgb565::BLACK, Rgb565::RED, Rgb565::GREEN]);
+
+        let _: Rgb565 = table.convert(Gray2::new(3));
+    }
+
+    #[test]
+    fn luma_weights() {
+        assert_eq!(LumaWeights::Bt601.luma(Rgb888::WHITE), 255);
+        assert_eq!(LumaWeights::Bt709.luma(Rgb888::WHITE), 255);
+        assert_eq!(LumaWeights::Bt601.luma(Rgb888::BLACK), 0);
+
+        assert_eq!(LumaWeights::Bt601.luma(Rgb888::RED), 76);
+        assert_eq!(LumaWeights::Bt709.luma(Rgb888::RED), 54);
+        assert_eq!(LumaWeights::Bt601.luma(Rgb888::BLUE), 29);
+        assert_eq!(LumaWeights::Bt709.luma(Rgb888::BLUE), 18);
+    }
+
+    #[test]
+    fn luma_to_gray() {
+        let gray: Gray8 = LumaWeights::Bt709.convert(Rgb888::new(100, 100, 100));
+        assert_eq!(gray, Gray8::new(100));
+
+        let gray: Gray4 = LumaWeights::Bt709.convert(Bgr555::WHITE);
+        assert_eq!(gray, Gray4::WHITE);
+
+        let gray: Gray2 = LumaWeights::Bt601.convert(Rgb888::GREEN);
+        assert_eq!(gray, Gray2::new(2));
+    }
+
+    #[test]
+    fn gamma_correct_luminance() {
+        assert_eq!(GammaCorrect.luminance(Rgb888::WHITE), 255);
+        assert_eq!(GammaCorrect.luminance(Rgb888::BLACK), 0);
+        assert_eq!(GammaCorrect.luminance(Rgb888::CSS_GRAY), 128);
+        assert_eq!(GammaCorrect.luminance(Rgb888::GREEN), 220);
+    }
+
+    #[test]
+    fn gamma_correct_downscaling() {
+        for &color in &[
+            Rgb888::BLACK,
+            Rgb888::WHITE,
+            Rgb888::RED,
+            Rgb888::CYAN,
+            Rgb888::CSS_GRAY,
+        ] {
+            let converted: Rgb565 = GammaCorrect.convert(color);
+            assert_eq!(converted, Rgb565::from(color));
+        }
+
+        let color = Rgb888::new(20, 128, 50);
+        assert_eq!(Rgb332::from(color), Rgb332::new(1, 4, 1));
+
+        let converted: Rgb332 = GammaCorrect.convert(color);
+        assert_eq!(converted, Rgb332::new(0, 3, 0));
+    }
+
+    #[test]
+    fn threshold() {
+        let threshold = Threshold::new(100, LumaWeights::Bt601);
+
+        assert_eq!(threshold.convert(Rgb888::BLACK), BinaryColor::Off);
+        assert_eq!(threshold.convert(Rgb888::WHITE), BinaryColor::On);
+        assert_eq!(threshold.convert(Rgb888::new(99, 99, 99)), BinaryColor::Off);
+        assert_eq!(
+            threshold.convert(Rgb888::new(100, 100, 100)),
+            BinaryColor::On
+        );
+        assert_eq!(threshold.convert(Rgb565::GREEN), BinaryColor::On);
+
+        assert_eq!(
+            Threshold::new(0, LumaWeights::Bt709).convert(Rgb888::BLACK),
+            BinaryColor::On
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use crate::pixelcolor::PixelColor;
+
+/// Color arithmetic.
+///
+/// `ColorMath` provides arithmetic operations which are applied to each channel of a color
+/// independently. It is implemented for all RGB and grayscale color types in this module and for
+/// [`BinaryColor`], which is treated as a color with a single channel with a maximum value of `1`.
+///
+/// All operations are based on the [`combine_channels`] method. The other methods use integer
+/// arithmetic with rounding to the nearest integer and never overflow the valid channel range.
+///
+/// Together with [`BlendMode`] these operations are used by the [`blended`] draw target adapter.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{ColorMath, Gray8, Rgb565, RgbColor};
+///
+/// let color = Rgb565::new(20, 40, 20);
+///
+/// assert_eq!(color.saturating_add(Rgb565::BLUE), Rgb565::new(20, 40, 31));
+/// assert_eq!(color.multiply(Rgb565::RED), Rgb565::new(20, 0, 0));
+/// assert_eq!(Gray8::new(200).scale(128), Gray8::new(100));
+/// assert_eq!(Gray8::new(0).mix(Gray8::new(100), 51), Gray8::new(20));
+/// ```
+///
+/// [`BinaryColor`]: enum.BinaryColor.html
+/// [`combine_channels`]: #tymethod.combine_channels
+/// [`BlendMode`]: enum.BlendMode.html
+/// [`blended`]: https://docs.rs/embedded-graphics/latest/embedded_graphics/draw_target/trait.DrawTargetExt.html#tymethod.blended
+pub trait ColorMath: PixelColor {
+    /// Combines the channels of two colors.
+    ///
+    /// The closure is called once for every channel with the channel values of `self`, `other`
+    /// and the maximum channel value as arguments. The returned value must not be larger than
+    /// the maximum channel value.
+    fn combine_channels<F>(self, other: Self, f: F) -> Self
+    where
+        F: FnMut(u8, u8, u8) -> u8;
+
+    /// Adds two colors and limits the channels to the maximum value.
+    fn saturating_add(self, other: Self) -> Self {
+        self.combine_channels(other, |a, b, max| a.saturating_add(b).min(max))
+    }
+
+    /// Subtracts two colors and limits the channels to zero.
+    fn saturating_sub(self, other: Self) -> Self {
+        self.combine_channels(other, |a, b, _| a.saturating_sub(b))
+    }
+
+    /// Multiplies two colors.
+    ///
+    /// The result is darker than both colors, except if one of the colors is white.
+    fn multiply(self, other: Self) -> Self {
+        self.combine_channels(other, |a, b, max| {
+            div_round(u32::from(a) * u32::from(b), u32::from(max))
+        })
+    }
+
+    /// Multiplies the inverse of two colors.
+    ///
+    /// The result is brighter than both colors, except if one of the colors is black.
+    fn screen(self, other: Self) -> Self {
+        self.combine_channels(other, |a, b, max| {
+            max - div_round(u32::from(max - a) * u32::from(max - b), u32::from(max))
+        })
+    }
+
+    /// Combines the channel values of two colors with a bitwise XOR operation.
+    fn xor(self, other: Self) -> Self {
+        self.combine_channels(other, |a, b, _| a ^ b)
+    }
+
+    /// Scales all channels by `factor / 255`.
+    fn scale(self, factor: u8) -> Self {
+        self.combine_channels(self, |a, _, _| {
+            div_round(u32::from(a) * u32::from(factor), 255)
+        })
+    }
+
+    /// Mixes two colors.
+    ///
+    /// `t` is the weight of the `other` color: `0` returns `self` and `255` returns `other`.
+    fn mix(self, other: Self, t: u8) -> Self {
+        self.combine_channels(other, |a, b, _| {
+            let t = u32::from(t);
+
+            div_round(u32::from(a) * (255 - t) + u32::from(b) * t, 255)
+        })
+    }
+}
+
+/// Divides two integers and rounds the result to the nearest integer.
+fn div_round(numerator: u32, denominator: u32) -> u8 {
+    ((numerator + denominator / 2) / denominator) as u8
+}
+
+/// Blend mode.
+///
+/// Blend modes define how a new color is combined with the existing color of a pixel.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{BlendMode, Rgb888, RgbColor};
+///
+/// let background = Rgb888::new(200, 100, 0);
+///
+/// assert_eq!(BlendMode::Normal.blend(background, Rgb888::BLUE), Rgb888::BLUE);
+/// assert_eq!(
+///     BlendMode::Add.blend(background, Rgb888::new(100, 100, 100)),
+///     Rgb888::new(255, 200, 100)
+/// );
+/// assert_eq!(
+///     BlendMode::Xor.blend(background, Rgb888::WHITE),
+///     Rgb888::new(55, 155, 255)
+/// );
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum BlendMode {
+    /// Replaces the existing color.
+    Normal,
+
+    /// Multiplies the colors, see [`ColorMath::multiply`].
+    ///
+    /// [`ColorMath::multiply`]: trait.ColorMath.html#method.multiply
+    Multiply,
+
+    /// Screens the colors, see [`ColorMath::screen`].
+    ///
+    /// [`ColorMath::screen`]: trait.ColorMath.html#method.screen
+    Screen,
+
+    /// Adds the new color to the existing color.
+    Add,
+
+    /// Subtracts the new color from the existing color.
+    Subtract,
+
+    /// Combines the colors with a bitwise XOR operation.
+    ///
+    /// Drawing the same color twice restores the original color, which makes this mode useful
+    /// to draw cursors.
+    Xor,
+}
+
+impl Default for BlendMode {
+    fn default() -> Self {
+        Self::Normal
+    }
+}
+
+impl BlendMode {
+    /// Blends a new color with the existing color of a pixel.
+    pub fn blend<C: ColorMath>(self, existing: C, new: C) -> C {
+        match self {
+            BlendMode::Normal => new,
+            BlendMode::Multiply => existing.multiply(new),
+            BlendMode::Screen => existing.screen(new),
+            BlendMode::Add => existing.saturating_add(new),
+            BlendMode::Subtract => existing.saturating_sub(new),
+            BlendMode::Xor => existing.xor(new),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::{
+        Bgr565, BinaryColor, Gray2, Gray4, Gray8, GrayColor, Rgb332, Rgb565, Rgb888, RgbColor,
+    };
+
+    #[test]
+    fn saturating_add_sub() {
+        let a = Rgb565::new(10, 50, 31);
+        let b = Rgb565::new(30, 20, 1);
+
+        assert_eq!(a.saturating_add(b), Rgb565::new(31, 63, 31));
+        assert_eq!(a.saturating_sub(b), Rgb565::new(0, 30, 30));
+        assert_eq!(Gray4::new(9).saturating_add(Gray4::new(9)), Gray4::WHITE);
+        assert_eq!(Gray8::new(250).saturating_add(Gray8::new(10)), Gray8::WHITE);
+    }
+
+    #[test]
+    fn multiply_screen() {
+        let a = Rgb888::new(255, 128, 0);
+        let b = Rgb888::new(128, 128, 128);
+
+        assert_eq!(a.multiply(b), Rgb888::new(128, 64, 0));
+        assert_eq!(a.screen(b), Rgb888::new(255, 192, 128));
+
+        assert_eq!(Rgb332::WHITE.multiply(Rgb332::CYAN), Rgb332::CYAN);
+        assert_eq!(Rgb332::BLACK.screen(Rgb332::CYAN), Rgb332::CYAN);
+        assert_eq!(Gray2::new(2).multiply(Gray2::new(2)), Gray2::new(1));
+    }
+
+    #[test]
+    fn xor() {
+        let color = Bgr565::new(1, 2, 3);
+
+        assert_eq!(color.xor(Bgr565::WHITE), Bgr565::new(30, 61, 28));
+        assert_eq!(color.xor(Bgr565::WHITE).xor(Bgr565::WHITE), color);
+    }
+
+    #[test]
+    fn scale_and_mix() {
+        assert_eq!(Rgb888::WHITE.scale(0), Rgb888::BLACK);
+        assert_eq!(Rgb888::WHITE.scale(255), Rgb888::WHITE);
+        assert_eq!(Rgb565::WHITE.scale(128), Rgb565::new(16, 32, 16));
+
+        assert_eq!(Gray8::BLACK.mix(Gray8::WHITE, 0), Gray8::BLACK);
+        assert_eq!(Gray8::BLACK.mix(Gray8::WHITE, 255), Gray8::WHITE);
+        assert_eq!(Rgb888::RED.mix(Rgb888::BLUE, 128), Rgb888::new(127, 0, 128));
+    }
+
+    #[test]
+    fn binary_color() {
+        use BinaryColor::{Off, On};
+
+        assert_eq!(On.saturating_add(On), On);
+        assert_eq!(Off.saturating_add(On), On);
+        assert_eq!(On.saturating_sub(On), Off);
+        assert_eq!(On.saturating_sub(Off), On);
+        assert_eq!(On.multiply(Off), Off);
+        assert_eq!(Off.screen(On), On);
+        assert_eq!(On.xor(On), Off);
+        assert_eq!(On.scale(127), Off);
+        assert_eq!(On.scale(128), On);
+        assert_eq!(Off.mix(On, 200), On);
+    }
+
+    #[test]
+    fn blend_modes() {
+        let existing = Gray8::new(100);
+        let new = Gray8::new(200);
+
+        assert_eq!(BlendMode::Normal.blend(existing, new), new);
+        assert_eq!(BlendMode::Multiply.blend(existing, new), Gray8::new(78));
+        assert_eq!(BlendMode::Screen.blend(existing, new), Gray8::new(222));
+        assert_eq!(BlendMode::Add.blend(existing, new), Gray8::WHITE);
+        assert_eq!(BlendMode::Subtract.blend(existing, new), Gray8::BLACK);
+        assert_eq!(BlendMode::Xor.blend(existing, new).luma(), 100 ^ 200);
+    }
+}
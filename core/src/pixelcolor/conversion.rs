@@ -1,7 +1,7 @@
-use crate::pixelcolor::{binary_color::*, gray_color::*, rgb_color::*};
+use crate::pixelcolor::{binary_color::*, gray_color::*, rgb_color::*, Hsl, Hsv};
 
 /// Convert color channel values from one bit depth to another.
-const fn convert_channel(value: u8, from_max: u8, to_max: u8) -> u8 {
+pub(crate) const fn convert_channel(value: u8, from_max: u8, to_max: u8) -> u8 {
     ((value as u16 * to_max as u16 + from_max as u16 / 2) / from_max as u16) as u8
 }
 
@@ -32,12 +32,54 @@ macro_rules! impl_rgb_conversion {
     };
 }
 
-impl_rgb_conversion!(Rgb555, (Bgr555, Rgb565, Bgr565, Rgb888, Bgr888));
-impl_rgb_conversion!(Bgr555, (Rgb555, Rgb565, Bgr565, Rgb888, Bgr888));
-impl_rgb_conversion!(Rgb565, (Rgb555, Bgr555, Bgr565, Rgb888, Bgr888));
-impl_rgb_conversion!(Bgr565, (Rgb555, Bgr555, Rgb565, Rgb888, Bgr888));
-impl_rgb_conversion!(Rgb888, (Rgb555, Bgr555, Rgb565, Bgr565, Bgr888));
-impl_rgb_conversion!(Bgr888, (Rgb555, Bgr555, Rgb565, Bgr565, Rgb888));
+impl_rgb_conversion!(
+    Rgb332,
+    (Bgr233, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888)
+);
+impl_rgb_conversion!(
+    Bgr233,
+    (Rgb332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888)
+);
+impl_rgb_conversion!(
+    Rgb444,
+    (Rgb332, Bgr233, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888)
+);
+impl_rgb_conversion!(
+    Bgr444,
+    (Rgb332, Bgr233, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888)
+);
+impl_rgb_conversion!(
+    Rgb555,
+    (Rgb332, Bgr233, Rgb444, Bgr444, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888)
+);
+impl_rgb_conversion!(
+    Bgr555,
+    (Rgb332, Bgr233, Rgb444, Bgr444, Rgb555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888)
+);
+impl_rgb_conversion!(
+    Rgb565,
+    (Rgb332, Bgr233, Rgb444, Bgr444, Rgb555, Bgr555, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888)
+);
+impl_rgb_conversion!(
+    Bgr565,
+    (Rgb332, Bgr233, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Rgb666, Bgr666, Rgb888, Bgr888)
+);
+impl_rgb_conversion!(
+    Rgb666,
+    (Rgb332, Bgr233, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Bgr666, Rgb888, Bgr888)
+);
+impl_rgb_conversion!(
+    Bgr666,
+    (Rgb332, Bgr233, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Rgb888, Bgr888)
+);
+impl_rgb_conversion!(
+    Rgb888,
+    (Rgb332, Bgr233, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Bgr888)
+);
+impl_rgb_conversion!(
+    Bgr888,
+    (Rgb332, Bgr233, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888)
+);
 
 /// Macro to implement conversions from `GrayX` to RGB color types.
 macro_rules! impl_from_gray {
@@ -58,7 +100,47 @@ macro_rules! impl_from_gray {
     }
 }
 
-impl_from_gray!(Gray2, Gray4, Gray8 => Rgb555, Bgr555, Rgb565, Bgr565, Rgb888, Bgr888);
+impl_from_gray!(
+    Gray2, Gray4, Gray8 =>
+    Rgb332, Bgr233, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888
+);
+
+/// Macro to implement conversions between `Hsv` and `Hsl` and RGB color types.
+///
+/// The conversions use `Rgb888` as an intermediate format.
+macro_rules! impl_hsx_conversion {
+    ($($type:ident),+) => {
+        $(
+            impl From<Hsv> for $type {
+                fn from(color: Hsv) -> Self {
+                    Rgb888::from(color).into()
+                }
+            }
+
+            impl From<$type> for Hsv {
+                fn from(color: $type) -> Self {
+                    Rgb888::from(color).into()
+                }
+            }
+
+            impl From<Hsl> for $type {
+                fn from(color: Hsl) -> Self {
+                    Rgb888::from(color).into()
+                }
+            }
+
+            impl From<$type> for Hsl {
+                fn from(color: $type) -> Self {
+                    Rgb888::from(color).into()
+                }
+            }
+        )+
+    };
+}
+
+impl_hsx_conversion!(
+    Rgb332, Bgr233, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Bgr888
+);
 
 /// Macro to implement conversion from `BinaryColor` to RGB and grayscale types.
 macro_rules! impl_from_binary {
@@ -72,10 +154,16 @@ macro_rules! impl_from_binary {
     };
 }
 
+impl_from_binary!(Rgb332);
+impl_from_binary!(Bgr233);
+impl_from_binary!(Rgb444);
+impl_from_binary!(Bgr444);
 impl_from_binary!(Rgb555);
 impl_from_binary!(Bgr555);
 impl_from_binary!(Rgb565);
 impl_from_binary!(Bgr565);
+impl_from_binary!(Rgb666);
+impl_from_binary!(Bgr666);
 impl_from_binary!(Rgb888);
 impl_from_binary!(Bgr888);
 impl_from_binary!(Gray2);
@@ -128,16 +216,28 @@ mod tests {
         };
 
         ($from_type:ident) => {
-            test_rgb_conversions!($from_type, (Rgb555, Bgr555, Rgb565, Bgr565, Rgb888, Bgr888));
+            test_rgb_conversions!(
+                $from_type,
+                (
+                    Rgb332, Bgr233, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666,
+                    Rgb888, Bgr888
+                )
+            );
         };
     }
 
     #[test]
     fn rgb_color_conversions() {
+        test_rgb_conversions!(Rgb332);
+        test_rgb_conversions!(Bgr233);
+        test_rgb_conversions!(Rgb444);
+        test_rgb_conversions!(Bgr444);
         test_rgb_conversions!(Rgb555);
         test_rgb_conversions!(Bgr555);
         test_rgb_conversions!(Rgb565);
         test_rgb_conversions!(Bgr565);
+        test_rgb_conversions!(Rgb666);
+        test_rgb_conversions!(Bgr666);
         test_rgb_conversions!(Rgb888);
         test_rgb_conversions!(Bgr888);
     }
@@ -156,14 +256,28 @@ mod tests {
 
     #[test]
     fn rgb_from_gray() {
+        test_rgb_from_gray!(Rgb332);
+        test_rgb_from_gray!(Bgr233);
+        test_rgb_from_gray!(Rgb444);
+        test_rgb_from_gray!(Bgr444);
         test_rgb_from_gray!(Rgb555);
         test_rgb_from_gray!(Bgr555);
         test_rgb_from_gray!(Rgb565);
         test_rgb_from_gray!(Bgr565);
+        test_rgb_from_gray!(Rgb666);
+        test_rgb_from_gray!(Bgr666);
         test_rgb_from_gray!(Rgb888);
         test_rgb_from_gray!(Bgr888);
     }
 
+    #[test]
+    fn hsx_conversions() {
+        assert_eq!(Rgb565::from(Hsv::new(180, 255, 255)), Rgb565::CYAN);
+        assert_eq!(Hsv::from(Bgr555::MAGENTA), Hsv::new(300, 255, 255));
+        assert_eq!(Rgb332::from(Hsl::new(0, 0, 255)), Rgb332::WHITE);
+        assert_eq!(Hsl::from(Rgb444::BLACK), Hsl::new(0, 0, 0));
+    }
+
     macro_rules! test_from_binary {
         ($type:ident) => {
             assert_eq!($type::from(BinaryColor::Off), $type::BLACK);
@@ -173,10 +287,16 @@ mod tests {
 
     #[test]
     pub fn conversion_from_binary_color() {
+        test_from_binary!(Rgb332);
+        test_from_binary!(Bgr233);
+        test_from_binary!(Rgb444);
+        test_from_binary!(Bgr444);
         test_from_binary!(Rgb555);
         test_from_binary!(Bgr555);
         test_from_binary!(Rgb565);
         test_from_binary!(Bgr565);
+        test_from_binary!(Rgb666);
+        test_from_binary!(Bgr666);
         test_from_binary!(Rgb888);
         test_from_binary!(Bgr888);
         test_from_binary!(Gray2);
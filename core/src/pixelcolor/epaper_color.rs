@@ -0,0 +1,365 @@
+use crate::pixelcolor::{
+    raw::{RawData, RawU2, RawU4},
+    BinaryColor, PixelColor, Rgb888, RgbColor,
+};
+
+/// Tri-color e-paper color.
+///
+/// `TriColor` is used for e-paper displays with black, white and red pixels. Most controllers for
+/// these displays store the image in two separate 1 bit per pixel planes: a black/white plane, in
+/// which a set bit represents a white pixel, and a red plane, in which a set bit represents a red
+/// pixel. Red pixels take precedence over the black/white plane. The [`from_planes`] and
+/// [`to_planes`] methods convert between these bits and colors, and [`ImageRawPlanes`] can be
+/// used to draw images stored in this format.
+///
+/// If both planes are packed into one image with 2 bits per pixel, the red bit is stored in the
+/// most significant bit and the black/white bit in the least significant bit. The raw values of
+/// the colors use the same bits as [`to_planes`], which means that red is stored as `0b11`. The
+/// raw value `0b10` is also converted to red.
+///
+/// Other colors can be converted to `TriColor` by converting them into [`Rgb888`] first, which
+/// picks the nearest color of the three available colors.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{Rgb888, TriColor, WebColors};
+///
+/// assert_eq!(TriColor::from(Rgb888::CSS_DARK_RED), TriColor::RED);
+/// assert_eq!(TriColor::from(Rgb888::CSS_LIGHT_GRAY), TriColor::WHITE);
+///
+/// assert_eq!(TriColor::RED.to_planes(), (true, true));
+/// assert_eq!(TriColor::from_planes(false, false), TriColor::BLACK);
+/// ```
+///
+/// [`from_planes`]: #method.from_planes
+/// [`to_planes`]: #method.to_planes
+/// [`ImageRawPlanes`]: ../image/struct.ImageRawPlanes.html
+/// [`Rgb888`]: struct.Rgb888.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct TriColor(u8);
+
+impl TriColor {
+    /// Black.
+    pub const BLACK: Self = Self(0b00);
+
+    /// White.
+    pub const WHITE: Self = Self(0b01);
+
+    /// Red.
+    pub const RED: Self = Self(0b11);
+
+    const PALETTE: [(Self, Rgb888); 3] = [
+        (Self::BLACK, Rgb888::BLACK),
+        (Self::WHITE, Rgb888::WHITE),
+        (Self::RED, Rgb888::RED),
+    ];
+
+    /// Creates a color from the bits in the black/white and the red plane.
+    ///
+    /// A set bit in the black/white plane represents white and a set bit in the red plane
+    /// represents red. If the red bit is set the value of the black/white bit is ignored.
+    pub fn from_planes(black_white: bool, red: bool) -> Self {
+        if red {
+            Self::RED
+        } else if black_white {
+            Self::WHITE
+        } else {
+            Self::BLACK
+        }
+    }
+
+    /// Returns the bits in the black/white and the red plane.
+    ///
+    /// Red pixels are stored as white pixels in the black/white plane, to make sure they don't
+    /// appear black on controllers which ignore the red plane.
+    pub fn to_planes(self) -> (bool, bool) {
+        match self {
+            Self::BLACK => (false, false),
+            Self::RED => (true, true),
+            _ => (true, false),
+        }
+    }
+}
+
+impl Default for TriColor {
+    fn default() -> Self {
+        Self::WHITE
+    }
+}
+
+impl PixelColor for TriColor {
+    type Raw = RawU2;
+}
+
+impl From<RawU2> for TriColor {
+    fn from(data: RawU2) -> Self {
+        let value = data.into_inner();
+
+        Self::from_planes(value & 0b01 != 0, value & 0b10 != 0)
+    }
+}
+
+impl From<TriColor> for RawU2 {
+    fn from(color: TriColor) -> Self {
+        RawU2::new(color.0)
+    }
+}
+
+impl From<BinaryColor> for TriColor {
+    fn from(color: BinaryColor) -> Self {
+        Self::from_planes(color.is_on(), false)
+    }
+}
+
+impl From<Rgb888> for TriColor {
+    fn from(color: Rgb888) -> Self {
+        nearest(color, &Self::PALETTE)
+    }
+}
+
+impl From<TriColor> for Rgb888 {
+    fn from(color: TriColor) -> Self {
+        to_rgb(color, &TriColor::PALETTE)
+    }
+}
+
+/// Seven color e-paper color.
+///
+/// `SevenColor` is used for seven color e-paper displays, like ACeP panels, which can display
+/// black, white, green, blue, red, yellow and orange pixels. The colors are stored with 4 bits
+/// per pixel, using the values expected by common controllers for these displays: black is
+/// stored as `0`, white as `1`, green as `2`, blue as `3`, red as `4`, yellow as `5` and orange
+/// as `6`. This makes it possible to use [`ImageRaw`] to draw images in the controller's native
+/// format. Other raw values, like the "clean" value `7`, are converted to white.
+///
+/// Other colors can be converted to `SevenColor` by converting them into [`Rgb888`] first, which
+/// picks the nearest color of the seven available colors.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{raw::RawU4, Rgb888, SevenColor, WebColors};
+///
+/// assert_eq!(SevenColor::from(Rgb888::CSS_DARK_ORANGE), SevenColor::ORANGE);
+/// assert_eq!(SevenColor::from(Rgb888::CSS_MIDNIGHT_BLUE), SevenColor::BLACK);
+///
+/// assert_eq!(RawU4::from(SevenColor::BLUE), RawU4::new(3));
+/// ```
+///
+/// [`ImageRaw`]: ../image/struct.ImageRaw.html
+/// [`Rgb888`]: struct.Rgb888.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct SevenColor(u8);
+
+impl SevenColor {
+    /// Black.
+    pub const BLACK: Self = Self(0);
+
+    /// White.
+    pub const WHITE: Self = Self(1);
+
+    /// Green.
+    pub const GREEN: Self = Self(2);
+
+    /// Blue.
+    pub const BLUE: Self = Self(3);
+
+    /// Red.
+    pub const RED: Self = Self(4);
+
+    /// Yellow.
+    pub const YELLOW: Self = Self(5);
+
+    /// Orange.
+    pub const ORANGE: Self = Self(6);
+
+    const PALETTE: [(Self, Rgb888); 7] = [
+        (Self::BLACK, Rgb888::BLACK),
+        (Self::WHITE, Rgb888::WHITE),
+        (Self::GREEN, Rgb888::GREEN),
+        (Self::BLUE, Rgb888::BLUE),
+        (Self::RED, Rgb888::RED),
+        (Self::YELLOW, Rgb888::YELLOW),
+        (Self::ORANGE, Rgb888::new(255, 128, 0)),
+    ];
+}
+
+impl Default for SevenColor {
+    fn default() -> Self {
+        Self::WHITE
+    }
+}
+
+impl PixelColor for SevenColor {
+    type Raw = RawU4;
+}
+
+impl From<RawU4> for SevenColor {
+    fn from(data: RawU4) -> Self {
+        let value = data.into_inner();
+
+        if value <= Self::ORANGE.0 {
+            Self(value)
+        } else {
+            Self::WHITE
+        }
+    }
+}
+
+impl From<SevenColor> for RawU4 {
+    fn from(color: SevenColor) -> Self {
+        RawU4::new(color.0)
+    }
+}
+
+impl From<BinaryColor> for SevenColor {
+    fn from(color: BinaryColor) -> Self {
+        if color.is_on() {
+            Self::WHITE
+        } else {
+            Self::BLACK
+        }
+    }
+}
+
+impl From<Rgb888> for SevenColor {
+    fn from(color: Rgb888) -> Self {
+        nearest(color, &Self::PALETTE)
+    }
+}
+
+impl From<SevenColor> for Rgb888 {
+    fn from(color: SevenColor) -> Self {
+        to_rgb(color, &SevenColor::PALETTE)
+    }
+}
+
+/// Returns the palette entry with the smallest euclidean distance to `color`.
+fn nearest<C: Copy>(color: Rgb888, palette: &[(C, Rgb888)]) -> C {
+    let distance = |other: Rgb888| {
+        let dr = i32::from(color.r()) - i32::from(other.r());
+        let dg = i32::from(color.g()) - i32::from(other.g());
+        let db = i32::from(color.b()) - i32::from(other.b());
+
+        dr * dr + dg * dg + db * db
+    };
+
+    palette
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb))
+        .map(|(color, _)| *color)
+        .unwrap()
+}
+
+/// Returns the RGB value of a palette entry.
+fn to_rgb<C: PartialEq>(color: C, palette: &[(C, Rgb888)]) -> Rgb888 {
+    palette
+        .iter()
+        .find(|(entry, _)| *entry == color)
+        .map(|(_, rgb)| *rgb)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::WebColors;
+
+    #[test]
+    fn tri_color_planes() {
+        for &color in &[TriColor::BLACK, TriColor::WHITE, TriColor::RED] {
+            let (black_white, red) = color.to_planes();
+            assert_eq!(TriColor::from_planes(black_white, red), color);
+        }
+
+        assert_eq!(TriColor::from_planes(false, true), TriColor::RED);
+    }
+
+    #[test]
+    fn tri_color_raw() {
+        assert_eq!(TriColor::from(RawU2::new(0b00)), TriColor::BLACK);
+        assert_eq!(TriColor::from(RawU2::new(0b01)), TriColor::WHITE);
+        assert_eq!(TriColor::from(RawU2::new(0b10)), TriColor::RED);
+        assert_eq!(TriColor::from(RawU2::new(0b11)), TriColor::RED);
+
+        assert_eq!(RawU2::from(TriColor::BLACK), RawU2::new(0b00));
+        assert_eq!(RawU2::from(TriColor::WHITE), RawU2::new(0b01));
+        assert_eq!(RawU2::from(TriColor::RED), RawU2::new(0b11));
+    }
+
+    #[test]
+    fn tri_color_raw_matches_planes() {
+        for &color in &[TriColor::BLACK, TriColor::WHITE, TriColor::RED] {
+            let raw = RawU2::from(color).into_inner();
+            let (black_white, red) = color.to_planes();
+
+            assert_eq!(raw & 0b01 != 0, black_white, "{:?}", color);
+            assert_eq!(raw & 0b10 != 0, red, "{:?}", color);
+            assert_eq!(TriColor::from(RawU2::from(color)), color);
+        }
+
+        for value in 0..=0b11 {
+            let color = TriColor::from(RawU2::new(value));
+            let (black_white, red) = color.to_planes();
+
+            assert_eq!(TriColor::from_planes(black_white, red), color);
+            assert_eq!(
+                TriColor::from_planes(value & 0b01 != 0, value & 0b10 != 0),
+                color
+            );
+        }
+    }
+
+    #[test]
+    fn tri_color_from_rgb() {
+        assert_eq!(TriColor::from(Rgb888::BLACK), TriColor::BLACK);
+        assert_eq!(TriColor::from(Rgb888::WHITE), TriColor::WHITE);
+        assert_eq!(TriColor::from(Rgb888::RED), TriColor::RED);
+        assert_eq!(TriColor::from(Rgb888::CSS_NAVY), TriColor::BLACK);
+        assert_eq!(TriColor::from(Rgb888::CSS_ORANGE_RED), TriColor::RED);
+        assert_eq!(TriColor::from(Rgb888::YELLOW), TriColor::WHITE);
+    }
+
+    #[test]
+    fn tri_color_to_rgb() {
+        assert_eq!(Rgb888::from(TriColor::BLACK), Rgb888::BLACK);
+        assert_eq!(Rgb888::from(TriColor::WHITE), Rgb888::WHITE);
+        assert_eq!(Rgb888::from(TriColor::RED), Rgb888::RED);
+    }
+
+    #[test]
+    fn seven_color_raw() {
+        for value in 0..=6 {
+            let color = SevenColor::from(RawU4::new(value));
+            assert_eq!(RawU4::from(color), RawU4::new(value));
+        }
+
+        assert_eq!(SevenColor::from(RawU4::new(7)), SevenColor::WHITE);
+        assert_eq!(SevenColor::from(RawU4::new(15)), SevenColor::WHITE);
+    }
+
+    #[test]
+    fn seven_color_from_rgb() {
+        for &(color, rgb) in SevenColor::PALETTE.iter() {
+            assert_eq!(SevenColor::from(rgb), color);
+            assert_eq!(Rgb888::from(color), rgb);
+        }
+
+        assert_eq!(SevenColor::from(Rgb888::CSS_LIME_GREEN), SevenColor::GREEN);
+        assert_eq!(SevenColor::from(Rgb888::CSS_GOLD), SevenColor::YELLOW);
+        assert_eq!(SevenColor::from(Rgb888::CSS_ROYAL_BLUE), SevenColor::BLUE);
+        assert_eq!(
+            SevenColor::from(Rgb888::CSS_DARK_SLATE_GRAY),
+            SevenColor::BLACK
+        );
+    }
+
+    #[test]
+    fn from_binary() {
+        assert_eq!(TriColor::from(BinaryColor::Off), TriColor::BLACK);
+        assert_eq!(TriColor::from(BinaryColor::On), TriColor::WHITE);
+        assert_eq!(SevenColor::from(BinaryColor::Off), SevenColor::BLACK);
+        assert_eq!(SevenColor::from(BinaryColor::On), SevenColor::WHITE);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::pixelcolor::{
     raw::{RawData, RawU2, RawU4, RawU8},
-    PixelColor,
+    ColorMath, PixelColor,
 };
 
 /// Grayscale color.
@@ -48,6 +48,15 @@ macro_rules! gray_color {
             const WHITE: Self = Self::new(255);
         }
 
+        impl ColorMath for $type {
+            fn combine_channels<F>(self, other: Self, mut f: F) -> Self
+            where
+                F: FnMut(u8, u8, u8) -> u8,
+            {
+                Self::new(f(self.luma(), other.luma(), Self::WHITE.luma()))
+            }
+        }
+
         impl From<$raw_type> for $type {
             fn from(data: $raw_type) -> Self {
                 Self(data)
@@ -0,0 +1,177 @@
+use core::fmt;
+
+/// Creates an RGB color from a hex value or 8 bit channel values.
+///
+/// The macro accepts the color type and either a `0xRRGGBB` hex value or the red, green and blue
+/// channel values in the range `0..=255`. The channels are scaled to the channel widths of the
+/// color type by using the same rounding as the `From` conversions between RGB color types.
+///
+/// The macro can be used in `const` and `static` items for all types that implement
+/// [`RgbColor`].
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::{Bgr888, Rgb565, Rgb888},
+///     rgb,
+/// };
+///
+/// const ACCENT: Rgb565 = rgb!(Rgb565, 0xFF8800);
+/// const BACKGROUND: Bgr888 = rgb!(Bgr888, 16, 32, 48);
+///
+/// assert_eq!(ACCENT, Rgb565::from(Rgb888::new(0xFF, 0x88, 0x00)));
+/// assert_eq!(BACKGROUND, Bgr888::new(16, 32, 48));
+/// ```
+///
+/// [`RgbColor`]: pixelcolor/trait.RgbColor.html
+#[macro_export]
+macro_rules! rgb {
+    ($type:ty, $hex:expr) => {
+        <$type>::from_hex($hex)
+    };
+    ($type:ty, $r:expr, $g:expr, $b:expr) => {
+        <$type>::from_hex($crate::pixelcolor::rgb_to_hex($r, $g, $b))
+    };
+}
+
+/// Error returned when a color can't be parsed from a string.
+///
+/// All RGB color types implement [`FromStr`] to parse colors in the `#RRGGBB` and `#RGB` formats
+/// used by CSS, for example to read colors from theme files. The hex digits are case
+/// insensitive.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{ParseColorError, Rgb565, Rgb888};
+///
+/// assert_eq!("#FF8800".parse(), Ok(Rgb888::new(0xFF, 0x88, 0x00)));
+/// assert_eq!("#f80".parse(), Ok(Rgb888::new(0xFF, 0x88, 0x00)));
+/// assert_eq!("#00f".parse(), Ok(Rgb565::new(0, 0, 31)));
+///
+/// assert_eq!("FF8800".parse::<Rgb888>(), Err(ParseColorError::MissingHash));
+/// assert_eq!("#FF88".parse::<Rgb888>(), Err(ParseColorError::InvalidLength));
+/// assert_eq!("#FF880G".parse::<Rgb888>(), Err(ParseColorError::InvalidDigit));
+/// ```
+///
+/// [`FromStr`]: https://doc.rust-lang.org/core/str/trait.FromStr.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum ParseColorError {
+    /// The string doesn't start with `#`.
+    MissingHash,
+
+    /// The string doesn't contain 3 or 6 hex digits.
+    InvalidLength,
+
+    /// The string contains a character which isn't a hex digit.
+    InvalidDigit,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            ParseColorError::MissingHash => "color doesn't start with '#'",
+            ParseColorError::InvalidLength => "color doesn't contain 3 or 6 hex digits",
+            ParseColorError::InvalidDigit => "color contains an invalid hex digit",
+        };
+
+        f.write_str(message)
+    }
+}
+
+/// Combines 8 bit channel values into a `0xRRGGBB` value.
+///
+/// This function is used by the [`rgb!`] macro and isn't part of the public API.
+///
+/// [`rgb!`]: ../macro.rgb.html
+#[doc(hidden)]
+pub const fn rgb_to_hex(r: u8, g: u8, b: u8) -> u32 {
+    (r as u32) << 16 | (g as u32) << 8 | b as u32
+}
+
+/// Parses a `#RRGGBB` or `#RGB` string into a `0xRRGGBB` value.
+pub(crate) fn parse_hex_color(s: &str) -> Result<u32, ParseColorError> {
+    if !s.starts_with('#') {
+        return Err(ParseColorError::MissingHash);
+    }
+
+    let digits = &s[1..];
+
+    if digits.len() != 3 && digits.len() != 6 {
+        return Err(ParseColorError::InvalidLength);
+    }
+
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(ParseColorError::InvalidDigit);
+    }
+
+    let value = u32::from_str_radix(digits, 16).map_err(|_| ParseColorError::InvalidDigit)?;
+
+    if digits.len() == 6 {
+        Ok(value)
+    } else {
+        // Expand each digit into two digits, e.g. `#F80` into `#FF8800`.
+        let r = (value >> 8) & 0xF;
+        let g = (value >> 4) & 0xF;
+        let b = value & 0xF;
+
+        Ok((r * 0x11) << 16 | (g * 0x11) << 8 | (b * 0x11))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::{Bgr555, Rgb332, Rgb565, Rgb888, RgbColor};
+
+    #[test]
+    fn parse() {
+        assert_eq!(parse_hex_color("#123456"), Ok(0x123456));
+        assert_eq!(parse_hex_color("#abcdef"), Ok(0xABCDEF));
+        assert_eq!(parse_hex_color("#1aF"), Ok(0x11AAFF));
+
+        assert_eq!(parse_hex_color(""), Err(ParseColorError::MissingHash));
+        assert_eq!(parse_hex_color("#"), Err(ParseColorError::InvalidLength));
+        assert_eq!(
+            parse_hex_color("#1234567"),
+            Err(ParseColorError::InvalidLength)
+        );
+        assert_eq!(parse_hex_color("#+12"), Err(ParseColorError::InvalidDigit));
+        assert_eq!(
+            parse_hex_color("#12 456"),
+            Err(ParseColorError::InvalidDigit)
+        );
+        assert_eq!(parse_hex_color("#ü12"), Err(ParseColorError::InvalidLength));
+    }
+
+    #[test]
+    fn from_hex_matches_conversion() {
+        for &hex in &[0x000000, 0xFFFFFF, 0xFF8800, 0x123456, 0x7F7F7F, 0x808080] {
+            let rgb888 = Rgb888::new((hex >> 16) as u8, (hex >> 8) as u8, hex as u8);
+
+            assert_eq!(Rgb888::from_hex(hex), rgb888);
+            assert_eq!(Rgb565::from_hex(hex), Rgb565::from(rgb888));
+            assert_eq!(Bgr555::from_hex(hex), Bgr555::from(rgb888));
+            assert_eq!(Rgb332::from_hex(hex), Rgb332::from(rgb888));
+        }
+    }
+
+    #[test]
+    fn const_construction() {
+        const ORANGE: Rgb565 = Rgb565::from_hex(0xFF8800);
+        const GRAY: Rgb332 = rgb!(Rgb332, 128, 128, 128);
+        const BLUE: Bgr555 = rgb!(Bgr555, 0x0000FF);
+
+        assert_eq!(ORANGE, Rgb565::new(31, 34, 0));
+        assert_eq!(GRAY, Rgb332::new(4, 4, 2));
+        assert_eq!(BLUE, Bgr555::BLUE);
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("#FF0000".parse(), Ok(Rgb565::RED));
+        assert_eq!("#0f0".parse(), Ok(Rgb888::GREEN));
+        assert_eq!("red".parse::<Rgb888>(), Err(ParseColorError::MissingHash));
+    }
+}
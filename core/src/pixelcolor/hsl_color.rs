@@ -0,0 +1,184 @@
+use crate::pixelcolor::{
+    hsv_color::{div_round, from_hue, hue},
+    Hsv, Rgb888, RgbColor,
+};
+
+/// HSL color.
+///
+/// `Hsl` stores a color as hue, saturation and lightness. Like [`Hsv`] it isn't a
+/// [`PixelColor`], but it can be converted to and from [`Rgb888`], all other RGB color types and
+/// [`Hsv`].
+///
+/// All conversions use integer arithmetic. Because of the limited precision of the channels,
+/// converting a color to `Hsl` and back again may not return exactly the same color.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{Hsl, Rgb888, RgbColor};
+///
+/// let navy = Hsl::new(240, 255, 64);
+///
+/// assert_eq!(Rgb888::from(navy), Rgb888::new(0, 0, 128));
+/// assert_eq!(Hsl::from(Rgb888::new(0, 0, 128)), navy);
+///
+/// // Increasing the lightness mixes the color with white.
+/// assert_eq!(Rgb888::from(Hsl::new(240, 255, 192)), Rgb888::new(129, 129, 255));
+/// ```
+///
+/// [`Hsv`]: struct.Hsv.html
+/// [`PixelColor`]: trait.PixelColor.html
+/// [`Rgb888`]: struct.Rgb888.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Hsl {
+    hue: u16,
+    saturation: u8,
+    lightness: u8,
+}
+
+impl Hsl {
+    /// Creates a new HSL color.
+    ///
+    /// The `hue` is specified in degrees and values outside the range `0..360` are wrapped
+    /// into this range. `saturation` and `lightness` range from 0 to 255.
+    pub const fn new(hue: u16, saturation: u8, lightness: u8) -> Self {
+        Self {
+            hue: hue % 360,
+            saturation,
+            lightness,
+        }
+    }
+
+    /// Returns the hue in degrees.
+    pub const fn hue(self) -> u16 {
+        self.hue
+    }
+
+    /// Returns the saturation.
+    pub const fn saturation(self) -> u8 {
+        self.saturation
+    }
+
+    /// Returns the lightness.
+    pub const fn lightness(self) -> u8 {
+        self.lightness
+    }
+}
+
+impl From<Rgb888> for Hsl {
+    fn from(color: Rgb888) -> Self {
+        let max = u32::from(color.r().max(color.g()).max(color.b()));
+        let min = u32::from(color.r().min(color.g()).min(color.b()));
+
+        let sum = max + min;
+        let lightness = div_round(sum, 2) as u8;
+
+        // The chroma is divided by `1 - |2 * lightness - 1|`, which is `0` for black and white.
+        let divisor = if sum > 255 { 510 - sum } else { sum };
+        let saturation = if divisor == 0 {
+            0
+        } else {
+            div_round((max - min) * 255, divisor).min(255) as u8
+        };
+
+        Self::new(hue(color), saturation, lightness)
+    }
+}
+
+impl From<Hsl> for Hsv {
+    fn from(color: Hsl) -> Self {
+        let lightness = u32::from(color.lightness);
+        let limit = lightness.min(255 - lightness);
+
+        let value = lightness + div_round(u32::from(color.saturation) * limit, 255);
+
+        let saturation = if value == 0 {
+            0
+        } else {
+            div_round(2 * (value - lightness) * 255, value) as u8
+        };
+
+        Hsv::new(color.hue, saturation, value as u8)
+    }
+}
+
+impl From<Hsv> for Hsl {
+    fn from(color: Hsv) -> Self {
+        let value = u32::from(color.value());
+
+        // Lightness = value * (1 - saturation / 2)
+        let lightness = div_round(value * (510 - u32::from(color.saturation())), 510);
+
+        let limit = lightness.min(255 - lightness);
+        let saturation = if limit == 0 {
+            0
+        } else {
+            div_round((value - lightness) * 255, limit).min(255) as u8
+        };
+
+        Hsl::new(color.hue(), saturation, lightness as u8)
+    }
+}
+
+impl From<Hsl> for Rgb888 {
+    fn from(color: Hsl) -> Self {
+        let lightness = u32::from(color.lightness);
+        let limit = lightness.min(255 - lightness);
+
+        let max = lightness + div_round(u32::from(color.saturation) * limit, 255);
+        let min = (2 * lightness).saturating_sub(max);
+
+        from_hue(color.hue, max as u8, min as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::WebColors;
+
+    #[test]
+    fn from_rgb() {
+        assert_eq!(Hsl::from(Rgb888::BLACK), Hsl::new(0, 0, 0));
+        assert_eq!(Hsl::from(Rgb888::WHITE), Hsl::new(0, 0, 255));
+        assert_eq!(Hsl::from(Rgb888::new(128, 128, 128)), Hsl::new(0, 0, 128));
+        assert_eq!(Hsl::from(Rgb888::GREEN), Hsl::new(120, 255, 128));
+        assert_eq!(Hsl::from(Rgb888::CSS_NAVY), Hsl::new(240, 255, 64));
+        assert_eq!(Hsl::from(Rgb888::new(255, 128, 128)), Hsl::new(0, 255, 192));
+    }
+
+    #[test]
+    fn to_rgb() {
+        assert_eq!(Rgb888::from(Hsl::new(0, 0, 0)), Rgb888::BLACK);
+        assert_eq!(Rgb888::from(Hsl::new(50, 255, 255)), Rgb888::WHITE);
+        assert_eq!(
+            Rgb888::from(Hsl::new(120, 0, 128)),
+            Rgb888::new(128, 128, 128)
+        );
+        assert_eq!(
+            Rgb888::from(Hsl::new(120, 255, 128)),
+            Rgb888::new(1, 255, 1)
+        );
+        assert_eq!(
+            Rgb888::from(Hsl::new(120, 255, 127)),
+            Rgb888::new(0, 254, 0)
+        );
+        assert_eq!(Rgb888::from(Hsl::new(240, 255, 64)), Rgb888::new(0, 0, 128));
+    }
+
+    #[test]
+    fn hsv_round_trip() {
+        for &color in &[
+            Hsv::new(0, 255, 255),
+            Hsv::new(90, 128, 200),
+            Hsv::new(200, 0, 100),
+            Hsv::new(300, 40, 230),
+        ] {
+            let converted = Hsv::from(Hsl::from(color));
+
+            assert_eq!(converted.hue(), color.hue());
+            assert!((i16::from(converted.saturation()) - i16::from(color.saturation())).abs() <= 2);
+            assert!((i16::from(converted.value()) - i16::from(color.value())).abs() <= 1);
+        }
+    }
+}
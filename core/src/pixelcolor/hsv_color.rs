@@ -0,0 +1,198 @@
+use crate::pixelcolor::{Rgb888, RgbColor};
+
+/// HSV color.
+///
+/// `Hsv` stores a color as hue, saturation and value. It isn't a [`PixelColor`] and can't be
+/// drawn directly, but it can be converted to and from [`Rgb888`] and all other RGB color types
+/// to generate colors, for example by changing the hue to cycle through the color wheel.
+///
+/// All conversions use integer arithmetic. Because of the limited precision of the channels,
+/// converting a color to `Hsv` and back again may not return exactly the same color.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{Hsv, Rgb565, Rgb888, RgbColor};
+///
+/// let yellow = Hsv::new(60, 255, 255);
+///
+/// assert_eq!(Rgb888::from(yellow), Rgb888::YELLOW);
+/// assert_eq!(Rgb565::from(yellow), Rgb565::YELLOW);
+/// assert_eq!(Hsv::from(Rgb888::YELLOW), yellow);
+/// ```
+///
+/// [`PixelColor`]: trait.PixelColor.html
+/// [`Rgb888`]: struct.Rgb888.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Hsv {
+    hue: u16,
+    saturation: u8,
+    value: u8,
+}
+
+impl Hsv {
+    /// Creates a new HSV color.
+    ///
+    /// The `hue` is specified in degrees and values outside the range `0..360` are wrapped
+    /// into this range. `saturation` and `value` range from 0 to 255.
+    pub const fn new(hue: u16, saturation: u8, value: u8) -> Self {
+        Self {
+            hue: hue % 360,
+            saturation,
+            value,
+        }
+    }
+
+    /// Returns the hue in degrees.
+    pub const fn hue(self) -> u16 {
+        self.hue
+    }
+
+    /// Returns the saturation.
+    pub const fn saturation(self) -> u8 {
+        self.saturation
+    }
+
+    /// Returns the value.
+    pub const fn value(self) -> u8 {
+        self.value
+    }
+}
+
+/// Divides two positive integers and rounds the result to the nearest integer.
+pub(crate) fn div_round(numerator: u32, denominator: u32) -> u32 {
+    (numerator + denominator / 2) / denominator
+}
+
+/// Calculates the hue in degrees of a RGB color.
+///
+/// Returns 0 for shades of gray.
+pub(crate) fn hue(color: Rgb888) -> u16 {
+    let (r, g, b) = (
+        i32::from(color.r()),
+        i32::from(color.g()),
+        i32::from(color.b()),
+    );
+
+    let max = r.max(g).max(b);
+    let delta = max - r.min(g).min(b);
+
+    if delta == 0 {
+        return 0;
+    }
+
+    let (offset, difference) = if max == r {
+        (0, g - b)
+    } else if max == g {
+        (120, b - r)
+    } else {
+        (240, r - g)
+    };
+
+    // Division with rounding towards the nearest integer for positive and negative values.
+    let sector = (difference * 120 + difference.signum() * delta) / (2 * delta);
+
+    ((offset + sector + 360) % 360) as u16
+}
+
+impl From<Rgb888> for Hsv {
+    fn from(color: Rgb888) -> Self {
+        let max = color.r().max(color.g()).max(color.b());
+        let min = color.r().min(color.g()).min(color.b());
+
+        let saturation = if max == 0 {
+            0
+        } else {
+            div_round(u32::from(max - min) * 255, u32::from(max)) as u8
+        };
+
+        Self::new(hue(color), saturation, max)
+    }
+}
+
+/// Creates a RGB color from a hue and the maximum and minimum channel values.
+pub(crate) fn from_hue(hue: u16, max: u8, min: u8) -> Rgb888 {
+    let sector = hue / 60;
+    let fraction = u32::from(hue % 60);
+    let chroma = u32::from(max - min);
+
+    let rising = min + div_round(chroma * fraction, 60) as u8;
+    let falling = max - div_round(chroma * fraction, 60) as u8;
+
+    match sector {
+        0 => Rgb888::new(max, rising, min),
+        1 => Rgb888::new(falling, max, min),
+        2 => Rgb888::new(min, max, rising),
+        3 => Rgb888::new(min, falling, max),
+        4 => Rgb888::new(rising, min, max),
+        _ => Rgb888::new(max, min, falling),
+    }
+}
+
+impl From<Hsv> for Rgb888 {
+    fn from(color: Hsv) -> Self {
+        let value = u32::from(color.value);
+        let min = value - div_round(value * u32::from(color.saturation), 255);
+
+        from_hue(color.hue, color.value, min as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::WebColors;
+
+    #[test]
+    fn new_wraps_hue() {
+        assert_eq!(Hsv::new(360, 1, 2), Hsv::new(0, 1, 2));
+        assert_eq!(Hsv::new(725, 1, 2).hue(), 5);
+    }
+
+    #[test]
+    fn from_rgb() {
+        assert_eq!(Hsv::from(Rgb888::BLACK), Hsv::new(0, 0, 0));
+        assert_eq!(Hsv::from(Rgb888::WHITE), Hsv::new(0, 0, 255));
+        assert_eq!(Hsv::from(Rgb888::RED), Hsv::new(0, 255, 255));
+        assert_eq!(Hsv::from(Rgb888::GREEN), Hsv::new(120, 255, 255));
+        assert_eq!(Hsv::from(Rgb888::BLUE), Hsv::new(240, 255, 255));
+        assert_eq!(Hsv::from(Rgb888::YELLOW), Hsv::new(60, 255, 255));
+        assert_eq!(Hsv::from(Rgb888::CYAN), Hsv::new(180, 255, 255));
+        assert_eq!(Hsv::from(Rgb888::MAGENTA), Hsv::new(300, 255, 255));
+
+        // 24°, 100%, 100%
+        assert_eq!(Hsv::from(Rgb888::new(255, 102, 0)), Hsv::new(24, 255, 255));
+        // 330°, 59%, 100%
+        assert_eq!(Hsv::from(Rgb888::CSS_HOT_PINK), Hsv::new(330, 150, 255));
+    }
+
+    #[test]
+    fn to_rgb() {
+        assert_eq!(Rgb888::from(Hsv::new(0, 0, 0)), Rgb888::BLACK);
+        assert_eq!(Rgb888::from(Hsv::new(123, 0, 255)), Rgb888::WHITE);
+        assert_eq!(Rgb888::from(Hsv::new(0, 255, 255)), Rgb888::RED);
+        assert_eq!(Rgb888::from(Hsv::new(120, 255, 255)), Rgb888::GREEN);
+        assert_eq!(Rgb888::from(Hsv::new(240, 255, 255)), Rgb888::BLUE);
+        assert_eq!(
+            Rgb888::from(Hsv::new(24, 255, 255)),
+            Rgb888::new(255, 102, 0)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for &color in &[
+            Rgb888::CSS_HOT_PINK,
+            Rgb888::CSS_ORANGE,
+            Rgb888::CSS_STEEL_BLUE,
+            Rgb888::CSS_OLIVE,
+            Rgb888::CSS_DARK_SLATE_GRAY,
+        ] {
+            let converted = Rgb888::from(Hsv::from(color));
+
+            assert!((i16::from(converted.r()) - i16::from(color.r())).abs() <= 2);
+            assert!((i16::from(converted.g()) - i16::from(color.g())).abs() <= 2);
+            assert!((i16::from(converted.b()) - i16::from(color.b())).abs() <= 2);
+        }
+    }
+}
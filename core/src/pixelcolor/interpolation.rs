@@ -0,0 +1,355 @@
+use crate::pixelcolor::{Hsv, Rgb888, RgbColor};
+
+/// Fixed point representation of `1.0` used for linear RGB, XYZ and Lab values.
+const ONE: i64 = 0xFFFF;
+
+/// Conversion table from sRGB channel values to linear intensities.
+///
+/// The linear values are scaled by `ONE`.
+#[rustfmt::skip]
+pub(crate) const SRGB_TO_LINEAR: [u16; 256] = [
+    0, 20, 40, 60, 80, 99, 119, 139, 159, 179, 199, 219,
+    241, 264, 288, 313, 340, 367, 396, 427, 458, 491, 526, 562,
+    599, 637, 677, 718, 761, 805, 851, 898, 947, 997, 1048, 1101,
+    1156, 1212, 1270, 1330, 1391, 1453, 1517, 1583, 1651, 1720, 1790, 1863,
+    1937, 2013, 2090, 2170, 2250, 2333, 2418, 2504, 2592, 2681, 2773, 2866,
+    2961, 3058, 3157, 3258, 3360, 3464, 3570, 3678, 3788, 3900, 4014, 4129,
+    4247, 4366, 4488, 4611, 4736, 4864, 4993, 5124, 5257, 5392, 5530, 5669,
+    5810, 5953, 6099, 6246, 6395, 6547, 6700, 6856, 7014, 7174, 7335, 7500,
+    7666, 7834, 8004, 8177, 8352, 8528, 8708, 8889, 9072, 9258, 9445, 9635,
+    9828, 10022, 10219, 10417, 10619, 10822, 11028, 11235, 11446, 11658, 11873, 12090,
+    12309, 12530, 12754, 12980, 13209, 13440, 13673, 13909, 14146, 14387, 14629, 14874,
+    15122, 15371, 15623, 15878, 16135, 16394, 16656, 16920, 17187, 17456, 17727, 18001,
+    18277, 18556, 18837, 19121, 19407, 19696, 19987, 20281, 20577, 20876, 21177, 21481,
+    21787, 22096, 22407, 22721, 23038, 23357, 23678, 24002, 24329, 24658, 24990, 25325,
+    25662, 26001, 26344, 26688, 27036, 27386, 27739, 28094, 28452, 28813, 29176, 29542,
+    29911, 30282, 30656, 31033, 31412, 31794, 32179, 32567, 32957, 33350, 33745, 34143,
+    34544, 34948, 35355, 35764, 36176, 36591, 37008, 37429, 37852, 38278, 38706, 39138,
+    39572, 40009, 40449, 40891, 41337, 41785, 42236, 42690, 43147, 43606, 44069, 44534,
+    45002, 45473, 45947, 46423, 46903, 47385, 47871, 48359, 48850, 49344, 49841, 50341,
+    50844, 51349, 51858, 52369, 52884, 53401, 53921, 54445, 54971, 55500, 56032, 56567,
+    57105, 57646, 58190, 58737, 59287, 59840, 60396, 60955, 61517, 62082, 62650, 63221,
+    63795, 64372, 64952, 65535,
+];
+
+/// Interpolates between two colors in RGB space.
+///
+/// `t` selects the position between both colors, with `0` returning `from` and `255`
+/// returning `to`. Each channel is interpolated linearly, which is fast but can result in dull
+/// intermediate colors.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{lerp_rgb, Rgb565, RgbColor};
+///
+/// assert_eq!(lerp_rgb(Rgb565::BLACK, Rgb565::WHITE, 0), Rgb565::BLACK);
+/// assert_eq!(lerp_rgb(Rgb565::BLACK, Rgb565::WHITE, 255), Rgb565::WHITE);
+/// assert_eq!(lerp_rgb(Rgb565::BLACK, Rgb565::WHITE, 128), Rgb565::new(16, 32, 16));
+/// ```
+pub fn lerp_rgb<C>(from: C, to: C, t: u8) -> C
+where
+    C: RgbColor + Into<Rgb888> + From<Rgb888>,
+{
+    let from = from.into();
+    let to = to.into();
+
+    let channel = |from: u8, to: u8| mix(i64::from(from), i64::from(to), t) as u8;
+
+    Rgb888::new(
+        channel(from.r(), to.r()),
+        channel(from.g(), to.g()),
+        channel(from.b(), to.b()),
+    )
+    .into()
+}
+
+/// Interpolates between two colors in HSV space.
+///
+/// `t` selects the position between both colors, with `0` returning `from` and `255`
+/// returning `to`. The hue is interpolated along the shorter arc of the color wheel and
+/// saturation and value are interpolated linearly. If one of the colors is a shade of gray its
+/// hue is ignored.
+///
+/// # Examples
+///
+/// Status colors which change from green over yellow to red:
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{lerp_hsv, Rgb888, RgbColor};
+///
+/// let ok = Rgb888::GREEN;
+/// let error = Rgb888::RED;
+///
+/// assert_eq!(lerp_hsv(ok, error, 0), Rgb888::GREEN);
+/// assert_eq!(lerp_hsv(ok, error, 128), Rgb888::YELLOW);
+/// assert_eq!(lerp_hsv(ok, error, 255), Rgb888::RED);
+/// ```
+pub fn lerp_hsv<C>(from: C, to: C, t: u8) -> C
+where
+    C: RgbColor + Into<Rgb888> + From<Rgb888>,
+{
+    let from = Hsv::from(from.into());
+    let to = Hsv::from(to.into());
+
+    let from_hue = i64::from(from.hue());
+    let to_hue = i64::from(to.hue());
+
+    let hue = if from.saturation() == 0 {
+        to_hue
+    } else if to.saturation() == 0 {
+        from_hue
+    } else {
+        let delta = (to_hue - from_hue + 540) % 360 - 180;
+
+        (from_hue + mix(0, delta, t) + 360) % 360
+    };
+
+    let saturation = mix(i64::from(from.saturation()), i64::from(to.saturation()), t);
+    let value = mix(i64::from(from.value()), i64::from(to.value()), t);
+
+    Rgb888::from(Hsv::new(hue as u16, saturation as u8, value as u8)).into()
+}
+
+/// Interpolates between two colors in CIE L\*a\*b\* space.
+///
+/// `t` selects the position between both colors, with `0` returning `from` and `255`
+/// returning `to`. Interpolating in Lab space results in perceptually uniform gradients, but is
+/// slower than [`lerp_rgb`] and [`lerp_hsv`]. The conversion uses fixed point arithmetic, a D65
+/// white point and a lookup table for the sRGB transfer function.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{lerp_lab, Rgb888, RgbColor};
+///
+/// // The midpoint between black and white has a lightness of 50%.
+/// assert_eq!(
+///     lerp_lab(Rgb888::BLACK, Rgb888::WHITE, 128),
+///     Rgb888::new(119, 119, 119)
+/// );
+/// ```
+///
+/// [`lerp_rgb`]: fn.lerp_rgb.html
+/// [`lerp_hsv`]: fn.lerp_hsv.html
+pub fn lerp_lab<C>(from: C, to: C, t: u8) -> C
+where
+    C: RgbColor + Into<Rgb888> + From<Rgb888>,
+{
+    let from = Lab::from_rgb(from.into());
+    let to = Lab::from_rgb(to.into());
+
+    Lab {
+        l: mix(from.l, to.l, t),
+        a: mix(from.a, to.a, t),
+        b: mix(from.b, to.b, t),
+    }
+    .to_rgb()
+    .into()
+}
+
+/// Interpolates linearly between two values and rounds to the nearest integer.
+fn mix(from: i64, to: i64, t: u8) -> i64 {
+    let t = i64::from(t);
+
+    (from * (255 - t) + to * t + 127).div_euclid(255)
+}
+
+/// CIE L\*a\*b\* color with fixed point components.
+///
+/// All components are scaled by `ONE`, which means that `l` ranges from `0` to `100 * ONE`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+struct Lab {
+    l: i64,
+    a: i64,
+    b: i64,
+}
+
+impl Lab {
+    fn from_rgb(color: Rgb888) -> Self {
+        let r = i64::from(SRGB_TO_LINEAR[usize::from(color.r())]);
+        let g = i64::from(SRGB_TO_LINEAR[usize::from(color.g())]);
+        let b = i64::from(SRGB_TO_LINEAR[usize::from(color.b())]);
+
+        // XYZ values relative to the D65 white point.
+        let x = (1777 * r + 1541 * g + 778 * b + 2048) / 4096;
+        let y = (871 * r + 2929 * g + 296 * b + 2048) / 4096;
+        let z = (73 * r + 448 * g + 3575 * b + 2048) / 4096;
+
+        let (fx, fy, fz) = (lab_f(x), lab_f(y), lab_f(z));
+
+        Self {
+            l: 116 * fy - 16 * ONE,
+            a: 500 * (fx - fy),
+            b: 200 * (fy - fz),
+        }
+    }
+
+    fn to_rgb(self) -> Rgb888 {
+        let fy = (self.l + 16 * ONE).div_euclid(116);
+        let fx = fy + self.a.div_euclid(500);
+        let fz = fy - self.b.div_euclid(200);
+
+        let (x, y, z) = (lab_f_inverse(fx), lab_f_inverse(fy), lab_f_inverse(fz));
+
+        let r = (12615 * x - 6296 * y - 2223 * z + 2048).div_euclid(4096);
+        let g = (-3773 * x + 7684 * y + 185 * z + 2048).div_euclid(4096);
+        let b = (217 * x - 836 * y + 4715 * z + 2048).div_euclid(4096);
+
+        Rgb888::new(linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b))
+    }
+}
+
+/// The nonlinear function used by the XYZ to Lab conversion.
+fn lab_f(t: i64) -> i64 {
+    // t > (6/29)^3
+    if t * 24389 > 216 * ONE {
+        cube_root(t * ONE * ONE)
+    } else {
+        // t / (3 * (6/29)^2) + 4/29
+        (t * 24389 + 432 * ONE) / 3132
+    }
+}
+
+/// The inverse of `lab_f`.
+fn lab_f_inverse(f: i64) -> i64 {
+    // f > 6/29
+    if f * 29 > 6 * ONE {
+        f * f / ONE * f / ONE
+    } else {
+        // 3 * (6/29)^2 * (f - 4/29)
+        (f * 29 - 4 * ONE) * 108 / 24389
+    }
+}
+
+/// Returns the integer cube root of a value between `0` and `ONE^3`.
+fn cube_root(value: i64) -> i64 {
+    let mut low = 0;
+    let mut high = ONE;
+
+    while low < high {
+        let mid = (low + high + 1) / 2;
+
+        if mid * mid * mid <= value {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    low
+}
+
+/// Converts a linear intensity into the nearest sRGB channel value.
+pub(crate) fn linear_to_srgb(value: i64) -> u8 {
+    if value <= 0 {
+        return 0;
+    } else if value >= ONE {
+        return 255;
+    }
+
+    let value = value as u16;
+
+    match SRGB_TO_LINEAR.binary_search(&value) {
+        Ok(index) => index as u8,
+        Err(index) => {
+            // `index` is always greater than 0, because SRGB_TO_LINEAR[0] is 0.
+            if value - SRGB_TO_LINEAR[index - 1] <= SRGB_TO_LINEAR[index] - value {
+                (index - 1) as u8
+            } else {
+                index as u8
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::{Rgb565, WebColors};
+
+    #[test]
+    fn rgb_endpoints() {
+        let from = Rgb888::CSS_HOT_PINK;
+        let to = Rgb888::CSS_STEEL_BLUE;
+
+        assert_eq!(lerp_rgb(from, to, 0), from);
+        assert_eq!(lerp_rgb(from, to, 255), to);
+        assert_eq!(
+            lerp_rgb(Rgb888::RED, Rgb888::BLUE, 128),
+            Rgb888::new(127, 0, 128)
+        );
+    }
+
+    #[test]
+    fn hsv_shorter_arc() {
+        // Magenta (300°) to yellow (60°) passes through red (0°).
+        assert_eq!(
+            lerp_hsv(Rgb888::MAGENTA, Rgb888::YELLOW, 128),
+            Rgb888::new(255, 0, 0)
+        );
+        assert_eq!(
+            lerp_hsv(Rgb888::YELLOW, Rgb888::MAGENTA, 128),
+            Rgb888::new(255, 0, 0)
+        );
+    }
+
+    #[test]
+    fn hsv_gray_uses_other_hue() {
+        assert_eq!(
+            lerp_hsv(Rgb888::BLACK, Rgb888::BLUE, 128),
+            Rgb888::new(64, 64, 128)
+        );
+        assert_eq!(
+            lerp_hsv(Rgb888::WHITE, Rgb888::GREEN, 128),
+            Rgb888::new(127, 255, 127)
+        );
+    }
+
+    #[test]
+    fn lab_round_trip() {
+        for &color in &[
+            Rgb888::BLACK,
+            Rgb888::WHITE,
+            Rgb888::RED,
+            Rgb888::GREEN,
+            Rgb888::BLUE,
+            Rgb888::CSS_HOT_PINK,
+            Rgb888::CSS_STEEL_BLUE,
+            Rgb888::CSS_OLIVE,
+            Rgb888::new(1, 2, 3),
+        ] {
+            let converted = Lab::from_rgb(color).to_rgb();
+
+            assert!((i16::from(converted.r()) - i16::from(color.r())).abs() <= 1);
+            assert!((i16::from(converted.g()) - i16::from(color.g())).abs() <= 1);
+            assert!((i16::from(converted.b()) - i16::from(color.b())).abs() <= 1);
+        }
+    }
+
+    #[test]
+    fn lab_white() {
+        let white = Lab::from_rgb(Rgb888::WHITE);
+
+        assert_eq!(white.l, 100 * ONE);
+        assert_eq!(white.a, 0);
+        assert_eq!(white.b, 0);
+    }
+
+    #[test]
+    fn lab_endpoints() {
+        let from = Rgb565::CSS_HOT_PINK;
+        let to = Rgb565::CSS_STEEL_BLUE;
+
+        assert_eq!(lerp_lab(from, to, 0), from);
+        assert_eq!(lerp_lab(from, to, 255), to);
+    }
+
+    #[test]
+    fn cube_root_is_exact_for_cubes() {
+        assert_eq!(cube_root(0), 0);
+        assert_eq!(cube_root(27), 3);
+        assert_eq!(cube_root(28), 3);
+        assert_eq!(cube_root(ONE * ONE * ONE), ONE);
+    }
+}
@@ -84,14 +84,32 @@
 //! [`raw` module]: raw/index.html
 
 mod binary_color;
+mod color_conversion;
+mod color_math;
 mod conversion;
+mod epaper_color;
 mod gray_color;
+mod hex_color;
+mod hsl_color;
+mod hsv_color;
+mod interpolation;
 pub mod raw;
 mod rgb_color;
 mod web_colors;
 
 pub use binary_color::*;
+pub use color_conversion::{
+    ColorConversion, DefaultConversion, GammaCorrect, LookupTable, LumaWeights, Threshold,
+};
+pub use color_math::{BlendMode, ColorMath};
+pub use epaper_color::{SevenColor, TriColor};
 pub use gray_color::*;
+#[doc(hidden)]
+pub use hex_color::rgb_to_hex;
+pub use hex_color::ParseColorError;
+pub use hsl_color::Hsl;
+pub use hsv_color::Hsv;
+pub use interpolation::{lerp_hsv, lerp_lab, lerp_rgb};
 use raw::RawData;
 pub use rgb_color::*;
 pub use web_colors::WebColors;
@@ -0,0 +1,252 @@
+use crate::pixelcolor::{
+    raw::{RawData, RawDataSliceAccess},
+    PixelColor,
+};
+use core::marker::PhantomData;
+
+/// Iterator which encodes colors into raw bytes.
+///
+/// `RawDataEncoder` converts an iterator over colors into an iterator over the bytes of their raw
+/// data representation. The bytes use the same layout as [`RawDataIter`] and [`ImageRaw`]:
+/// values with less than 8 bits per pixel and 12 bit values are packed with the most significant
+/// bit first, 18 bit values are padded to three bytes, as described in the [`RawU18`]
+/// documentation, and values with a multiple of 8 bits per pixel are stored in the byte order
+/// `BO`. If the last byte isn't completely filled, the remaining bits are set to `0`.
+///
+/// The encoder can be used in display drivers to convert the colors passed to
+/// [`fill_contiguous`] into the byte stream expected by the display. The [`fill`] method copies
+/// the next bytes into a buffer, which makes it possible to send the data in chunks, for example
+/// by using DMA transfers.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{
+///     raw::{BigEndian, LittleEndian, RawDataEncoder},
+///     Gray2, Rgb565, RgbColor,
+/// };
+///
+/// let colors = [Rgb565::RED, Rgb565::BLUE, Rgb565::WHITE];
+///
+/// let mut encoder = RawDataEncoder::<_, BigEndian>::new(colors.iter().copied());
+/// let mut buffer = [0u8; 4];
+///
+/// assert_eq!(encoder.fill(&mut buffer), 4);
+/// assert_eq!(buffer, [0xF8, 0x00, 0x00, 0x1F]);
+/// assert_eq!(encoder.fill(&mut buffer), 2);
+/// assert_eq!(buffer[0..2], [0xFF, 0xFF]);
+/// assert_eq!(encoder.fill(&mut buffer), 0);
+///
+/// // Colors with less than 8 bits per pixel are packed into bytes.
+/// let grays = [Gray2::new(3), Gray2::new(0), Gray2::new(1), Gray2::new(2), Gray2::new(3)];
+/// let bytes = RawDataEncoder::<_, LittleEndian>::new(grays.iter().copied());
+///
+/// assert!(bytes.eq([0b11_00_01_10, 0b11_00_00_00].iter().copied()));
+/// ```
+///
+/// [`RawDataIter`]: struct.RawDataIter.html
+/// [`RawU18`]: struct.RawU18.html
+/// [`ImageRaw`]: https://docs.rs/embedded-graphics/latest/embedded_graphics/image/struct.ImageRaw.html
+/// [`fill_contiguous`]: ../../draw_target/trait.DrawTarget.html#method.fill_contiguous
+/// [`fill`]: #method.fill
+#[derive(Clone, Debug)]
+pub struct RawDataEncoder<I, BO> {
+    colors: I,
+
+    /// Bits which haven't been returned yet.
+    ///
+    /// Only the `bit_count` least significant bits are used.
+    bits: u64,
+
+    /// Number of bits in `bits`.
+    bit_count: usize,
+
+    byte_order: PhantomData<BO>,
+}
+
+impl<I, BO> RawDataEncoder<I, BO>
+where
+    I: Iterator,
+    I::Item: PixelColor + Into<<I::Item as PixelColor>::Raw>,
+    <I::Item as PixelColor>::Raw: RawDataSliceAccess<BO>,
+{
+    /// Creates a new encoder.
+    pub fn new<C>(colors: C) -> Self
+    where
+        C: IntoIterator<IntoIter = I>,
+    {
+        Self {
+            colors: colors.into_iter(),
+            bits: 0,
+            bit_count: 0,
+            byte_order: PhantomData,
+        }
+    }
+
+    /// Copies the next bytes into `buffer`.
+    ///
+    /// Returns the number of bytes which were copied into the buffer. The return value is only
+    /// less than the length of the buffer if all colors have been encoded.
+    pub fn fill(&mut self, buffer: &mut [u8]) -> usize {
+        let mut count = 0;
+
+        while count < buffer.len() {
+            match self.next_byte() {
+                Some(byte) => buffer[count] = byte,
+                None => break,
+            }
+
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Returns the next byte.
+    fn next_byte(&mut self) -> Option<u8> {
+        let bpp = <I::Item as PixelColor>::Raw::BUFFER_BITS_PER_PIXEL;
+
+        while self.bit_count < 8 {
+            let color = match self.colors.next() {
+                Some(color) => color,
+                None if self.bit_count > 0 => {
+                    // Pad the last byte with zeros.
+                    let byte = (self.bits << (8 - self.bit_count)) as u8;
+                    self.bit_count = 0;
+
+                    return Some(byte);
+                }
+                None => return None,
+            };
+
+            // The raw data is written into a temporary buffer to reuse the layout used by
+            // `RawDataSliceMut`.
+            let mut data = [0u8; 4];
+            RawDataSliceAccess::<BO>::write(color.into(), &mut data, 0);
+
+            let value = u64::from(u32::from_be_bytes(data)) >> (32 - bpp);
+            self.bits = (self.bits << bpp) | value;
+            self.bit_count += bpp;
+        }
+
+        self.bit_count -= 8;
+
+        Some((self.bits >> self.bit_count) as u8)
+    }
+}
+
+impl<I, BO> Iterator for RawDataEncoder<I, BO>
+where
+    I: Iterator,
+    I::Item: PixelColor + Into<<I::Item as PixelColor>::Raw>,
+    <I::Item as PixelColor>::Raw: RawDataSliceAccess<BO>,
+{
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_byte()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::{
+        raw::{BigEndian, LittleEndian, RawDataIter, RawU18},
+        BinaryColor, Gray4, Gray8, Rgb444, Rgb565, Rgb666, Rgb888, RgbColor,
+    };
+
+    #[test]
+    fn bpp1() {
+        let colors = [BinaryColor::On, BinaryColor::Off]
+            .iter()
+            .copied()
+            .cycle()
+            .take(10);
+
+        let encoder = RawDataEncoder::<_, BigEndian>::new(colors);
+        assert!(encoder.eq([0b1010_1010, 0b1000_0000].iter().copied()));
+    }
+
+    #[test]
+    fn bpp4() {
+        let colors = [Gray4::new(0x1), Gray4::new(0x2), Gray4::new(0xF)];
+
+        let encoder = RawDataEncoder::<_, BigEndian>::new(colors.iter().copied());
+        assert!(encoder.eq([0x12, 0xF0].iter().copied()));
+    }
+
+    #[test]
+    fn bpp8() {
+        let colors = [Gray8::new(0x12), Gray8::new(0x34)];
+
+        let encoder = RawDataEncoder::<_, LittleEndian>::new(colors.iter().copied());
+        assert!(encoder.eq([0x12, 0x34].iter().copied()));
+    }
+
+    #[test]
+    fn bpp12() {
+        let colors = [
+            Rgb444::new(0x1, 0x2, 0x3),
+            Rgb444::new(0x4, 0x5, 0x6),
+            Rgb444::new(0x7, 0x8, 0x9),
+        ];
+
+        let encoder = RawDataEncoder::<_, BigEndian>::new(colors.iter().copied());
+        assert!(encoder.eq([0x12, 0x34, 0x56, 0x78, 0x90].iter().copied()));
+    }
+
+    #[test]
+    fn bpp16() {
+        let colors = [Rgb565::RED, Rgb565::GREEN];
+
+        let encoder = RawDataEncoder::<_, BigEndian>::new(colors.iter().copied());
+        assert!(encoder.eq([0xF8, 0x00, 0x07, 0xE0].iter().copied()));
+
+        let encoder = RawDataEncoder::<_, LittleEndian>::new(colors.iter().copied());
+        assert!(encoder.eq([0x00, 0xF8, 0xE0, 0x07].iter().copied()));
+    }
+
+    #[test]
+    fn bpp18_round_trip() {
+        let colors = [
+            Rgb666::new(0x3F, 0x00, 0x15),
+            Rgb666::new(0x01, 0x2A, 0x3F),
+            Rgb666::new(0x00, 0x3F, 0x00),
+        ];
+
+        let mut data = [0; 9];
+        let mut encoder = RawDataEncoder::<_, BigEndian>::new(colors.iter().copied());
+        assert_eq!(encoder.fill(&mut data), 9);
+        assert_eq!(data[0..3], [0xFC, 0x00, 0x54]);
+
+        let decoded = RawDataIter::<RawU18, BigEndian>::new(&data).map(Rgb666::from);
+        assert!(decoded.eq(colors.iter().copied()));
+    }
+
+    #[test]
+    fn bpp24() {
+        let colors = [Rgb888::new(0x11, 0x22, 0x33)];
+
+        let encoder = RawDataEncoder::<_, BigEndian>::new(colors.iter().copied());
+        assert!(encoder.eq([0x11, 0x22, 0x33].iter().copied()));
+
+        let encoder = RawDataEncoder::<_, LittleEndian>::new(colors.iter().copied());
+        assert!(encoder.eq([0x33, 0x22, 0x11].iter().copied()));
+    }
+
+    #[test]
+    fn fill_in_chunks() {
+        let colors = [Rgb888::RED, Rgb888::GREEN, Rgb888::BLUE];
+        let mut encoder = RawDataEncoder::<_, BigEndian>::new(colors.iter().copied());
+
+        let mut buffer = [0; 4];
+        assert_eq!(encoder.fill(&mut buffer), 4);
+        assert_eq!(buffer, [0xFF, 0x00, 0x00, 0x00]);
+        assert_eq!(encoder.fill(&mut buffer), 4);
+        assert_eq!(buffer, [0xFF, 0x00, 0x00, 0x00]);
+        assert_eq!(encoder.fill(&mut buffer), 1);
+        assert_eq!(buffer[0], 0xFF);
+        assert_eq!(encoder.fill(&mut buffer), 0);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::pixelcolor::raw::{
-    BigEndian, LittleEndian, RawData, RawU1, RawU16, RawU2, RawU24, RawU32, RawU4, RawU8,
+    to_bytes::raw_u18_from_bytes, BigEndian, LittleEndian, RawData, RawU1, RawU12, RawU16, RawU18,
+    RawU2, RawU24, RawU32, RawU4, RawU8,
 };
 use byteorder::{ByteOrder, BE, LE};
 use core::marker::PhantomData;
@@ -13,7 +14,7 @@ pub struct RawDataIter<'a, R, BO> {
     /// Index into `data` for next read.
     byte_position: usize,
 
-    /// Remaining bits in the current byte (only used for bpp < 8).
+    /// Remaining bits in the current byte (only used for bpp < 8 and bit packed data).
     bits_left: u8,
 
     raw_type: PhantomData<R>,
@@ -69,6 +70,42 @@ impl<'a, R, BO> RawDataIter<'a, R, BO> {
         Some(ret)
     }
 
+    /// Returns the next `bit_count` bits of bit packed data.
+    ///
+    /// The value may span multiple bytes, which are read with the most significant bit first.
+    fn next_packed_bits(&mut self, bit_count: u8) -> Option<u32> {
+        if self.byte_position >= self.data.len() {
+            return None;
+        }
+
+        let bits_available =
+            (self.data.len() - self.byte_position) * 8 - (8 - usize::from(self.bits_left));
+        if bits_available < usize::from(bit_count) {
+            return None;
+        }
+
+        let mut value = 0u32;
+        let mut remaining = bit_count;
+
+        while remaining > 0 {
+            let count = remaining.min(self.bits_left);
+            let mask = ((1u16 << count) - 1) as u8;
+            let bits = (self.data[self.byte_position] >> (self.bits_left - count)) & mask;
+
+            value = (value << count) | u32::from(bits);
+
+            remaining -= count;
+            self.bits_left -= count;
+
+            if self.bits_left == 0 {
+                self.byte_position += 1;
+                self.bits_left = 8;
+            }
+        }
+
+        Some(value)
+    }
+
     /// Returns the a slice of the next `byte_count` bytes.
     fn next_bytes(&mut self, byte_count: usize) -> Option<&[u8]> {
         if (self.data.len() - self.byte_position) >= byte_count {
@@ -125,6 +162,24 @@ impl_next_for_bits!(RawU1, 1);
 impl_next_for_bits!(RawU2, 2);
 impl_next_for_bits!(RawU4, 4);
 
+macro_rules! impl_next_for_packed_bits {
+    ($raw_type:ident, $bit_count:expr) => {
+        impl<BO> RawDataIterNext<BO> for $raw_type {
+            fn next<'a>(iter: &mut RawDataIter<'a, $raw_type, BO>) -> Option<$raw_type> {
+                iter.next_packed_bits($bit_count).map($raw_type::from_u32)
+            }
+        }
+    };
+}
+
+impl_next_for_packed_bits!(RawU12, 12);
+
+impl<BO> RawDataIterNext<BO> for RawU18 {
+    fn next<'a>(iter: &mut RawDataIter<'a, RawU18, BO>) -> Option<RawU18> {
+        iter.next_bytes(3).map(raw_u18_from_bytes)
+    }
+}
+
 impl<BO> RawDataIterNext<BO> for RawU8 {
     fn next<'a>(iter: &mut RawDataIter<'a, RawU8, BO>) -> Option<RawU8> {
         iter.next_bytes(1).map(|data| RawU8::new(data[0]))
@@ -273,6 +328,42 @@ mod tests {
         assert_eq!(iter.count(), 1);
     }
 
+    #[test]
+    fn raw_u12() {
+        let expected = [0x102, 0x030, 0x405, 0x060]
+            .iter()
+            .copied()
+            .map(RawU12::new);
+
+        let iter: RawDataIter<RawU12, BigEndian> = RawDataIter::new(BYTES_DATA_1);
+        assert!(iter.eq(expected.clone()));
+
+        let iter: RawDataIter<RawU12, LittleEndian> = RawDataIter::new(BYTES_DATA_1);
+        assert!(iter.eq(expected));
+    }
+
+    #[test]
+    fn raw_u12_align() {
+        let data = &[0x12, 0x34, 0x56, 0x78];
+
+        let mut iter: RawDataIter<RawU12, BigEndian> = RawDataIter::new(data);
+        assert_eq!(iter.next(), Some(RawU12::new(0x123)));
+        iter.align();
+        assert_eq!(iter.next(), Some(RawU12::new(0x567)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn raw_u18() {
+        let expected = [0x0_420C, 0x1_0518].iter().copied().map(RawU18::new);
+
+        let iter: RawDataIter<RawU18, BigEndian> = RawDataIter::new(BYTES_DATA_2);
+        assert!(iter.eq(expected.clone()));
+
+        let iter: RawDataIter<RawU18, LittleEndian> = RawDataIter::new(BYTES_DATA_2);
+        assert!(iter.eq(expected));
+    }
+
     #[test]
     fn raw_u24_le() {
         let expected = [0x302010, 0x605040].iter().copied().map(RawU24::new);
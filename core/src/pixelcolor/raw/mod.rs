@@ -112,11 +112,16 @@
 //! [`ToBytes`]: trait.ToBytes.html
 //! [`to_be_bytes`]: trait.ToBytes.html#tymethod.to_be_bytes
 
+mod encoder;
 mod iter;
+mod slice_mut;
 mod to_bytes;
 
+pub use encoder::RawDataEncoder;
 pub use iter::RawDataIter;
 pub(crate) use iter::RawDataIterNext;
+pub(crate) use slice_mut::RawDataSliceAccess;
+pub use slice_mut::RawDataSliceMut;
 pub use to_bytes::ToBytes;
 
 /// Trait implemented by all `RawUx` types.
@@ -125,6 +130,8 @@ pub trait RawData:
     + private::Sealed
     + RawDataIterNext<LittleEndian>
     + RawDataIterNext<BigEndian>
+    + RawDataSliceAccess<LittleEndian>
+    + RawDataSliceAccess<BigEndian>
     + From<<Self as RawData>::Storage>
     + ToBytes
 {
@@ -136,6 +143,11 @@ pub trait RawData:
     /// Bits per pixel.
     const BITS_PER_PIXEL: usize;
 
+    /// Number of bits used by each pixel in a byte buffer.
+    ///
+    /// This is the same as `BITS_PER_PIXEL`, except for types which are padded to whole bytes.
+    const BUFFER_BITS_PER_PIXEL: usize = Self::BITS_PER_PIXEL;
+
     /// Converts this raw data into the storage type.
     ///
     /// If the primitive integer types used as the storage type contains more bits
@@ -168,7 +180,7 @@ impl RawData for () {
 impl private::Sealed for () {}
 
 macro_rules! impl_raw_data {
-    ($type:ident : $storage_type:ident, $bpp:expr, $mask:expr, $bpp_str:expr, $doc:expr) => {
+    ($type:ident : $storage_type:ident, $bpp:expr, $mask:expr, $bpp_str:expr, $doc:expr $(, $buffer_bpp:expr)?) => {
         #[doc = $bpp_str]
         #[doc = "per pixel raw data."]
         #[doc = ""]
@@ -196,6 +208,8 @@ macro_rules! impl_raw_data {
 
             const BITS_PER_PIXEL: usize = $bpp;
 
+            $(const BUFFER_BITS_PER_PIXEL: usize = $buffer_bpp;)?
+
             fn into_inner(self) -> Self::Storage {
                 self.0
             }
@@ -234,7 +248,35 @@ impl_raw_data!(RawU1: u8, 1, 0x01, "1 bit");
 impl_raw_data!(RawU2: u8, 2, 0x03, "2 bits");
 impl_raw_data!(RawU4: u8, 4, 0x0F, "4 bits");
 impl_raw_data!(RawU8: u8, 8, 0xFF, "8 bits");
+impl_raw_data!(
+    RawU12: u16,
+    12,
+    0x0FFF,
+    "12 bits",
+    "`RawU12` is internally stored in an `u16`. It can be constructed from an `u16` by using \
+     the [`new`] method or by calling `RawU12::from(u16_value)`. To convert a `RawU12` back into \
+     an `u16` the [`into_inner`] method can be used.\n\n\
+     In byte buffers 12 bit values are packed without padding with the most significant bit \
+     first, independent of the byte order. Two pixels are stored in three bytes, which is the \
+     12 bits per pixel format used by display controllers like the ST7735. [`ToBytes`] returns \
+     a single value in the same layout, left aligned in two bytes.\n\n\
+     [`ToBytes`]: trait.ToBytes.html"
+);
 impl_raw_data!(RawU16: u16, 16, 0xFFFF, "16 bits");
+impl_raw_data!(
+    RawU18: u32,
+    18,
+    0x3_FFFF,
+    "18 bits",
+    "`RawU18` is internally stored in an `u32`. It can be constructed from an `u32` by using \
+     the [`new`] method or by calling `RawU18::from(u32_value)`. To convert a `RawU18` back into \
+     an `u32` the [`into_inner`] method can be used.\n\n\
+     In byte buffers each value is stored in three bytes, independent of the byte order. Every \
+     byte contains 6 bits, starting with the most significant bits, which are left aligned and \
+     padded with two `0` bits. For `Rgb666` colors the bytes are `RRRRRR00 GGGGGG00 BBBBBB00`, \
+     which is the 18 bits per pixel format used by display controllers like the ILI9488.",
+    24
+);
 impl_raw_data!(RawU24: u32, 24, 0xFF_FFFF, "24 bits");
 impl_raw_data!(RawU32: u32, 32, 0xFFFF_FFFF, "32 bits");
 
@@ -269,6 +311,8 @@ mod tests {
         assert_eq!(RawU1::new(u8::max_value()).0, 0x1);
         assert_eq!(RawU2::new(u8::max_value()).0, 0x3);
         assert_eq!(RawU4::new(u8::max_value()).0, 0xF);
+        assert_eq!(RawU12::new(u16::max_value()).0, 0xFFF);
+        assert_eq!(RawU18::new(u32::max_value()).0, 0x3FFFF);
         assert_eq!(RawU24::new(u32::max_value()).0, 0xFFFFFF);
     }
 }
@@ -0,0 +1,422 @@
+use crate::pixelcolor::raw::{
+    to_bytes::raw_u18_from_bytes, BigEndian, LittleEndian, RawData, RawU1, RawU12, RawU16, RawU18,
+    RawU2, RawU24, RawU32, RawU4, RawU8, ToBytes,
+};
+use byteorder::{ByteOrder, BE, LE};
+use core::{marker::PhantomData, ops::Range};
+
+/// Mutable slice of raw pixel data.
+///
+/// `RawDataSliceMut` is the writable counterpart to [`RawDataIter`]. It uses the same data
+/// layout: values with less than 8 bits per pixel and 12 bit values are packed without padding
+/// with the most significant bit first. 18 bit values are padded to three bytes, as described
+/// in the [`RawU18`] documentation. Values with a multiple of 8 bits per pixel are stored in the
+/// byte order `BO`.
+///
+/// Pixels are addressed by their index in the slice. If the buffer contains multiple rows of
+/// pixels which start at byte boundaries, the index of the first pixel in each row must be
+/// calculated from the number of bytes per row.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{
+///     raw::{BigEndian, RawDataSliceMut, RawU4},
+///     Gray4,
+/// };
+///
+/// let mut data = [0u8; 3];
+/// let mut slice: RawDataSliceMut<RawU4, BigEndian> = RawDataSliceMut::new(&mut data);
+///
+/// slice.set(0, Gray4::new(0x1).into());
+/// slice.set(3, RawU4::new(0xA));
+/// slice.fill(4..6, RawU4::new(0xF));
+///
+/// assert_eq!(slice.get(3), Some(RawU4::new(0xA)));
+/// assert_eq!(slice.get(6), None);
+///
+/// assert_eq!(data, [0x10, 0x0A, 0xFF]);
+/// ```
+///
+/// [`RawDataIter`]: struct.RawDataIter.html
+/// [`RawU18`]: struct.RawU18.html
+#[derive(PartialEq, Eq, Hash, Debug)]
+pub struct RawDataSliceMut<'a, R, BO> {
+    /// Pixel data.
+    data: &'a mut [u8],
+
+    raw_type: PhantomData<R>,
+    byte_order: PhantomData<BO>,
+}
+
+impl<'a, R, BO> RawDataSliceMut<'a, R, BO>
+where
+    R: RawData + RawDataSliceAccess<BO>,
+{
+    /// Creates a new mutable raw data slice.
+    pub fn new(data: &'a mut [u8]) -> Self {
+        Self {
+            data,
+            raw_type: PhantomData,
+            byte_order: PhantomData,
+        }
+    }
+
+    /// Returns the number of pixels in the slice.
+    ///
+    /// Excess bits at the end of the data, which don't contain a whole pixel, are ignored.
+    pub fn len(&self) -> usize {
+        (self.data.len() * 8)
+            .checked_div(R::BUFFER_BITS_PER_PIXEL)
+            .unwrap_or(0)
+    }
+
+    /// Returns `true` if the slice doesn't contain any pixels.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the pixel at the given index.
+    ///
+    /// Returns `None` if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<R> {
+        if index < self.len() {
+            Some(<R as RawDataSliceAccess<BO>>::read(self.data, index))
+        } else {
+            None
+        }
+    }
+
+    /// Sets the pixel at the given index.
+    ///
+    /// # Panics
+    ///
+    /// If the index is out of bounds.
+    pub fn set(&mut self, index: usize, value: R) {
+        assert!(
+            index < self.len(),
+            "index out of bounds: the len is {} but the index is {}",
+            self.len(),
+            index
+        );
+
+        RawDataSliceAccess::<BO>::write(value, self.data, index);
+    }
+
+    /// Sets all pixels in the given range to the same value.
+    ///
+    /// # Panics
+    ///
+    /// If the range is out of bounds.
+    pub fn fill(&mut self, range: Range<usize>, value: R)
+    where
+        R: Copy,
+    {
+        assert!(
+            range.end <= self.len(),
+            "range end index {} out of range for slice of length {}",
+            range.end,
+            self.len()
+        );
+
+        for index in range {
+            RawDataSliceAccess::<BO>::write(value, self.data, index);
+        }
+    }
+}
+
+/// Helper trait to read and write raw data in a byte slice.
+pub trait RawDataSliceAccess<BO>: Sized {
+    /// Reads the value at the given pixel index.
+    ///
+    /// The caller must ensure that the index is in bounds.
+    fn read(data: &[u8], index: usize) -> Self;
+
+    /// Writes the value at the given pixel index.
+    ///
+    /// The caller must ensure that the index is in bounds.
+    fn write(self, data: &mut [u8], index: usize);
+}
+
+/// Reads `bit_count` bits starting at `bit_offset`, with the most significant bit first.
+fn read_bits(data: &[u8], bit_offset: usize, bit_count: usize) -> u32 {
+    (bit_offset..bit_offset + bit_count).fold(0u32, |value, bit| {
+        let set = data[bit / 8] & (0x80 >> (bit % 8)) != 0;
+
+        (value << 1) | u32::from(set)
+    })
+}
+
+/// Writes the `bit_count` least significant bits of `value` starting at `bit_offset`, with the
+/// most significant bit first.
+fn write_bits(data: &mut [u8], bit_offset: usize, bit_count: usize, value: u32) {
+    for i in 0..bit_count {
+        let bit = bit_offset + i;
+        let mask = 0x80 >> (bit % 8);
+
+        if value & (1 << (bit_count - 1 - i)) != 0 {
+            data[bit / 8] |= mask;
+        } else {
+            data[bit / 8] &= !mask;
+        }
+    }
+}
+
+macro_rules! impl_access_for_bits {
+    ($raw_type:ident, $bit_count:expr) => {
+        impl<BO> RawDataSliceAccess<BO> for $raw_type {
+            fn read(data: &[u8], index: usize) -> Self {
+                $raw_type::from_u32(read_bits(data, index * $bit_count, $bit_count))
+            }
+
+            fn write(self, data: &mut [u8], index: usize) {
+                let value = u32::from(self.into_inner());
+
+                write_bits(data, index * $bit_count, $bit_count, value)
+            }
+        }
+    };
+}
+
+impl_access_for_bits!(RawU1, 1);
+impl_access_for_bits!(RawU2, 2);
+impl_access_for_bits!(RawU4, 4);
+impl_access_for_bits!(RawU12, 12);
+
+impl<BO> RawDataSliceAccess<BO> for RawU18 {
+    fn read(data: &[u8], index: usize) -> Self {
+        raw_u18_from_bytes(&data[index * 3..])
+    }
+
+    fn write(self, data: &mut [u8], index: usize) {
+        let start = index * 3;
+
+        data[start..start + 3].copy_from_slice(&self.to_be_bytes());
+    }
+}
+
+impl<BO> RawDataSliceAccess<BO> for RawU8 {
+    fn read(data: &[u8], index: usize) -> Self {
+        RawU8::new(data[index])
+    }
+
+    fn write(self, data: &mut [u8], index: usize) {
+        data[index] = self.into_inner();
+    }
+}
+
+macro_rules! impl_access_for_bytes {
+    ($raw_type:ident, $byte_count:expr, $endian:ident, $read_function:path, $to_bytes:ident) => {
+        impl RawDataSliceAccess<$endian> for $raw_type {
+            fn read(data: &[u8], index: usize) -> Self {
+                let start = index * $byte_count;
+
+                $raw_type::new($read_function(&data[start..start + $byte_count]))
+            }
+
+            fn write(self, data: &mut [u8], index: usize) {
+                let start = index * $byte_count;
+
+                data[start..start + $byte_count].copy_from_slice(&self.$to_bytes());
+            }
+        }
+    };
+    ($raw_type:ident, $byte_count:expr, $read_function:ident) => {
+        impl_access_for_bytes!(
+            $raw_type,
+            $byte_count,
+            BigEndian,
+            BE::$read_function,
+            to_be_bytes
+        );
+        impl_access_for_bytes!(
+            $raw_type,
+            $byte_count,
+            LittleEndian,
+            LE::$read_function,
+            to_le_bytes
+        );
+    };
+}
+
+impl_access_for_bytes!(RawU16, 2, read_u16);
+impl_access_for_bytes!(RawU24, 3, read_u24);
+impl_access_for_bytes!(RawU32, 4, read_u32);
+
+/// Dummy implementation to allow () as `PixelColor::Raw`.
+impl<BO> RawDataSliceAccess<BO> for () {
+    fn read(_data: &[u8], _index: usize) {}
+
+    fn write(self, _data: &mut [u8], _index: usize) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::raw::RawDataIter;
+
+    #[test]
+    fn len() {
+        let mut data = [0; 5];
+
+        assert_eq!(
+            RawDataSliceMut::<RawU1, BigEndian>::new(&mut data).len(),
+            40
+        );
+        assert_eq!(
+            RawDataSliceMut::<RawU12, BigEndian>::new(&mut data).len(),
+            3
+        );
+        assert_eq!(
+            RawDataSliceMut::<RawU16, BigEndian>::new(&mut data).len(),
+            2
+        );
+        assert_eq!(
+            RawDataSliceMut::<RawU24, BigEndian>::new(&mut data).len(),
+            1
+        );
+        assert!(RawDataSliceMut::<RawU32, BigEndian>::new(&mut data[0..3]).is_empty());
+        assert!(RawDataSliceMut::<(), BigEndian>::new(&mut data).is_empty());
+    }
+
+    #[test]
+    fn raw_u1() {
+        let mut data = [0xFF, 0x00];
+        let mut slice = RawDataSliceMut::<RawU1, BigEndian>::new(&mut data);
+
+        slice.set(1, RawU1::new(0));
+        slice.set(8, RawU1::new(1));
+        slice.fill(12..16, RawU1::new(1));
+
+        assert_eq!(slice.get(0), Some(RawU1::new(1)));
+        assert_eq!(slice.get(1), Some(RawU1::new(0)));
+        assert_eq!(slice.get(16), None);
+
+        assert_eq!(data, [0b1011_1111, 0b1000_1111]);
+    }
+
+    #[test]
+    fn raw_u2() {
+        let mut data = [0x00];
+        let mut slice = RawDataSliceMut::<RawU2, LittleEndian>::new(&mut data);
+
+        slice.set(0, RawU2::new(3));
+        slice.set(2, RawU2::new(1));
+        slice.set(3, RawU2::new(2));
+        slice.set(3, RawU2::new(1));
+
+        assert_eq!(data, [0b11_00_01_01]);
+    }
+
+    #[test]
+    fn raw_u4() {
+        let mut data = [0x12, 0x34];
+        let mut slice = RawDataSliceMut::<RawU4, BigEndian>::new(&mut data);
+
+        slice.set(1, RawU4::new(0xA));
+        slice.set(2, RawU4::new(0xB));
+
+        assert_eq!(slice.get(3), Some(RawU4::new(0x4)));
+        assert_eq!(data, [0x1A, 0xB4]);
+    }
+
+    #[test]
+    fn raw_u12() {
+        let mut data = [0; 3];
+        let mut slice = RawDataSliceMut::<RawU12, BigEndian>::new(&mut data);
+
+        slice.set(0, RawU12::new(0x123));
+        slice.set(1, RawU12::new(0x456));
+
+        assert_eq!(slice.get(1), Some(RawU12::new(0x456)));
+        assert_eq!(data, [0x12, 0x34, 0x56]);
+    }
+
+    #[test]
+    fn raw_u18() {
+        let mut data = [0xFF; 7];
+        let mut slice = RawDataSliceMut::<RawU18, LittleEndian>::new(&mut data);
+
+        assert_eq!(slice.len(), 2);
+
+        slice.set(0, RawU18::new(0x3_F000));
+        slice.set(1, RawU18::new(0x0_0FFF));
+
+        assert_eq!(slice.get(0), Some(RawU18::new(0x3_F000)));
+        assert_eq!(slice.get(1), Some(RawU18::new(0x0_0FFF)));
+        assert_eq!(data, [0xFC, 0x00, 0x00, 0x00, 0xFC, 0xFC, 0xFF]);
+
+        let iter: RawDataIter<RawU18, LittleEndian> = RawDataIter::new(&data);
+        assert!(iter.eq([RawU18::new(0x3_F000), RawU18::new(0x0_0FFF)]
+            .iter()
+            .copied()));
+    }
+
+    #[test]
+    fn raw_u8() {
+        let mut data = [0; 3];
+        let mut slice = RawDataSliceMut::<RawU8, BigEndian>::new(&mut data);
+
+        slice.fill(0..3, RawU8::new(0x55));
+        slice.set(1, RawU8::new(0xAA));
+
+        assert_eq!(data, [0x55, 0xAA, 0x55]);
+    }
+
+    #[test]
+    fn raw_u16() {
+        let mut data = [0; 4];
+
+        let mut slice = RawDataSliceMut::<RawU16, BigEndian>::new(&mut data);
+        slice.set(1, RawU16::new(0x1234));
+        assert_eq!(slice.get(1), Some(RawU16::new(0x1234)));
+        assert_eq!(data, [0x00, 0x00, 0x12, 0x34]);
+
+        let mut slice = RawDataSliceMut::<RawU16, LittleEndian>::new(&mut data);
+        slice.set(0, RawU16::new(0x1234));
+        assert_eq!(slice.get(0), Some(RawU16::new(0x1234)));
+        assert_eq!(data, [0x34, 0x12, 0x12, 0x34]);
+    }
+
+    #[test]
+    fn raw_u24() {
+        let mut data = [0; 6];
+
+        let mut slice = RawDataSliceMut::<RawU24, BigEndian>::new(&mut data);
+        slice.set(0, RawU24::new(0x123456));
+        assert_eq!(slice.get(0), Some(RawU24::new(0x123456)));
+
+        let mut slice = RawDataSliceMut::<RawU24, LittleEndian>::new(&mut data);
+        slice.set(1, RawU24::new(0x123456));
+        assert_eq!(slice.get(1), Some(RawU24::new(0x123456)));
+
+        assert_eq!(data, [0x12, 0x34, 0x56, 0x56, 0x34, 0x12]);
+    }
+
+    #[test]
+    fn raw_u32() {
+        let mut data = [0; 8];
+
+        let mut slice = RawDataSliceMut::<RawU32, BigEndian>::new(&mut data);
+        slice.set(0, RawU32::new(0x12345678));
+
+        let mut slice = RawDataSliceMut::<RawU32, LittleEndian>::new(&mut data);
+        slice.fill(1..2, RawU32::new(0x12345678));
+
+        assert_eq!(data, [0x12, 0x34, 0x56, 0x78, 0x78, 0x56, 0x34, 0x12]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+    fn set_out_of_bounds() {
+        let mut data = [0; 1];
+        RawDataSliceMut::<RawU4, BigEndian>::new(&mut data).set(2, RawU4::new(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 3 out of range for slice of length 2")]
+    fn fill_out_of_bounds() {
+        let mut data = [0; 2];
+        RawDataSliceMut::<RawU8, BigEndian>::new(&mut data).fill(1..3, RawU8::new(0));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::pixelcolor::{
-    raw::{RawU1, RawU16, RawU2, RawU24, RawU32, RawU4, RawU8},
+    raw::{RawData, RawU1, RawU12, RawU16, RawU18, RawU2, RawU24, RawU32, RawU4, RawU8},
     PixelColor,
 };
 
@@ -49,34 +49,93 @@ impl_to_bytes!(RawU8, [u8; 1]);
 impl_to_bytes!(RawU16, [u8; 2]);
 impl_to_bytes!(RawU32, [u8; 4]);
 
-impl ToBytes for RawU24 {
-    type Bytes = [u8; 3];
+/// Implements `ToBytes` for raw data types that are stored in the 3 least significant bytes of
+/// an `u32`.
+macro_rules! impl_to_3_bytes {
+    ($type:ty) => {
+        impl ToBytes for $type {
+            type Bytes = [u8; 3];
 
-    fn to_be_bytes(self) -> Self::Bytes {
-        let mut ret = [0; 3];
+            fn to_be_bytes(self) -> Self::Bytes {
+                let mut ret = [0; 3];
 
-        ret.copy_from_slice(&self.0.to_be_bytes()[1..4]);
+                ret.copy_from_slice(&self.0.to_be_bytes()[1..4]);
 
-        ret
-    }
+                ret
+            }
 
-    fn to_le_bytes(self) -> Self::Bytes {
-        let mut ret = [0; 3];
+            fn to_le_bytes(self) -> Self::Bytes {
+                let mut ret = [0; 3];
 
-        ret.copy_from_slice(&self.0.to_le_bytes()[0..3]);
+                ret.copy_from_slice(&self.0.to_le_bytes()[0..3]);
 
-        ret
-    }
+                ret
+            }
 
-    #[cfg(target_endian = "big")]
-    fn to_ne_bytes(self) -> Self::Bytes {
-        self.to_be_bytes()
-    }
+            #[cfg(target_endian = "big")]
+            fn to_ne_bytes(self) -> Self::Bytes {
+                self.to_be_bytes()
+            }
 
-    #[cfg(target_endian = "little")]
-    fn to_ne_bytes(self) -> Self::Bytes {
-        self.to_le_bytes()
-    }
+            #[cfg(target_endian = "little")]
+            fn to_ne_bytes(self) -> Self::Bytes {
+                self.to_le_bytes()
+            }
+        }
+    };
+}
+
+impl_to_3_bytes!(RawU24);
+
+/// Implements `ToBytes` for raw data types which use the same byte layout for all byte orders.
+macro_rules! impl_to_bytes_without_byte_order {
+    ($type:ty, $bytes_type:ty, $to_bytes:path) => {
+        impl ToBytes for $type {
+            type Bytes = $bytes_type;
+
+            fn to_be_bytes(self) -> Self::Bytes {
+                $to_bytes(self)
+            }
+
+            fn to_le_bytes(self) -> Self::Bytes {
+                $to_bytes(self)
+            }
+
+            fn to_ne_bytes(self) -> Self::Bytes {
+                $to_bytes(self)
+            }
+        }
+    };
+}
+
+impl_to_bytes_without_byte_order!(RawU12, [u8; 2], raw_u12_to_bytes);
+impl_to_bytes_without_byte_order!(RawU18, [u8; 3], raw_u18_to_bytes);
+
+/// Converts a `RawU12` into the packed layout, left aligned in two bytes.
+fn raw_u12_to_bytes(raw: RawU12) -> [u8; 2] {
+    let value = raw.into_inner();
+
+    [(value >> 4) as u8, (value << 4) as u8]
+}
+
+/// Converts a `RawU18` into three bytes with 6 left aligned bits each.
+fn raw_u18_to_bytes(raw: RawU18) -> [u8; 3] {
+    let value = raw.into_inner();
+
+    [
+        (value >> 10) as u8 & 0xFC,
+        (value >> 4) as u8 & 0xFC,
+        (value << 2) as u8,
+    ]
+}
+
+/// Converts three bytes with 6 left aligned bits each into a `RawU18`.
+pub(crate) fn raw_u18_from_bytes(bytes: &[u8]) -> RawU18 {
+    let value = bytes[0..3]
+        .iter()
+        .fold(0, |value, &byte| (value << 6) | u32::from(byte >> 2));
+
+    RawU18::new(value)
 }
 
 impl ToBytes for () {
@@ -117,7 +176,10 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::pixelcolor::{Bgr565, Bgr888, BinaryColor, Gray2, Gray4, Gray8, Rgb565, Rgb888};
+    use crate::pixelcolor::{
+        Bgr444, Bgr565, Bgr666, Bgr888, BinaryColor, Gray2, Gray4, Gray8, Rgb444, Rgb565, Rgb666,
+        Rgb888,
+    };
 
     fn assert_all_orders<T>(value: T, bytes: T::Bytes)
     where
@@ -153,6 +215,29 @@ mod tests {
         assert_all_orders(Gray8::new(255), [255]);
     }
 
+    #[test]
+    fn bpp12() {
+        assert_all_orders(Rgb444::new(0x1, 0x2, 0x3), [0x12, 0x30]);
+        assert_all_orders(Bgr444::new(0x1, 0x2, 0x3), [0x32, 0x10]);
+    }
+
+    #[test]
+    fn bpp18() {
+        assert_all_orders(Rgb666::new(0x3F, 0x01, 0x2A), [0xFC, 0x04, 0xA8]);
+        assert_all_orders(Bgr666::new(0x3F, 0x01, 0x2A), [0xA8, 0x04, 0xFC]);
+    }
+
+    #[test]
+    fn bpp18_from_bytes() {
+        let raw = RawU18::new(0x2_A5C3);
+
+        assert_eq!(raw_u18_from_bytes(&raw.to_be_bytes()), raw);
+        assert_eq!(
+            raw_u18_from_bytes(&[0xFF, 0xFF, 0xFF]),
+            RawU18::new(0x3_FFFF)
+        );
+    }
+
     #[test]
     fn bpp16_rgb_be() {
         assert_eq!(
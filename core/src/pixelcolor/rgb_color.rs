@@ -1,8 +1,10 @@
 use crate::pixelcolor::{
-    raw::{RawData, RawU16, RawU24},
-    PixelColor,
+    conversion::convert_channel,
+    hex_color::{parse_hex_color, ParseColorError},
+    raw::{RawData, RawU12, RawU16, RawU18, RawU24, RawU8},
+    ColorMath, PixelColor,
 };
-use core::fmt;
+use core::{fmt, str::FromStr};
 
 /// RGB color.
 pub trait RgbColor: PixelColor {
@@ -73,6 +75,7 @@ macro_rules! impl_rgb_color {
         #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
         pub struct $type($storage_type);
 
+        #[allow(trivial_numeric_casts)]
         impl $type {
             const R_MASK: $storage_type = ($type::MAX_R as $storage_type) << $r_pos;
             const G_MASK: $storage_type = ($type::MAX_G as $storage_type) << $g_pos;
@@ -103,12 +106,37 @@ macro_rules! impl_rgb_color {
             #[doc = "Too large channel values will be limited by setting the"]
             #[doc = "unused most significant bits to zero."]
             pub const fn new(r: u8, g: u8, b: u8) -> Self {
+                #![allow(trivial_numeric_casts)]
+
                 let r_shifted = (r & Self::MAX_R) as $storage_type << $r_pos;
                 let g_shifted = (g & Self::MAX_G) as $storage_type << $g_pos;
                 let b_shifted = (b & Self::MAX_B) as $storage_type << $b_pos;
 
                 Self(r_shifted | g_shifted | b_shifted)
             }
+
+            #[doc = "Creates a new"]
+            #[doc = $type_str]
+            #[doc = "color from a `0xRRGGBB` hex value.\n"]
+            #[doc = "The 8 bit channels are scaled to the channel widths of this type"]
+            #[doc = "in the same way as the conversion from `Rgb888`. This method can"]
+            #[doc = "be used to define constants, see also the [`rgb!`] macro.\n"]
+            #[doc = "[`rgb!`]: ../macro.rgb.html"]
+            pub const fn from_hex(hex: u32) -> Self {
+                Self::new(
+                    convert_channel((hex >> 16) as u8, 255, Self::MAX_R),
+                    convert_channel((hex >> 8) as u8, 255, Self::MAX_G),
+                    convert_channel(hex as u8, 255, Self::MAX_B),
+                )
+            }
+        }
+
+        impl FromStr for $type {
+            type Err = ParseColorError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse_hex_color(s).map(Self::from_hex)
+            }
         }
 
         impl RgbColor for $type {
@@ -148,6 +176,19 @@ macro_rules! impl_rgb_color {
             type Raw = $data_type;
         }
 
+        impl ColorMath for $type {
+            fn combine_channels<F>(self, other: Self, mut f: F) -> Self
+            where
+                F: FnMut(u8, u8, u8) -> u8,
+            {
+                Self::new(
+                    f(self.r(), other.r(), Self::MAX_R),
+                    f(self.g(), other.g(), Self::MAX_G),
+                    f(self.b(), other.b(), Self::MAX_B),
+                )
+            }
+        }
+
         impl From<$data_type> for $type {
             fn from(data: $data_type) -> Self {
                 let data = data.into_inner();
@@ -215,11 +256,20 @@ macro_rules! rgb_color {
     };
 }
 
+rgb_color!(Rgb332, RawU8, u8, Rgb = (3, 3, 2));
+rgb_color!(Bgr233, RawU8, u8, Bgr = (3, 3, 2));
+
+rgb_color!(Rgb444, RawU12, u16, Rgb = (4, 4, 4));
+rgb_color!(Bgr444, RawU12, u16, Bgr = (4, 4, 4));
+
 rgb_color!(Rgb555, RawU16, u16, Rgb = (5, 5, 5));
 rgb_color!(Bgr555, RawU16, u16, Bgr = (5, 5, 5));
 rgb_color!(Rgb565, RawU16, u16, Rgb = (5, 6, 5));
 rgb_color!(Bgr565, RawU16, u16, Bgr = (5, 6, 5));
 
+rgb_color!(Rgb666, RawU18, u32, Rgb = (6, 6, 6));
+rgb_color!(Bgr666, RawU18, u32, Bgr = (6, 6, 6));
+
 rgb_color!(Rgb888, RawU24, u32, Rgb = (8, 8, 8));
 rgb_color!(Bgr888, RawU24, u32, Bgr = (8, 8, 8));
 
@@ -228,6 +278,28 @@ mod tests {
     use super::*;
     use crate::pixelcolor::IntoStorage;
 
+    /// Convert color to integer and back again to test bit positions
+    fn test_bpp8<C>(color: C, value: u8)
+    where
+        C: RgbColor + From<RawU8> + Into<RawU8> + core::fmt::Debug,
+    {
+        let value = RawU8::new(value);
+
+        assert_eq!(color.into(), value);
+        assert_eq!(C::from(value), color);
+    }
+
+    /// Convert color to integer and back again to test bit positions
+    fn test_bpp12<C>(color: C, value: u16)
+    where
+        C: RgbColor + From<RawU12> + Into<RawU12> + core::fmt::Debug,
+    {
+        let value = RawU12::new(value);
+
+        assert_eq!(color.into(), value);
+        assert_eq!(C::from(value), color);
+    }
+
     /// Convert color to integer and back again to test bit positions
     fn test_bpp16<C>(color: C, value: u16)
     where
@@ -239,6 +311,17 @@ mod tests {
         assert_eq!(C::from(value), color);
     }
 
+    /// Convert color to integer and back again to test bit positions
+    fn test_bpp18<C>(color: C, value: u32)
+    where
+        C: RgbColor + From<RawU18> + Into<RawU18> + core::fmt::Debug,
+    {
+        let value = RawU18::new(value);
+
+        assert_eq!(color.into(), value);
+        assert_eq!(C::from(value), color);
+    }
+
     /// Convert color to integer and back again to test bit positions
     fn test_bpp24<C>(color: C, value: u32)
     where
@@ -250,6 +333,34 @@ mod tests {
         assert_eq!(C::from(value), color);
     }
 
+    #[test]
+    pub fn bit_positions_rgb332() {
+        test_bpp8(Rgb332::new(0b101, 0, 0), 0b101 << 3 + 2);
+        test_bpp8(Rgb332::new(0, 0b101, 0), 0b101 << 2);
+        test_bpp8(Rgb332::new(0, 0, 0b11), 0b11 << 0);
+    }
+
+    #[test]
+    pub fn bit_positions_bgr233() {
+        test_bpp8(Bgr233::new(0b101, 0, 0), 0b101 << 0);
+        test_bpp8(Bgr233::new(0, 0b101, 0), 0b101 << 3);
+        test_bpp8(Bgr233::new(0, 0, 0b11), 0b11 << 3 + 3);
+    }
+
+    #[test]
+    pub fn bit_positions_rgb444() {
+        test_bpp12(Rgb444::new(0b1001, 0, 0), 0b1001 << 4 + 4);
+        test_bpp12(Rgb444::new(0, 0b1001, 0), 0b1001 << 4);
+        test_bpp12(Rgb444::new(0, 0, 0b1001), 0b1001 << 0);
+    }
+
+    #[test]
+    pub fn bit_positions_bgr444() {
+        test_bpp12(Bgr444::new(0b1001, 0, 0), 0b1001 << 0);
+        test_bpp12(Bgr444::new(0, 0b1001, 0), 0b1001 << 4);
+        test_bpp12(Bgr444::new(0, 0, 0b1001), 0b1001 << 4 + 4);
+    }
+
     #[test]
     pub fn bit_positions_rgb555() {
         test_bpp16(Rgb555::new(0b10001, 0, 0), 0b10001 << 5 + 5);
@@ -278,6 +389,20 @@ mod tests {
         test_bpp16(Bgr565::new(0, 0, 0b10001), 0b10001 << 5 + 6);
     }
 
+    #[test]
+    pub fn bit_positions_rgb666() {
+        test_bpp18(Rgb666::new(0b100001, 0, 0), 0b100001 << 6 + 6);
+        test_bpp18(Rgb666::new(0, 0b100001, 0), 0b100001 << 6);
+        test_bpp18(Rgb666::new(0, 0, 0b100001), 0b100001 << 0);
+    }
+
+    #[test]
+    pub fn bit_positions_bgr666() {
+        test_bpp18(Bgr666::new(0b100001, 0, 0), 0b100001 << 0);
+        test_bpp18(Bgr666::new(0, 0b100001, 0), 0b100001 << 6);
+        test_bpp18(Bgr666::new(0, 0, 0b100001), 0b100001 << 6 + 6);
+    }
+
     #[test]
     pub fn bit_positions_rgb888() {
         test_bpp24(Rgb888::new(0b10000001, 0, 0), 0b10000001 << 8 + 8);
@@ -299,6 +424,12 @@ mod tests {
 
         let color: Bgr555 = RawU16::from(0xFFFF).into();
         assert_eq!(RawU16::from(color).into_inner(), 0x7FFF);
+
+        let color: Rgb444 = RawU12::from(0xFFFF).into();
+        assert_eq!(RawU12::from(color).into_inner(), 0xFFF);
+
+        let color: Rgb666 = RawU18::from(0xFFFF_FFFF).into();
+        assert_eq!(RawU18::from(color).into_inner(), 0x3FFFF);
     }
 
     #[test]
@@ -1,6 +1,9 @@
 //! Named colors as defined in the CSS specification.
 
-use crate::pixelcolor::{Bgr555, Bgr565, Bgr888, PixelColor, Rgb555, Rgb565, Rgb888, RgbColor};
+use crate::pixelcolor::{
+    Bgr233, Bgr444, Bgr555, Bgr565, Bgr666, Bgr888, PixelColor, Rgb332, Rgb444, Rgb555, Rgb565,
+    Rgb666, Rgb888, RgbColor,
+};
 
 macro_rules! color_doc {
     (@internal $text:expr, $($rest:tt)*) => {
@@ -66,7 +69,10 @@ macro_rules! web_colors {
 
 #[rustfmt::skip]
 web_colors!(
-    (Rgb555, Rgb565, Rgb888, Bgr555, Bgr565, Bgr888),
+    (
+        Rgb332, Rgb444, Rgb555, Rgb565, Rgb666, Rgb888,
+        Bgr233, Bgr444, Bgr555, Bgr565, Bgr666, Bgr888
+    ),
     [
         (CSS_ALICE_BLUE, "Alice Blue", (240, 248, 255)),
         (CSS_ANTIQUE_WHITE, "Antique White", (250, 235, 215)),
@@ -219,31 +225,55 @@ mod tests {
 
     #[test]
     fn max_channels() {
+        assert_eq!(Rgb332::WHITE, Rgb332::CSS_WHITE);
+        assert_eq!(Bgr233::WHITE, Bgr233::CSS_WHITE);
+        assert_eq!(Rgb444::WHITE, Rgb444::CSS_WHITE);
+        assert_eq!(Bgr444::WHITE, Bgr444::CSS_WHITE);
         assert_eq!(Rgb555::WHITE, Rgb555::CSS_WHITE);
         assert_eq!(Bgr555::WHITE, Bgr555::CSS_WHITE);
         assert_eq!(Rgb565::WHITE, Rgb565::CSS_WHITE);
         assert_eq!(Bgr565::WHITE, Bgr565::CSS_WHITE);
+        assert_eq!(Rgb666::WHITE, Rgb666::CSS_WHITE);
+        assert_eq!(Bgr666::WHITE, Bgr666::CSS_WHITE);
         assert_eq!(Rgb888::WHITE, Rgb888::CSS_WHITE);
         assert_eq!(Bgr888::WHITE, Bgr888::CSS_WHITE);
 
+        assert_eq!(Rgb332::RED, Rgb332::CSS_RED);
+        assert_eq!(Bgr233::RED, Bgr233::CSS_RED);
+        assert_eq!(Rgb444::RED, Rgb444::CSS_RED);
+        assert_eq!(Bgr444::RED, Bgr444::CSS_RED);
         assert_eq!(Rgb555::RED, Rgb555::CSS_RED);
         assert_eq!(Bgr555::RED, Bgr555::CSS_RED);
         assert_eq!(Rgb565::RED, Rgb565::CSS_RED);
         assert_eq!(Bgr565::RED, Bgr565::CSS_RED);
+        assert_eq!(Rgb666::RED, Rgb666::CSS_RED);
+        assert_eq!(Bgr666::RED, Bgr666::CSS_RED);
         assert_eq!(Rgb888::RED, Rgb888::CSS_RED);
         assert_eq!(Bgr888::RED, Bgr888::CSS_RED);
 
+        assert_eq!(Rgb332::GREEN, Rgb332::CSS_LIME);
+        assert_eq!(Bgr233::GREEN, Bgr233::CSS_LIME);
+        assert_eq!(Rgb444::GREEN, Rgb444::CSS_LIME);
+        assert_eq!(Bgr444::GREEN, Bgr444::CSS_LIME);
         assert_eq!(Rgb555::GREEN, Rgb555::CSS_LIME);
         assert_eq!(Bgr555::GREEN, Bgr555::CSS_LIME);
         assert_eq!(Rgb565::GREEN, Rgb565::CSS_LIME);
         assert_eq!(Bgr565::GREEN, Bgr565::CSS_LIME);
+        assert_eq!(Rgb666::GREEN, Rgb666::CSS_LIME);
+        assert_eq!(Bgr666::GREEN, Bgr666::CSS_LIME);
         assert_eq!(Rgb888::GREEN, Rgb888::CSS_LIME);
         assert_eq!(Bgr888::GREEN, Bgr888::CSS_LIME);
 
+        assert_eq!(Rgb332::BLUE, Rgb332::CSS_BLUE);
+        assert_eq!(Bgr233::BLUE, Bgr233::CSS_BLUE);
+        assert_eq!(Rgb444::BLUE, Rgb444::CSS_BLUE);
+        assert_eq!(Bgr444::BLUE, Bgr444::CSS_BLUE);
         assert_eq!(Rgb555::BLUE, Rgb555::CSS_BLUE);
         assert_eq!(Bgr555::BLUE, Bgr555::CSS_BLUE);
         assert_eq!(Rgb565::BLUE, Rgb565::CSS_BLUE);
         assert_eq!(Bgr565::BLUE, Bgr565::CSS_BLUE);
+        assert_eq!(Rgb666::BLUE, Rgb666::CSS_BLUE);
+        assert_eq!(Bgr666::BLUE, Bgr666::CSS_BLUE);
         assert_eq!(Rgb888::BLUE, Rgb888::CSS_BLUE);
         assert_eq!(Bgr888::BLUE, Bgr888::CSS_BLUE);
     }
@@ -252,5 +282,11 @@ mod tests {
     fn conversion() {
         // 24bit: 7FFF00
         assert_eq!(Rgb565::CSS_CHARTREUSE.into_storage(), 0x7fe0);
+        assert_eq!(Rgb332::CSS_CHARTREUSE.into_storage(), 0b0111_1100);
+        assert_eq!(Rgb444::CSS_CHARTREUSE.into_storage(), 0x7f0);
+        assert_eq!(
+            Rgb666::CSS_CHARTREUSE.into_storage(),
+            0b011111_111111_000000
+        );
     }
 }
@@ -108,3 +108,79 @@ pub enum Alignment {
     /// Right.
     Right,
 }
+
+/// Vertical alignment of a block of text.
+///
+/// The vertical alignment defines how multiple lines of text are positioned relative to the text
+/// position. The position of each line is additionally influenced by the [`Baseline`], which is
+/// applied to every line separately.
+///
+/// [`Baseline`]: enum.Baseline.html
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum VerticalAlignment {
+    /// Top.
+    ///
+    /// The first line is positioned at the text position and the following lines are placed
+    /// below it.
+    Top,
+    /// Middle.
+    ///
+    /// The block of lines is vertically centered around the text position.
+    Middle,
+    /// Bottom.
+    ///
+    /// The last line is positioned at the text position and the preceding lines are placed
+    /// above it.
+    Bottom,
+}
+
+/// Line height.
+///
+/// The line height is defined as the vertical distance between the baseline of two adjacent lines.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum LineHeight {
+    /// Absolute line height in pixels.
+    Pixels(u32),
+
+    /// Relative line height in percent of the default line height of the character style.
+    Percent(u32),
+}
+
+impl LineHeight {
+    /// Converts the line height to an absolute pixel value.
+    ///
+    /// The `base_line_height` is used to resolve relative line heights and is usually the value
+    /// returned by [`TextRenderer::line_height`].
+    ///
+    /// [`TextRenderer::line_height`]: trait.TextRenderer.html#tymethod.line_height
+    pub fn to_absolute(self, base_line_height: u32) -> u32 {
+        match self {
+            Self::Pixels(pixels) => pixels,
+            Self::Percent(percent) => {
+                let height = u64::from(base_line_height) * u64::from(percent) / 100;
+
+                height.min(u64::from(u32::max_value())) as u32
+            }
+        }
+    }
+}
+
+impl Default for LineHeight {
+    fn default() -> Self {
+        Self::Percent(100)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_height_to_absolute() {
+        assert_eq!(LineHeight::Pixels(15).to_absolute(10), 15);
+        assert_eq!(LineHeight::Percent(100).to_absolute(10), 10);
+        assert_eq!(LineHeight::Percent(150).to_absolute(10), 15);
+        assert_eq!(LineHeight::Percent(0).to_absolute(10), 0);
+        assert_eq!(LineHeight::default().to_absolute(7), 7);
+    }
+}
@@ -0,0 +1,23 @@
+/// 6x11 pixel anti-aliased monospace font.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct FontDejaVuSansMono10;
+
+impl GrayMonoFont for FontDejaVuSansMono10 {
+    const FONT_IMAGE: &'static [u8] = include_bytes!("DejaVuSansMono10.raw");
+    const FONT_IMAGE_WIDTH: u32 = 96;
+    const COVERAGE_DEPTH: CoverageDepth = CoverageDepth::Bits2;
+
+    const CHARACTER_SIZE: Size = Size::new(6, 11);
+    const BASELINE: Option<i32> = Some(7);
+
+    const UNDERLINE_OFFSET: i32 = 7 + 2;
+
+    fn char_offset(c: char) -> u32 {
+        let c = c as u32;
+
+        match c {
+            0x20..=0x7F => c - 0x20,
+            _ => '?' as u32 - ' ' as u32,
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{
+    draw_target::{DrawTarget, GetPixel},
+    geometry::Dimensions,
+    pixelcolor::{BlendMode, ColorMath},
+    primitives::Rectangle,
+    Pixel,
+};
+use core::iter;
+
+/// Blending draw target.
+///
+/// Created by calling [`blended`] on any [`DrawTarget`] which implements [`GetPixel`].
+/// See the [`blended`] method documentation for more information.
+///
+/// [`DrawTarget`]: trait.DrawTarget.html
+/// [`GetPixel`]: trait.GetPixel.html
+/// [`blended`]: trait.DrawTargetExt.html#tymethod.blended
+#[derive(Debug)]
+pub struct Blended<'a, T> {
+    /// The parent draw target.
+    parent: &'a mut T,
+
+    /// The blend mode.
+    mode: BlendMode,
+}
+
+impl<'a, T> Blended<'a, T>
+where
+    T: GetPixel,
+    T::Color: ColorMath,
+{
+    pub(super) fn new(parent: &'a mut T, mode: BlendMode) -> Self {
+        Self { parent, mode }
+    }
+}
+
+impl<T> DrawTarget for Blended<'_, T>
+where
+    T: GetPixel,
+    T::Color: ColorMath,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        if self.mode == BlendMode::Normal {
+            return self.parent.draw_iter(pixels);
+        }
+
+        for Pixel(point, color) in pixels {
+            let color = match self.parent.pixel(point) {
+                Some(existing) => self.mode.blend(existing, color),
+                None => color,
+            };
+
+            self.parent.draw_iter(iter::once(Pixel(point, color)))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Dimensions for Blended<'_, T>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        draw_target::{DrawTarget, DrawTargetExt},
+        geometry::{Point, Size},
+        mock_display::MockDisplay,
+        pixelcolor::{BinaryColor, BlendMode, Gray4, GrayColor},
+        primitives::{Primitive, PrimitiveStyle, Rectangle},
+        Drawable,
+    };
+
+    #[test]
+    fn xor_binary_color() {
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+
+        Rectangle::new(Point::new(0, 0), Size::new(4, 2))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display)
+            .unwrap();
+
+        let cursor = Rectangle::new(Point::new(2, 0), Size::new(3, 3))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+
+        cursor.draw(&mut display.blended(BlendMode::Xor)).unwrap();
+
+        display.assert_pattern(&[
+            "##..#", //
+            "##..#", //
+            "  ###", //
+        ]);
+
+        // Drawing the cursor again restores the original content.
+        cursor.draw(&mut display.blended(BlendMode::Xor)).unwrap();
+
+        display.assert_pattern(&[
+            "####.", //
+            "####.", //
+            "  ...", //
+        ]);
+    }
+
+    #[test]
+    fn add_gray() {
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+
+        display
+            .fill_solid(
+                &Rectangle::new(Point::zero(), Size::new(3, 1)),
+                Gray4::new(0x5),
+            )
+            .unwrap();
+
+        let mut blended = display.blended(BlendMode::Add);
+        blended
+            .fill_contiguous(
+                &Rectangle::new(Point::zero(), Size::new(4, 1)),
+                [
+                    Gray4::new(0x1),
+                    Gray4::new(0xA),
+                    Gray4::new(0xF),
+                    Gray4::new(0x3),
+                ]
+                .iter()
+                .copied(),
+            )
+            .unwrap();
+
+        display.assert_pattern(&["6FF3"]);
+    }
+
+    #[test]
+    fn normal_mode_is_passed_through() {
+        let mut display = MockDisplay::new();
+
+        display
+            .blended(BlendMode::Normal)
+            .clear(Gray4::WHITE)
+            .unwrap();
+
+        assert_eq!(display.get_pixel(Point::new(63, 63)), Some(Gray4::WHITE));
+    }
+}
@@ -1,42 +1,54 @@
 use crate::{
-    draw_target::DrawTarget, geometry::Dimensions, pixelcolor::PixelColor, primitives::Rectangle,
+    draw_target::DrawTarget,
+    geometry::Dimensions,
+    pixelcolor::{ColorConversion, DefaultConversion, PixelColor},
+    primitives::Rectangle,
     Pixel,
 };
 use core::marker::PhantomData;
 
 /// Color conversion draw target.
 ///
-/// Created by calling [`color_converted`] on any [`DrawTarget`].
+/// Created by calling [`color_converted`], [`color_converted_with`] or [`map_colors`] on any
+/// [`DrawTarget`].
 /// See the [`color_converted`] method documentation for more information.
 ///
 /// [`DrawTarget`]: trait.DrawTarget.html
 /// [`color_converted`]: trait.DrawTargetExt.html#tymethod.color_converted
+/// [`color_converted_with`]: trait.DrawTargetExt.html#tymethod.color_converted_with
+/// [`map_colors`]: trait.DrawTargetExt.html#tymethod.map_colors
 #[derive(Debug)]
-pub struct ColorConverted<'a, T, C> {
+pub struct ColorConverted<'a, T, C, M = DefaultConversion> {
     /// The parent draw target.
     parent: &'a mut T,
 
+    /// The color conversion.
+    conversion: M,
+
     /// The input color type.
     color_type: PhantomData<C>,
 }
 
-impl<'a, T, C> ColorConverted<'a, T, C>
+impl<'a, T, C, M> ColorConverted<'a, T, C, M>
 where
     T: DrawTarget,
-    C: PixelColor + Into<T::Color>,
+    C: PixelColor,
+    M: ColorConversion<C, T::Color>,
 {
-    pub(super) fn new(parent: &'a mut T) -> Self {
+    pub(super) fn new(parent: &'a mut T, conversion: M) -> Self {
         Self {
             parent,
+            conversion,
             color_type: PhantomData,
         }
     }
 }
 
-impl<T, C> DrawTarget for ColorConverted<'_, T, C>
+impl<T, C, M> DrawTarget for ColorConverted<'_, T, C, M>
 where
     T: DrawTarget,
-    C: PixelColor + Into<T::Color>,
+    C: PixelColor,
+    M: ColorConversion<C, T::Color>,
 {
     type Color = C;
     type Error = T::Error;
@@ -45,28 +57,35 @@ where
     where
         I: IntoIterator<Item = Pixel<Self::Color>>,
     {
-        self.parent
-            .draw_iter(pixels.into_iter().map(|Pixel(p, c)| Pixel(p, c.into())))
+        let conversion = &self.conversion;
+
+        self.parent.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(p, c)| Pixel(p, conversion.convert(c))),
+        )
     }
 
     fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
     where
         I: IntoIterator<Item = Self::Color>,
     {
+        let conversion = &self.conversion;
+
         self.parent
-            .fill_contiguous(area, colors.into_iter().map(|c| c.into()))
+            .fill_contiguous(area, colors.into_iter().map(|c| conversion.convert(c)))
     }
 
     fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
-        self.parent.fill_solid(area, color.into())
+        self.parent.fill_solid(area, self.conversion.convert(color))
     }
 
     fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
-        self.parent.clear(color.into())
+        self.parent.clear(self.conversion.convert(color))
     }
 }
 
-impl<T, C> Dimensions for ColorConverted<'_, T, C>
+impl<T, C, M> Dimensions for ColorConverted<'_, T, C, M>
 where
     T: DrawTarget,
 {
@@ -74,3 +93,114 @@ where
         self.parent.bounding_box()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        draw_target::{DrawTarget, DrawTargetExt},
+        geometry::{Point, Size},
+        mock_display::MockDisplay,
+        pixelcolor::{
+            BinaryColor, Gray2, Gray8, LookupTable, LumaWeights, Rgb888, RgbColor, Threshold,
+        },
+        primitives::Rectangle,
+        Pixel,
+    };
+
+    #[test]
+    fn default_conversion() {
+        let mut display = MockDisplay::<Rgb888>::new();
+
+        display
+            .color_converted()
+            .fill_solid(
+                &Rectangle::new(Point::new(1, 0), Size::new(2, 1)),
+                BinaryColor::On,
+            )
+            .unwrap();
+
+        display.assert_pattern(&[" WW"]);
+    }
+
+    #[test]
+    fn custom_conversion() {
+        let mut display = MockDisplay::<Gray8>::new();
+        let mut converted = display.color_converted_with(LumaWeights::Bt601);
+
+        converted
+            .fill_contiguous(
+                &Rectangle::new(Point::zero(), Size::new(3, 1)),
+                [Rgb888::WHITE, Rgb888::BLACK, Rgb888::new(0x44, 0x44, 0x44)]
+                    .iter()
+                    .copied(),
+            )
+            .unwrap();
+
+        converted
+            .draw_iter(
+                [Pixel(Point::new(3, 0), Rgb888::new(0x88, 0x88, 0x88))]
+                    .iter()
+                    .copied(),
+            )
+            .unwrap();
+
+        display.assert_pattern(&["F048"]);
+    }
+
+    #[test]
+    fn map_colors() {
+        let mut display = MockDisplay::<Rgb888>::new();
+        let mut mapped = display.map_colors(|color| match color {
+            BinaryColor::Off => Rgb888::RED,
+            BinaryColor::On => Rgb888::GREEN,
+        });
+
+        mapped
+            .fill_contiguous(
+                &Rectangle::new(Point::zero(), Size::new(2, 1)),
+                [BinaryColor::Off, BinaryColor::On].iter().copied(),
+            )
+            .unwrap();
+        mapped
+            .fill_solid(
+                &Rectangle::new(Point::new(2, 0), Size::new(2, 1)),
+                BinaryColor::Off,
+            )
+            .unwrap();
+
+        display.assert_pattern(&["RGRR"]);
+    }
+
+    #[test]
+    fn lookup_table() {
+        let mut display = MockDisplay::<Rgb888>::new();
+        let table = LookupTable::new(&[Rgb888::BLACK, Rgb888::BLUE, Rgb888::CYAN, Rgb888::WHITE]);
+
+        display
+            .color_converted_with(table)
+            .draw_iter(
+                [
+                    Pixel(Point::new(0, 0), Gray2::new(3)),
+                    Pixel(Point::new(1, 0), Gray2::new(1)),
+                    Pixel(Point::new(2, 0), Gray2::new(2)),
+                ]
+                .iter()
+                .copied(),
+            )
+            .unwrap();
+
+        display.assert_pattern(&["WBC"]);
+    }
+
+    #[test]
+    fn threshold() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+
+        display
+            .color_converted_with(Threshold::new(200, LumaWeights::Bt709))
+            .clear(Rgb888::YELLOW)
+            .unwrap();
+
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(BinaryColor::On));
+    }
+}
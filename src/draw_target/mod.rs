@@ -1,19 +1,44 @@
 //! A target for embedded-graphics drawing operations.
 
+mod blended;
 mod clipped;
 mod color_converted;
 mod cropped;
+mod page_buffer;
+mod transformed;
 mod translated;
 
-use crate::{geometry::Point, pixelcolor::PixelColor, primitives::Rectangle};
+use crate::{
+    geometry::{AffineTransform, Point},
+    pixelcolor::{BlendMode, ColorConversion, ColorMath, DefaultConversion, PixelColor},
+    primitives::Rectangle,
+};
 
+pub use blended::Blended;
 pub use clipped::Clipped;
 pub use color_converted::ColorConverted;
 pub use cropped::Cropped;
+pub use page_buffer::PageBuffer;
+pub use transformed::Transformed;
 pub use translated::Translated;
 
 pub use embedded_graphics_core::draw_target::DrawTarget;
 
+/// Draw target which can return the color of a pixel.
+///
+/// `GetPixel` is implemented by draw targets which store the drawn pixels, like framebuffers. It
+/// is required by draw target adapters which combine new colors with the existing colors, like
+/// [`blended`].
+///
+/// [`blended`]: trait.DrawTargetExt.html#tymethod.blended
+pub trait GetPixel: DrawTarget {
+    /// Returns the color of the pixel at the given point.
+    ///
+    /// Returns `None` if the point is outside the draw target or if the color of the pixel is
+    /// unknown.
+    fn pixel(&self, point: Point) -> Option<Self::Color>;
+}
+
 /// Extension trait for `DrawTarget`s.
 pub trait DrawTargetExt: DrawTarget + Sized {
     /// Creates a translated draw target based on this draw target.
@@ -213,6 +238,197 @@ pub trait DrawTargetExt: DrawTarget + Sized {
     fn color_converted<C>(&mut self) -> ColorConverted<'_, Self, C>
     where
         C: PixelColor + Into<Self::Color>;
+
+    /// Creates a color conversion draw target which uses a custom color conversion.
+    ///
+    /// Works like [`color_converted`], but converts colors by using the given
+    /// [`ColorConversion`] instead of the `Into` trait. This makes it possible to select a
+    /// conversion strategy, like luma weights, gamma correct conversions or a threshold for
+    /// conversions to `BinaryColor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::{BinaryColor, LumaWeights, Rgb888, RgbColor, Threshold},
+    ///     prelude::*,
+    ///     primitives::{PrimitiveStyle, Rectangle},
+    /// };
+    ///
+    /// let mut display = MockDisplay::<BinaryColor>::new();
+    ///
+    /// // All colors with a luma of at least 100 are drawn as `BinaryColor::On`.
+    /// let threshold = Threshold::new(100, LumaWeights::Bt709);
+    /// let mut converted = display.color_converted_with(threshold);
+    ///
+    /// Rectangle::new(Point::new(0, 0), Size::new(2, 2))
+    ///     .into_styled(PrimitiveStyle::with_fill(Rgb888::GREEN))
+    ///     .draw(&mut converted)?;
+    ///
+    /// Rectangle::new(Point::new(2, 0), Size::new(2, 2))
+    ///     .into_styled(PrimitiveStyle::with_fill(Rgb888::BLUE))
+    ///     .draw(&mut converted)?;
+    ///
+    /// display.assert_pattern(&[
+    ///     "##..", //
+    ///     "##..", //
+    /// ]);
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`color_converted`]: #tymethod.color_converted
+    /// [`ColorConversion`]: ../pixelcolor/trait.ColorConversion.html
+    fn color_converted_with<C, M>(&mut self, conversion: M) -> ColorConverted<'_, Self, C, M>
+    where
+        C: PixelColor,
+        M: ColorConversion<C, Self::Color>;
+
+    /// Creates a color mapping draw target based on this draw target.
+    ///
+    /// All colors are mapped by calling `f` before they are passed to the parent draw target.
+    /// This can be used to draw `BinaryColor` drawables with arbitrary foreground and background
+    /// colors, to implement palettes or to invert colors. `fill_solid` and `clear` only call `f`
+    /// once and `fill_contiguous` is passed through to the parent draw target, which keeps the
+    /// optimized implementations of these methods in the parent draw target usable.
+    ///
+    /// Precomputed palettes can be used by passing a [`LookupTable`] to
+    /// [`color_converted_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     mono_font::{ascii::Font6x9, MonoTextStyleBuilder},
+    ///     pixelcolor::{BinaryColor, Rgb565, RgbColor},
+    ///     prelude::*,
+    ///     text::Text,
+    /// };
+    ///
+    /// let mut display = MockDisplay::<Rgb565>::new();
+    ///
+    /// // Draw white text on a blue background.
+    /// let mut mapped = display.map_colors(|color| match color {
+    ///     BinaryColor::On => Rgb565::WHITE,
+    ///     BinaryColor::Off => Rgb565::BLUE,
+    /// });
+    ///
+    /// let style = MonoTextStyleBuilder::new()
+    ///     .font(Font6x9)
+    ///     .text_color(BinaryColor::On)
+    ///     .background_color(BinaryColor::Off)
+    ///     .build();
+    ///
+    /// Text::new("Text", Point::new(0, 6))
+    ///     .into_styled(style)
+    ///     .draw(&mut mapped)?;
+    /// #
+    /// # assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Rgb565::BLUE));
+    /// # assert_eq!(display.get_pixel(Point::new(1, 1)), Some(Rgb565::WHITE));
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`LookupTable`]: ../pixelcolor/struct.LookupTable.html
+    /// [`color_converted_with`]: #tymethod.color_converted_with
+    fn map_colors<C, F>(&mut self, f: F) -> ColorConverted<'_, Self, C, F>
+    where
+        C: PixelColor,
+        F: Fn(C) -> Self::Color;
+
+    /// Creates a blending draw target based on this draw target.
+    ///
+    /// All colors are combined with the existing color of the pixel in the parent draw target by
+    /// using the given [`BlendMode`], before they are drawn to the parent draw target. This
+    /// requires a draw target that implements [`GetPixel`] to read the existing colors, like a
+    /// framebuffer. If the parent draw target doesn't return a color for a pixel the new color is
+    /// drawn unchanged.
+    ///
+    /// All drawing operations are performed pixel by pixel, except if [`BlendMode::Normal`] is
+    /// used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     draw_target::{GetPixel, PageBuffer},
+    ///     pixelcolor::{BinaryColor, BlendMode},
+    ///     prelude::*,
+    ///     primitives::{PrimitiveStyle, Rectangle},
+    /// };
+    ///
+    /// let mut data = [0u8; 16];
+    /// let mut buffer = PageBuffer::new(&mut data, 16, 8);
+    ///
+    /// Rectangle::new(Point::new(0, 0), Size::new(8, 8))
+    ///     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+    ///     .draw(&mut buffer)?;
+    ///
+    /// // Draw an inverting cursor. Drawing it again would restore the original content.
+    /// Rectangle::new(Point::new(6, 0), Size::new(4, 8))
+    ///     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+    ///     .draw(&mut buffer.blended(BlendMode::Xor))?;
+    ///
+    /// assert_eq!(buffer.pixel(Point::new(5, 0)), Some(BinaryColor::On));
+    /// assert_eq!(buffer.pixel(Point::new(6, 0)), Some(BinaryColor::Off));
+    /// assert_eq!(buffer.pixel(Point::new(8, 0)), Some(BinaryColor::On));
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`BlendMode`]: ../pixelcolor/enum.BlendMode.html
+    /// [`BlendMode::Normal`]: ../pixelcolor/enum.BlendMode.html#variant.Normal
+    /// [`GetPixel`]: trait.GetPixel.html
+    fn blended(&mut self, mode: BlendMode) -> Blended<'_, Self>
+    where
+        Self: GetPixel,
+        Self::Color: ColorMath;
+
+    /// Creates an affine transformed draw target based on this draw target.
+    ///
+    /// All pixels are transformed by `transform` before being passed to the parent draw target,
+    /// which makes it possible to draw any drawable, like text, images or primitives, rotated or
+    /// scaled.
+    ///
+    /// Each pixel is transformed separately, which leaves holes between the drawn pixels if the
+    /// transform scales up or rotates the drawing. Use [`with_supersampling`] to fill these holes.
+    ///
+    /// # Performance
+    ///
+    /// All drawing operations are converted into individual pixels, which makes drawing to a
+    /// transformed draw target slower than drawing to the parent draw target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     geometry::AffineTransform,
+    ///     mock_display::MockDisplay,
+    ///     mono_font::{ascii::Font6x9, MonoTextStyle},
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::Text,
+    /// };
+    ///
+    /// let mut display = MockDisplay::new();
+    /// display.set_allow_overdraw(true);
+    ///
+    /// // Rotate by 30° and scale to twice the size.
+    /// let transform = AffineTransform::identity()
+    ///     .rotated(30.0.deg())
+    ///     .scaled(2, 2)
+    ///     .translated(Point::new(5, 5));
+    ///
+    /// let mut transformed_display = display.transformed(&transform).with_supersampling(3);
+    ///
+    /// Text::new("Text", Point::new(0, 6))
+    ///     .into_styled(MonoTextStyle::new(Font6x9, BinaryColor::On))
+    ///     .draw(&mut transformed_display)?;
+    /// #
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`with_supersampling`]: struct.Transformed.html#method.with_supersampling
+    fn transformed(&mut self, transform: &AffineTransform) -> Transformed<'_, Self>;
 }
 
 impl<T> DrawTargetExt for T
@@ -235,7 +451,35 @@ where
     where
         C: PixelColor + Into<Self::Color>,
     {
-        ColorConverted::new(self)
+        ColorConverted::new(self, DefaultConversion)
+    }
+
+    fn color_converted_with<C, M>(&mut self, conversion: M) -> ColorConverted<'_, Self, C, M>
+    where
+        C: PixelColor,
+        M: ColorConversion<C, Self::Color>,
+    {
+        ColorConverted::new(self, conversion)
+    }
+
+    fn map_colors<C, F>(&mut self, f: F) -> ColorConverted<'_, Self, C, F>
+    where
+        C: PixelColor,
+        F: Fn(C) -> Self::Color,
+    {
+        ColorConverted::new(self, f)
+    }
+
+    fn blended(&mut self, mode: BlendMode) -> Blended<'_, Self>
+    where
+        Self: GetPixel,
+        Self::Color: ColorMath,
+    {
+        Blended::new(self, mode)
+    }
+
+    fn transformed(&mut self, transform: &AffineTransform) -> Transformed<'_, Self> {
+        Transformed::new(self, transform)
     }
 }
 
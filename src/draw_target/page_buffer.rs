@@ -0,0 +1,334 @@
+use core::convert::Infallible;
+
+use crate::{
+    draw_target::{DrawTarget, GetPixel},
+    geometry::{Dimensions, OriginDimensions, Point, Size},
+    image::{pages, ImageRawPages},
+    pixelcolor::BinaryColor,
+    primitives::Rectangle,
+    Pixel,
+};
+
+/// Monochrome framebuffer in vertical page layout.
+///
+/// `PageBuffer` is a draw target which stores the pixels in a byte slice, using the same layout
+/// as monochrome display controllers like the SSD1306, SH1106 or ST7565: the buffer is divided
+/// into pages of 8 rows and each byte contains 8 vertically stacked pixels, with the least
+/// significant bit at the top. The content of the buffer can be sent to the display without any
+/// conversion, either at once or page by page by using the [`page`] method.
+///
+/// Images stored in the same layout can be copied into the buffer by using the [`blit`] method,
+/// which is much faster than drawing them pixel by pixel.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     draw_target::PageBuffer,
+///     image::ImageRawPages,
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::{Line, PrimitiveStyle},
+/// };
+///
+/// let mut data = [0u8; 128 * 64 / 8];
+/// let mut buffer = PageBuffer::new(&mut data, 128, 64);
+///
+/// Line::new(Point::new(0, 0), Point::new(0, 9))
+///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+///     .draw(&mut buffer)?;
+///
+/// assert_eq!(buffer.page(0)[0], 0xFF);
+/// assert_eq!(buffer.page(1)[0], 0x03);
+///
+/// // Copy an image in page layout into the buffer.
+/// let icon = ImageRawPages::new(&[0x0F, 0xF0], 2, 8);
+/// buffer.blit(&icon, Point::new(10, 4));
+///
+/// assert_eq!(&buffer.page(0)[10..12], &[0xF0, 0x00]);
+/// assert_eq!(&buffer.page(1)[10..12], &[0x00, 0x0F]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`page`]: #method.page
+/// [`blit`]: #method.blit
+#[derive(PartialEq, Eq, Hash, Debug)]
+pub struct PageBuffer<'a> {
+    /// Pixel data, organized in pages of 8 rows.
+    data: &'a mut [u8],
+
+    /// Buffer size in pixels
+    size: Size,
+}
+
+impl<'a> PageBuffer<'a> {
+    /// Creates a new page buffer.
+    ///
+    /// The buffer uses the existing content of `data`.
+    ///
+    /// # Panics
+    ///
+    /// If `data` doesn't have the correct length.
+    pub fn new(data: &'a mut [u8], width: u32, height: u32) -> Self {
+        assert_eq!(data.len(), width as usize * pages(height));
+
+        Self {
+            data,
+            size: Size::new(width, height),
+        }
+    }
+
+    /// Returns the buffer content.
+    pub fn data(&self) -> &[u8] {
+        self.data
+    }
+
+    /// Returns the data of a single page.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is larger than the index of the last page.
+    pub fn page(&self, index: usize) -> &[u8] {
+        let width = self.size.width as usize;
+
+        &self.data[index * width..(index + 1) * width]
+    }
+
+    /// Returns an image drawable which contains the buffer content.
+    pub fn as_image(&self) -> ImageRawPages<'_> {
+        ImageRawPages::new(self.data, self.size.width, self.size.height)
+    }
+
+    /// Copies an image into the buffer.
+    ///
+    /// The image data is copied byte by byte, by shifting the image pages into place if the top
+    /// edge isn't aligned to a page. Parts of the image outside the buffer are ignored.
+    pub fn blit(&mut self, image: &ImageRawPages<'_>, top_left: Point) {
+        let image_size = image.size();
+        let image_width = image_size.width as usize;
+
+        let area = Rectangle::new(top_left, image_size).intersection(&self.bounding_box());
+        if area.is_zero_sized() {
+            return;
+        }
+
+        let x_range = area.top_left.x..area.top_left.x + area.size.width as i32;
+
+        for (source_page, source) in image.data().chunks(image_width).enumerate() {
+            let y = top_left.y + source_page as i32 * 8;
+            let rows = image_size.height as i32 - source_page as i32 * 8;
+            let source_mask = page_mask(rows);
+
+            let page = y.div_euclid(8);
+            let shift = y.rem_euclid(8);
+
+            for x in x_range.clone() {
+                let byte = u16::from(source[(x - top_left.x) as usize] & source_mask) << shift;
+                let mask = u16::from(source_mask) << shift;
+
+                self.write_byte(page, x, byte as u8, mask as u8);
+                self.write_byte(page + 1, x, (byte >> 8) as u8, (mask >> 8) as u8);
+            }
+        }
+    }
+
+    /// Replaces the masked bits of a byte in the buffer.
+    ///
+    /// Bytes outside the buffer and bits below the last row are ignored.
+    fn write_byte(&mut self, page: i32, x: i32, value: u8, mask: u8) {
+        if page < 0 || page as usize >= pages(self.size.height) {
+            return;
+        }
+
+        let mask = mask & page_mask(self.size.height as i32 - page * 8);
+        let byte = &mut self.data[page as usize * self.size.width as usize + x as usize];
+
+        *byte = (*byte & !mask) | (value & mask);
+    }
+}
+
+/// Returns the mask of the used bits in a page with the given number of remaining rows.
+fn page_mask(rows: i32) -> u8 {
+    if rows >= 8 {
+        0xFF
+    } else {
+        (1u8 << rows) - 1
+    }
+}
+
+impl DrawTarget for PageBuffer<'_> {
+    type Color = BinaryColor;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bounding_box = self.bounding_box();
+        let width = self.size.width as usize;
+
+        for Pixel(point, color) in pixels
+            .into_iter()
+            .filter(|Pixel(point, _)| bounding_box.contains(*point))
+        {
+            let (x, y) = (point.x as usize, point.y as usize);
+            let byte = &mut self.data[y / 8 * width + x];
+            let mask = 1 << (y % 8);
+
+            if color.is_on() {
+                *byte |= mask;
+            } else {
+                *byte &= !mask;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let value = if color.is_on() { 0xFF } else { 0x00 };
+
+        for byte in self.data.iter_mut() {
+            *byte = value;
+        }
+
+        Ok(())
+    }
+}
+
+impl GetPixel for PageBuffer<'_> {
+    fn pixel(&self, point: Point) -> Option<Self::Color> {
+        if !self.bounding_box().contains(point) {
+            return None;
+        }
+
+        let (x, y) = (point.x as usize, point.y as usize);
+        let byte = self.data[y / 8 * self.size.width as usize + x];
+
+        Some(BinaryColor::from(byte & (1 << (y % 8)) != 0))
+    }
+}
+
+impl OriginDimensions for PageBuffer<'_> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        image::Image,
+        mock_display::MockDisplay,
+        primitives::{Primitive, PrimitiveStyle},
+        Drawable,
+    };
+
+    #[test]
+    fn draw_pixels() {
+        let mut data = [0; 8];
+        let mut buffer = PageBuffer::new(&mut data, 4, 10);
+
+        Pixel(Point::new(0, 0), BinaryColor::On)
+            .draw(&mut buffer)
+            .unwrap();
+        Pixel(Point::new(1, 7), BinaryColor::On)
+            .draw(&mut buffer)
+            .unwrap();
+        Pixel(Point::new(3, 9), BinaryColor::On)
+            .draw(&mut buffer)
+            .unwrap();
+        Pixel(Point::new(3, 10), BinaryColor::On)
+            .draw(&mut buffer)
+            .unwrap();
+
+        assert_eq!(data, [0x01, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02]);
+    }
+
+    #[test]
+    fn clear() {
+        let mut data = [0x55; 4];
+        let mut buffer = PageBuffer::new(&mut data, 2, 16);
+
+        buffer.clear(BinaryColor::On).unwrap();
+        assert_eq!(buffer.data(), &[0xFF; 4]);
+
+        buffer.clear(BinaryColor::Off).unwrap();
+        assert_eq!(buffer.data(), &[0x00; 4]);
+    }
+
+    #[test]
+    fn as_image() {
+        let mut data = [0; 6];
+        let mut buffer = PageBuffer::new(&mut data, 3, 12);
+
+        Rectangle::new(Point::new(1, 2), Size::new(2, 9))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut buffer)
+            .unwrap();
+
+        let mut display = MockDisplay::new();
+        Image::new(&buffer.as_image(), Point::zero())
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "...", //
+            "...", //
+            ".##", //
+            ".##", //
+            ".##", //
+            ".##", //
+            ".##", //
+            ".##", //
+            ".##", //
+            ".##", //
+            ".##", //
+            "...", //
+        ]);
+    }
+
+    /// Checks that blitting an image gives the same result as drawing it pixel by pixel.
+    fn assert_blit_matches_draw(top_left: Point) {
+        let image_data = [
+            0xA5, 0x3C, 0xFF, 0x81, 0x7E, // page 0
+            0x05, 0x02, 0x07, 0x01, 0x06, // page 1
+        ];
+        let image = ImageRawPages::new(&image_data, 5, 11);
+
+        let mut expected_data = [0x5A; 7 * 4];
+        let mut expected = PageBuffer::new(&mut expected_data, 7, 27);
+        Image::new(&image, top_left).draw(&mut expected).unwrap();
+
+        let mut data = [0x5A; 7 * 4];
+        let mut buffer = PageBuffer::new(&mut data, 7, 27);
+        buffer.blit(&image, top_left);
+
+        // Bits below the last row aren't used and are ignored in the comparison.
+        let last_page = 0x07;
+        for (i, (a, b)) in buffer.data().iter().zip(expected.data()).enumerate() {
+            let mask = if i >= 3 * 7 { last_page } else { 0xFF };
+            assert_eq!(a & mask, b & mask, "top_left: {:?}, byte: {}", top_left, i);
+        }
+    }
+
+    #[test]
+    fn blit() {
+        for y in -12..28 {
+            for &x in &[-5, -2, 0, 1, 3, 7] {
+                assert_blit_matches_draw(Point::new(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn blit_does_not_change_bits_below_last_row() {
+        let mut data = [0x00; 2];
+        let mut buffer = PageBuffer::new(&mut data, 1, 12);
+
+        buffer.blit(&ImageRawPages::new(&[0xFF, 0xFF], 1, 16), Point::zero());
+
+        assert_eq!(data, [0xFF, 0x0F]);
+    }
+}
@@ -0,0 +1,298 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{AffineTransform, Dimensions, Point},
+    pixelcolor::PixelColor,
+    primitives::Rectangle,
+    Pixel,
+};
+
+/// Transformed draw target.
+///
+/// Created by calling [`transformed`] on any [`DrawTarget`].
+/// See the [`transformed`] method documentation for more.
+///
+/// [`DrawTarget`]: trait.DrawTarget.html
+/// [`transformed`]: trait.DrawTargetExt.html#tymethod.transformed
+#[derive(Debug)]
+pub struct Transformed<'a, T>
+where
+    T: DrawTarget,
+{
+    parent: &'a mut T,
+    transform: AffineTransform,
+    supersampling: u32,
+}
+
+impl<'a, T> Transformed<'a, T>
+where
+    T: DrawTarget,
+{
+    pub(super) fn new(parent: &'a mut T, transform: &AffineTransform) -> Self {
+        Self {
+            parent,
+            transform: *transform,
+            supersampling: 1,
+        }
+    }
+
+    /// Sets the supersampling factor.
+    ///
+    /// Each drawn pixel is split into `factor` x `factor` samples, which are transformed
+    /// separately. This fills the holes between pixels that would otherwise occur if a transform
+    /// scales up or rotates the drawn pixels. The factor should be at least as large as the
+    /// largest scale factor of the transform. A factor of 2 is sufficient to fill the holes in
+    /// rotated drawings without scaling.
+    ///
+    /// Supersampling can cause pixels in the parent draw target to be drawn multiple times.
+    ///
+    /// A factor of 0 is treated as 1, which disables supersampling.
+    pub fn with_supersampling(mut self, factor: u32) -> Self {
+        self.supersampling = factor.max(1);
+
+        self
+    }
+}
+
+impl<T> DrawTarget for Transformed<'_, T>
+where
+    T: DrawTarget,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        self.parent.draw_iter(TransformedPixels::new(
+            pixels.into_iter(),
+            self.transform,
+            self.supersampling,
+        ))
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.parent.clear(color)
+    }
+}
+
+impl<T> Dimensions for Transformed<'_, T>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        let parent = self.parent.bounding_box();
+
+        let (inverse, bottom_right) = match (self.transform.inverse(), parent.bottom_right()) {
+            (Some(inverse), Some(bottom_right)) => (inverse, bottom_right),
+            _ => return Rectangle::zero(),
+        };
+
+        let top_left = parent.top_left;
+        let corners = [
+            top_left,
+            Point::new(bottom_right.x, top_left.y),
+            Point::new(top_left.x, bottom_right.y),
+            bottom_right,
+        ];
+
+        let mut min = Point::new(core::i32::MAX, core::i32::MAX);
+        let mut max = Point::new(core::i32::MIN, core::i32::MIN);
+        for corner in corners.iter() {
+            let corner = inverse.transform_point(*corner);
+
+            min = min.component_min(corner);
+            max = max.component_max(corner);
+        }
+
+        Rectangle::with_corners(min, max)
+    }
+}
+
+/// Iterator which transforms all pixels of another iterator.
+struct TransformedPixels<I>
+where
+    I: Iterator,
+{
+    pixels: I,
+    transform: AffineTransform,
+    supersampling: u32,
+    current: Option<I::Item>,
+    sample: u32,
+}
+
+impl<I, C> TransformedPixels<I>
+where
+    I: Iterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    fn new(pixels: I, transform: AffineTransform, supersampling: u32) -> Self {
+        Self {
+            pixels,
+            transform,
+            supersampling,
+            current: None,
+            sample: 0,
+        }
+    }
+
+    /// Returns the transformed position of a sample of the given point.
+    fn sample_position(&self, point: Point, sample: u32) -> Point {
+        let factor = i64::from(self.supersampling);
+        let one = i64::from(AffineTransform::ONE);
+
+        // Offset of the sample center relative to the pixel center.
+        let offset = |index: i64| (2 * index + 1) * one / (2 * factor) - one / 2;
+
+        let sample = i64::from(sample);
+        self.transform.transform_point_bits(
+            i64::from(point.x) * one + offset(sample % factor),
+            i64::from(point.y) * one + offset(sample / factor),
+        )
+    }
+}
+
+impl<I, C> Iterator for TransformedPixels<I>
+where
+    I: Iterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let samples = self.supersampling.pow(2);
+
+        loop {
+            if self.current.is_none() || self.sample >= samples {
+                self.current = Some(self.pixels.next()?);
+                self.sample = 0;
+            }
+
+            let Pixel(point, color) = self.current?;
+            let sample = self.sample;
+            self.sample += 1;
+
+            let position = self.sample_position(point, sample);
+
+            // Skip samples which map to the same position as a previous sample of this pixel.
+            if (0..sample).all(|previous| self.sample_position(point, previous) != position) {
+                return Some(Pixel(position, color));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        draw_target::{DrawTarget, DrawTargetExt},
+        geometry::{AffineTransform, AngleUnit, Dimensions, Point, Size},
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Primitive, PrimitiveStyle, Rectangle},
+        Drawable, Pixel,
+    };
+
+    #[test]
+    fn draw_iter() {
+        let mut display = MockDisplay::new();
+
+        let transform = AffineTransform::identity()
+            .rotated(90.0.deg())
+            .translated(Point::new(3, 0));
+        let mut transformed = display.transformed(&transform);
+
+        let pixels = [
+            Pixel(Point::new(0, 0), BinaryColor::On),
+            Pixel(Point::new(1, 0), BinaryColor::On),
+            Pixel(Point::new(2, 1), BinaryColor::Off),
+        ];
+        transformed.draw_iter(pixels.iter().copied()).unwrap();
+
+        display.assert_pattern(&[
+            "   #", //
+            "   #", //
+            "  . ", //
+        ]);
+    }
+
+    #[test]
+    fn large_coordinates() {
+        let offset = Point::new(i32::max_value() / 2, i32::max_value() / 2);
+        let transform = AffineTransform::identity().translated(-offset);
+
+        for &supersampling in &[1, 2] {
+            let mut display = MockDisplay::new();
+
+            Rectangle::new(offset + Point::new(1, 0), Size::new(2, 1))
+                .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+                .draw(
+                    &mut display
+                        .transformed(&transform)
+                        .with_supersampling(supersampling),
+                )
+                .unwrap();
+
+            display.assert_pattern(&[
+                " ##", //
+            ]);
+        }
+    }
+
+    #[test]
+    fn scaled_without_supersampling() {
+        let mut display = MockDisplay::new();
+
+        let transform = AffineTransform::identity().scaled(2, 2);
+
+        Rectangle::new(Point::zero(), Size::new(3, 2))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display.transformed(&transform))
+            .unwrap();
+
+        display.assert_pattern(&[
+            "# # #", //
+            "     ", //
+            "# # #", //
+        ]);
+    }
+
+    #[test]
+    fn scaled_with_supersampling() {
+        let mut display = MockDisplay::new();
+
+        let transform = AffineTransform::identity().scaled(2, 2);
+
+        Rectangle::new(Point::zero(), Size::new(3, 2))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display.transformed(&transform).with_supersampling(2))
+            .unwrap();
+
+        display.assert_pattern(&[
+            "######", //
+            "######", //
+            "######", //
+            "######", //
+        ]);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+
+        let transform = AffineTransform::identity()
+            .scaled(2, 2)
+            .translated(Point::new(10, 0));
+
+        assert_eq!(
+            display.transformed(&transform).bounding_box(),
+            Rectangle::with_corners(Point::new(-5, 0), Point::new(27, 32))
+        );
+
+        let transform = AffineTransform::identity().scaled(0, 2);
+        assert_eq!(
+            display.transformed(&transform).bounding_box(),
+            Rectangle::zero()
+        );
+    }
+}
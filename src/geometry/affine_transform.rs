@@ -0,0 +1,352 @@
+use crate::geometry::{Angle, Point, Trigonometry};
+
+/// 2D affine transformation.
+///
+/// An `AffineTransform` maps points by multiplying them with a 2x2 matrix and adding an offset.
+/// All values are stored as 16.16 fixed point numbers, which makes it possible to apply the
+/// transform without using floating point arithmetic. The fixed point values are stored in 64 bit
+/// integers to support the full range of `i32` coordinates.
+///
+/// Transformations can be combined by chaining the [`rotated`], [`scaled`], [`scaled_fixed`]
+/// and [`translated`] methods. Each method appends an operation, which is applied after all
+/// previous operations.
+///
+/// The y axis of the display coordinate system points down, which means that positive rotation
+/// angles rotate points clockwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::{geometry::AffineTransform, prelude::*};
+///
+/// let transform = AffineTransform::identity()
+///     .scaled(2, 2)
+///     .rotated(90.0.deg())
+///     .translated(Point::new(10, 20));
+///
+/// assert_eq!(transform.transform_point(Point::new(3, 0)), Point::new(10, 26));
+/// ```
+///
+/// [`rotated`]: #method.rotated
+/// [`scaled`]: #method.scaled
+/// [`scaled_fixed`]: #method.scaled_fixed
+/// [`translated`]: #method.translated
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct AffineTransform {
+    /// Matrix in row major order.
+    matrix: [i64; 4],
+
+    /// Offset.
+    offset: [i64; 2],
+}
+
+impl AffineTransform {
+    /// Number of fractional bits used by fixed point values.
+    pub const FRACTIONAL_BITS: u32 = 16;
+
+    /// Fixed point representation of 1.
+    pub const ONE: i32 = 1 << Self::FRACTIONAL_BITS;
+
+    /// Creates an identity transform.
+    pub const fn identity() -> Self {
+        Self {
+            matrix: [Self::ONE as i64, 0, 0, Self::ONE as i64],
+            offset: [0, 0],
+        }
+    }
+
+    /// Creates a transform from a raw 16.16 fixed point matrix and offset.
+    ///
+    /// A point `(x, y)` is mapped to `(a * x + b * y + offset.x, c * x + d * y + offset.y)`, with
+    /// `matrix` being `[a, b, c, d]`.
+    pub const fn from_bits(matrix: [i32; 4], offset: [i32; 2]) -> Self {
+        Self {
+            matrix: [
+                matrix[0] as i64,
+                matrix[1] as i64,
+                matrix[2] as i64,
+                matrix[3] as i64,
+            ],
+            offset: [offset[0] as i64, offset[1] as i64],
+        }
+    }
+
+    /// Appends a rotation around the origin.
+    pub fn rotated(&self, angle: Angle) -> Self {
+        let cos = to_bits(angle.cos().into());
+        let sin = to_bits(angle.sin().into());
+
+        self.then(&Self {
+            matrix: [cos, -sin, sin, cos],
+            offset: [0, 0],
+        })
+    }
+
+    /// Appends an integer scaling relative to the origin.
+    pub fn scaled(&self, x: i32, y: i32) -> Self {
+        self.then(&Self {
+            matrix: [to_fixed(x), 0, 0, to_fixed(y)],
+            offset: [0, 0],
+        })
+    }
+
+    /// Appends a scaling relative to the origin using 16.16 fixed point scale factors.
+    pub fn scaled_fixed(&self, x: i32, y: i32) -> Self {
+        self.then(&Self::from_bits([x, 0, 0, y], [0, 0]))
+    }
+
+    /// Appends a translation.
+    pub fn translated(&self, offset: Point) -> Self {
+        self.then(&Self {
+            matrix: Self::identity().matrix,
+            offset: [to_fixed(offset.x), to_fixed(offset.y)],
+        })
+    }
+
+    /// Appends another transform.
+    ///
+    /// The returned transform is equivalent to applying `self` first and `other` afterwards.
+    pub fn then(&self, other: &Self) -> Self {
+        let [a1, b1, c1, d1] = self.matrix;
+        let [a2, b2, c2, d2] = other.matrix;
+
+        let [x, y] = self.offset;
+
+        Self {
+            matrix: [
+                mul(a2, a1, b2, c1),
+                mul(a2, b1, b2, d1),
+                mul(c2, a1, d2, c1),
+                mul(c2, b1, d2, d1),
+            ],
+            offset: [
+                mul(a2, x, b2, y) + other.offset[0],
+                mul(c2, x, d2, y) + other.offset[1],
+            ],
+        }
+    }
+
+    /// Returns the inverse transform.
+    ///
+    /// Returns `None` if the transform isn't invertible, for example if a scale factor is 0.
+    pub fn inverse(&self) -> Option<Self> {
+        let [a, b, c, d] = self.matrix;
+        let [a, b, c, d] = [i128::from(a), i128::from(b), i128::from(c), i128::from(d)];
+
+        let determinant = a * d - b * c;
+        if determinant == 0 {
+            return None;
+        }
+
+        // The inverse matrix is the adjugate divided by the determinant. The determinant has
+        // twice the number of fractional bits, which needs to be compensated for.
+        let one_squared = i128::from(Self::ONE).pow(2);
+        let div = |value: i128| (value * one_squared / determinant) as i64;
+
+        let matrix = [div(d), div(-b), div(-c), div(a)];
+        let inverse = Self {
+            matrix,
+            offset: [0, 0],
+        };
+
+        let [x, y] = self.offset;
+        let offset = inverse.map_bits(x, y);
+
+        Some(Self {
+            matrix,
+            offset: [-offset.0, -offset.1],
+        })
+    }
+
+    /// Transforms a point given as 16.16 fixed point values.
+    ///
+    /// The result is rounded to the nearest point.
+    pub(crate) fn transform_point_bits(&self, x: i64, y: i64) -> Point {
+        let (x, y) = self.map_bits(x, y);
+
+        from_bits(x, y)
+    }
+
+    /// Maps a point given as 16.16 fixed point values and returns 16.16 fixed point values.
+    fn map_bits(&self, x: i64, y: i64) -> (i64, i64) {
+        let [a, b, c, d] = self.matrix;
+
+        (
+            mul(a, x, b, y) + self.offset[0],
+            mul(c, x, d, y) + self.offset[1],
+        )
+    }
+
+    /// Transforms a point.
+    ///
+    /// The result is rounded to the nearest point.
+    pub fn transform_point(&self, point: Point) -> Point {
+        self.transform_point_bits(to_fixed(point.x), to_fixed(point.y))
+    }
+}
+
+impl Default for AffineTransform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Converts 16.16 fixed point values to a point, rounding to the nearest integer.
+///
+/// Coordinates outside the range of `i32` are saturated.
+fn from_bits(x: i64, y: i64) -> Point {
+    let round = |value: i64| {
+        let value =
+            (value + i64::from(AffineTransform::ONE / 2)) >> AffineTransform::FRACTIONAL_BITS;
+
+        value
+            .max(i64::from(i32::min_value()))
+            .min(i64::from(i32::max_value())) as i32
+    };
+
+    Point::new(round(x), round(y))
+}
+
+/// Converts an integer to a 16.16 fixed point value.
+fn to_fixed(value: i32) -> i64 {
+    i64::from(value) << AffineTransform::FRACTIONAL_BITS
+}
+
+/// Converts a float to a 16.16 fixed point value.
+fn to_bits(value: f32) -> i64 {
+    let value = value * AffineTransform::ONE as f32;
+
+    if value >= 0.0 {
+        (value + 0.5) as i64
+    } else {
+        (value - 0.5) as i64
+    }
+}
+
+/// Returns the sum of two products of fixed point values.
+///
+/// The products are calculated with 128 bit precision to prevent overflows.
+fn mul(a: i64, b: i64, c: i64, d: i64) -> i64 {
+    round_shift(i128::from(a) * i128::from(b) + i128::from(c) * i128::from(d))
+}
+
+/// Removes the fractional bits from a product of two fixed point values.
+fn round_shift(value: i128) -> i64 {
+    ((value + i128::from(AffineTransform::ONE / 2)) >> AffineTransform::FRACTIONAL_BITS) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::AngleUnit;
+
+    #[test]
+    fn identity() {
+        let transform = AffineTransform::identity();
+
+        assert_eq!(
+            transform.transform_point(Point::new(3, -4)),
+            Point::new(3, -4)
+        );
+        assert_eq!(AffineTransform::default(), transform);
+    }
+
+    #[test]
+    fn rotation() {
+        let transform = AffineTransform::identity().rotated(90.0.deg());
+
+        assert_eq!(
+            transform.transform_point(Point::new(10, 0)),
+            Point::new(0, 10)
+        );
+        assert_eq!(
+            transform.transform_point(Point::new(0, 10)),
+            Point::new(-10, 0)
+        );
+
+        let transform = AffineTransform::identity().rotated(45.0.deg());
+        assert_eq!(
+            transform.transform_point(Point::new(10, 0)),
+            Point::new(7, 7)
+        );
+    }
+
+    #[test]
+    fn scaling() {
+        let transform = AffineTransform::identity().scaled(2, -3);
+        assert_eq!(
+            transform.transform_point(Point::new(5, 6)),
+            Point::new(10, -18)
+        );
+
+        // Scale by 1.5
+        let transform = AffineTransform::identity().scaled_fixed(0x18000, 0x18000);
+        assert_eq!(
+            transform.transform_point(Point::new(4, 2)),
+            Point::new(6, 3)
+        );
+    }
+
+    #[test]
+    fn order() {
+        let transform = AffineTransform::identity()
+            .translated(Point::new(1, 0))
+            .scaled(2, 2);
+        assert_eq!(
+            transform.transform_point(Point::new(1, 1)),
+            Point::new(4, 2)
+        );
+
+        let transform = AffineTransform::identity()
+            .scaled(2, 2)
+            .translated(Point::new(1, 0));
+        assert_eq!(
+            transform.transform_point(Point::new(1, 1)),
+            Point::new(3, 2)
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let transform = AffineTransform::identity()
+            .scaled(2, 2)
+            .rotated(30.0.deg())
+            .translated(Point::new(10, -5));
+        let inverse = transform.inverse().unwrap();
+
+        for &point in &[Point::new(0, 0), Point::new(7, -3), Point::new(-20, 12)] {
+            assert_eq!(
+                inverse.transform_point(transform.transform_point(point)),
+                point
+            );
+        }
+
+        assert_eq!(AffineTransform::identity().scaled(0, 1).inverse(), None);
+    }
+
+    #[test]
+    fn large_coordinates() {
+        let point = Point::new(i32::max_value() / 2, -i32::max_value() / 2);
+
+        let transform = AffineTransform::identity();
+        assert_eq!(transform.transform_point(point), point);
+
+        let transform = AffineTransform::identity()
+            .translated(Point::new(100_000, -100_000))
+            .scaled(2, 2);
+        assert_eq!(
+            transform.transform_point(point),
+            Point::new(i32::max_value(), i32::min_value())
+        );
+
+        let transform = AffineTransform::identity().translated(Point::new(-40_000, 40_000));
+        assert_eq!(
+            transform.transform_point(point),
+            point + Point::new(-40_000, 40_000)
+        );
+        assert_eq!(
+            transform.inverse().unwrap().transform_point(point),
+            point + Point::new(40_000, -40_000)
+        );
+    }
+}
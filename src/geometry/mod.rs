@@ -1,8 +1,11 @@
 //! Geometry module.
 
+mod affine_transform;
 mod angle;
 mod real;
+mod subpixel_point;
 
+pub use affine_transform::AffineTransform;
 pub(crate) use angle::angle_consts;
 pub(crate) use angle::Trigonometry;
 pub use angle::{Angle, AngleUnit};
@@ -10,6 +13,7 @@ pub use embedded_graphics_core::geometry::{
     AnchorPoint, Dimensions, OriginDimensions, Point, Size,
 };
 pub(crate) use real::Real;
+pub use subpixel_point::SubpixelPoint;
 
 pub(crate) trait PointExt {
     /// Returns a point that is rotated by 90° relative to the origin.
@@ -0,0 +1,181 @@
+use crate::geometry::Point;
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// Point with sub-pixel precision.
+///
+/// `SubpixelPoint` stores the coordinates as 24.8 fixed point numbers, which means that the raw
+/// `x` and `y` values are in units of 1/256 pixel. An integer coordinate refers to the center of
+/// a pixel, which makes a `SubpixelPoint` created from a [`Point`] address the same pixel.
+///
+/// If the `fixed_point` cargo feature is enabled, `SubpixelPoint`s can also be converted from and
+/// to a pair of `fixed::types::I24F8` values.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::geometry::{Point, SubpixelPoint};
+///
+/// // (10.5, 20.25)
+/// let point = SubpixelPoint::from_bits(10 * 256 + 128, 20 * 256 + 64);
+///
+/// assert_eq!(point.round(), Point::new(11, 20));
+/// assert_eq!(point.floor(), Point::new(10, 20));
+/// assert_eq!(SubpixelPoint::from(Point::new(3, 4)).round(), Point::new(3, 4));
+/// ```
+///
+/// [`Point`]: struct.Point.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct SubpixelPoint {
+    /// The raw x coordinate in 1/256 pixel units.
+    pub x: i32,
+
+    /// The raw y coordinate in 1/256 pixel units.
+    pub y: i32,
+}
+
+impl SubpixelPoint {
+    /// Number of fractional bits.
+    pub const FRACTIONAL_BITS: u32 = 8;
+
+    /// Number of sub-pixel units per pixel.
+    pub const ONE: i32 = 1 << Self::FRACTIONAL_BITS;
+
+    /// Creates a point from raw X and Y coordinates in 1/256 pixel units.
+    pub const fn from_bits(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Creates a point with X and Y equal to zero.
+    pub const fn zero() -> Self {
+        Self { x: 0, y: 0 }
+    }
+
+    /// Returns the pixel which contains this point.
+    ///
+    /// Coordinates which lie exactly between two pixels are rounded up.
+    pub fn round(self) -> Point {
+        let half = Self::ONE / 2;
+
+        Point::new(
+            (self.x + half) >> Self::FRACTIONAL_BITS,
+            (self.y + half) >> Self::FRACTIONAL_BITS,
+        )
+    }
+
+    /// Returns the point with the fractional part removed.
+    pub fn floor(self) -> Point {
+        Point::new(
+            self.x >> Self::FRACTIONAL_BITS,
+            self.y >> Self::FRACTIONAL_BITS,
+        )
+    }
+}
+
+impl From<Point> for SubpixelPoint {
+    fn from(point: Point) -> Self {
+        Self::from_bits(point.x * Self::ONE, point.y * Self::ONE)
+    }
+}
+
+impl Add for SubpixelPoint {
+    type Output = SubpixelPoint;
+
+    fn add(self, other: SubpixelPoint) -> SubpixelPoint {
+        SubpixelPoint::from_bits(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for SubpixelPoint {
+    fn add_assign(&mut self, other: SubpixelPoint) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl Sub for SubpixelPoint {
+    type Output = SubpixelPoint;
+
+    fn sub(self, other: SubpixelPoint) -> SubpixelPoint {
+        SubpixelPoint::from_bits(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for SubpixelPoint {
+    fn sub_assign(&mut self, other: SubpixelPoint) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl Neg for SubpixelPoint {
+    type Output = SubpixelPoint;
+
+    fn neg(self) -> Self::Output {
+        SubpixelPoint::from_bits(-self.x, -self.y)
+    }
+}
+
+#[cfg(feature = "fixed_point")]
+impl From<(fixed::types::I24F8, fixed::types::I24F8)> for SubpixelPoint {
+    fn from((x, y): (fixed::types::I24F8, fixed::types::I24F8)) -> Self {
+        Self::from_bits(x.to_bits(), y.to_bits())
+    }
+}
+
+#[cfg(feature = "fixed_point")]
+impl From<SubpixelPoint> for (fixed::types::I24F8, fixed::types::I24F8) {
+    fn from(point: SubpixelPoint) -> Self {
+        (
+            fixed::types::I24F8::from_bits(point.x),
+            fixed::types::I24F8::from_bits(point.y),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round() {
+        assert_eq!(
+            SubpixelPoint::from_bits(127, -128).round(),
+            Point::new(0, 0)
+        );
+        assert_eq!(
+            SubpixelPoint::from_bits(128, -129).round(),
+            Point::new(1, -1)
+        );
+        assert_eq!(
+            SubpixelPoint::from_bits(-256 - 127, 511).round(),
+            Point::new(-1, 2)
+        );
+    }
+
+    #[test]
+    fn floor() {
+        assert_eq!(SubpixelPoint::from_bits(255, -1).floor(), Point::new(0, -1));
+        assert_eq!(
+            SubpixelPoint::from_bits(256, -256).floor(),
+            Point::new(1, -1)
+        );
+    }
+
+    #[test]
+    fn from_point() {
+        assert_eq!(
+            SubpixelPoint::from(Point::new(2, -3)),
+            SubpixelPoint::from_bits(512, -768)
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = SubpixelPoint::from_bits(10, 20);
+        let b = SubpixelPoint::from_bits(1, -2);
+
+        assert_eq!(a + b, SubpixelPoint::from_bits(11, 18));
+        assert_eq!(a - b, SubpixelPoint::from_bits(9, 22));
+        assert_eq!(-a, SubpixelPoint::from_bits(-10, -20));
+    }
+}
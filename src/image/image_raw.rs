@@ -27,6 +27,11 @@ pub type ImageRawBE<'a, C> = ImageRaw<'a, C, BigEndian>;
 /// For color types with less than 8 bits per pixels the start of each row is
 /// aligned to the next whole byte.
 ///
+/// Color types with 12 bits per pixel, like [`Rgb444`], are stored as a continuous bit stream
+/// with the most significant bit first, independent of the byte order. The start of each row is
+/// aligned to the next whole byte. Color types with 18 bits per pixel, like [`Rgb666`], use three
+/// bytes per pixel, which each contain 6 left aligned bits, independent of the byte order.
+///
 /// Details about the conversion of raw data to color types are explained in the
 /// [`raw` module documentation].
 ///
@@ -107,6 +112,8 @@ pub type ImageRawBE<'a, C> = ImageRaw<'a, C, BigEndian>;
 /// [`ImageRawLE`]: type.ImageRawLE.html
 /// [`Image`]: struct.Image.html
 /// [`PixelColor`]: ../pixelcolor/trait.PixelColor.html
+/// [`Rgb444`]: ../pixelcolor/struct.Rgb444.html
+/// [`Rgb666`]: ../pixelcolor/struct.Rgb666.html
 /// [`ByteOrder`]: ../pixelcolor/raw/trait.ByteOrder.html
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct ImageRaw<'a, C, BO = BigEndian>
@@ -149,7 +156,7 @@ where
 
     /// Returns the length of each row in bytes.
     fn bytes_per_row(&self) -> usize {
-        (self.size.width as usize * C::Raw::BITS_PER_PIXEL + 7) / 8
+        (self.size.width as usize * C::Raw::BUFFER_BITS_PER_PIXEL + 7) / 8
     }
 }
 
@@ -206,7 +213,7 @@ where
     BO: ByteOrder,
     RawDataIter<'a, C::Raw, BO>: Iterator<Item = C::Raw>,
 {
-    fn new(image: &ImageRaw<'a, C, BO>) -> Self {
+    pub(super) fn new(image: &ImageRaw<'a, C, BO>) -> Self {
         Self {
             iter: RawDataIter::new(image.data),
             x: 0,
@@ -252,7 +259,10 @@ mod tests {
         image::Image,
         iterator::PixelIteratorExt,
         mock_display::{ColorMapping, MockDisplay},
-        pixelcolor::{raw::RawU32, *},
+        pixelcolor::{
+            raw::{RawDataEncoder, RawU32},
+            *,
+        },
         Drawable, Pixel,
     };
 
@@ -352,6 +362,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn bpp4_seven_color() {
+        let data = [
+            0x01, 0x23, //
+            0x45, 0x67, //
+        ];
+        let image_data: ImageRaw<SevenColor> = ImageRaw::new(&data, 4, 2);
+
+        assert_pattern(
+            image_data,
+            &[
+                "KWGB", //
+                "RYOW", //
+            ],
+        );
+    }
+
+    #[test]
+    fn bpp2_tri_color() {
+        let data = [
+            0b00_01_10_11, //
+        ];
+        let image_data: ImageRaw<TriColor> = ImageRaw::new(&data, 4, 1);
+
+        assert_pattern(image_data, &["KWRR"]);
+    }
+
     #[test]
     fn bpp8_1() {
         let data = [
@@ -394,6 +431,123 @@ mod tests {
         display.assert_eq(&expected);
     }
 
+    #[test]
+    fn bpp8_rgb332() {
+        let data = [0xE0, 0x1C, 0x03, 0x00];
+        let image_data: ImageRawBE<Rgb332> = ImageRaw::new(&data, 2, 2);
+
+        assert_pattern(
+            image_data,
+            &[
+                "RG", //
+                "BK", //
+            ],
+        );
+    }
+
+    #[test]
+    fn bpp12() {
+        let data = [
+            0xF0, 0x00, 0xF0, 0x00, 0xF0, //
+            0x00, 0x0F, 0xFF, 0xF0, 0x00, //
+        ];
+        let image_data: ImageRawBE<Rgb444> = ImageRaw::new(&data, 3, 2);
+
+        assert_pattern(
+            image_data,
+            &[
+                "RGB", //
+                "KWR", //
+            ],
+        );
+    }
+
+    #[test]
+    fn bpp18() {
+        let data = [
+            0xFC, 0x00, 0x00, 0x00, 0xFC, 0x00, //
+            0x00, 0x00, 0xFC, 0x00, 0x00, 0x00, //
+        ];
+        let image_data: ImageRawLE<Rgb666> = ImageRaw::new(&data, 2, 2);
+
+        assert_pattern(
+            image_data,
+            &[
+                "RG", //
+                "BK", //
+            ],
+        );
+    }
+
+    /// Tests if the image contains the given colors, in rows of `image_data.size().width` pixels.
+    fn assert_colors<C, BO>(image_data: ImageRaw<C, BO>, colors: &[C])
+    where
+        C: PixelColor + From<<C as PixelColor>::Raw> + ColorMapping,
+        BO: ByteOrder,
+        for<'a> RawDataIter<'a, C::Raw, BO>: Iterator<Item = C::Raw>,
+    {
+        let width = image_data.size().width as i32;
+
+        let mut display = MockDisplay::new();
+        Image::new(&image_data, Point::zero())
+            .draw(&mut display)
+            .unwrap();
+
+        let mut expected = MockDisplay::new();
+        for (i, color) in (0..).zip(colors.iter()) {
+            Pixel(Point::new(i % width, i / width), *color)
+                .draw(&mut expected)
+                .unwrap();
+        }
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn bpp12_round_trip() {
+        let colors = [
+            Rgb444::new(0x1, 0x2, 0x3),
+            Rgb444::new(0x4, 0x5, 0x6),
+            Rgb444::new(0x7, 0x8, 0x9),
+            Rgb444::new(0xA, 0xB, 0xC),
+        ];
+
+        let mut data = [0; 6];
+        let mut encoder = RawDataEncoder::<_, BigEndian>::new(colors.iter().copied());
+        assert_eq!(encoder.fill(&mut data), 6);
+        assert_colors(ImageRawBE::<Rgb444>::new(&data, 2, 2), &colors);
+
+        let mut encoder = RawDataEncoder::<_, LittleEndian>::new(colors.iter().copied());
+        assert_eq!(encoder.fill(&mut data), 6);
+        assert_colors(ImageRawLE::<Rgb444>::new(&data, 2, 2), &colors);
+    }
+
+    #[test]
+    fn bpp18_round_trip() {
+        let colors = [
+            Rgb666::new(0x3F, 0x00, 0x15),
+            Rgb666::new(0x01, 0x2A, 0x3F),
+            Rgb666::new(0x00, 0x3F, 0x00),
+            Rgb666::new(0x12, 0x34, 0x05),
+        ];
+
+        let mut data = [0; 12];
+        let mut encoder = RawDataEncoder::<_, BigEndian>::new(colors.iter().copied());
+        assert_eq!(encoder.fill(&mut data), 12);
+        assert_colors(ImageRawBE::<Rgb666>::new(&data, 2, 2), &colors);
+
+        let mut encoder = RawDataEncoder::<_, LittleEndian>::new(colors.iter().copied());
+        assert_eq!(encoder.fill(&mut data), 12);
+        assert_colors(ImageRawLE::<Rgb666>::new(&data, 2, 2), &colors);
+
+        let colors = [Bgr666::new(0x3F, 0x00, 0x15), Bgr666::new(0x01, 0x2A, 0x3F)];
+
+        let mut data = [0; 6];
+        let mut encoder = RawDataEncoder::<_, BigEndian>::new(colors.iter().copied());
+        assert_eq!(encoder.fill(&mut data), 6);
+        assert_colors(ImageRawBE::<Bgr666>::new(&data, 1, 2), &colors);
+    }
+
     #[test]
     fn bpp16_little_endian() {
         let data = [
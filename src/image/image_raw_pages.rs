@@ -0,0 +1,179 @@
+use crate::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::{Dimensions, OriginDimensions, Point, Size},
+    image::ImageDrawable,
+    pixelcolor::BinaryColor,
+    primitives::{PointsIter, Rectangle},
+};
+
+/// A monochrome image stored in vertical pages.
+///
+/// Monochrome display controllers like the SSD1306, SH1106 or ST7565 organize their memory in
+/// pages of 8 rows. Each byte in a page contains 8 vertically stacked pixels, with the least
+/// significant bit at the top, and consecutive bytes contain consecutive columns. `ImageRawPages`
+/// can be used to draw images which are stored in this native layout. Images in this format can
+/// be copied into a [`PageBuffer`] without converting the data.
+///
+/// The image data starts with the first page, which contains the rows 0 to 7. If the height of the
+/// image isn't a multiple of 8 the unused bits in the last page are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     image::{Image, ImageRawPages},
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<BinaryColor> = Display::default();
+///
+/// // A 4x3 image with a diagonal line.
+/// let data = [0b001, 0b010, 0b100, 0b000];
+/// let raw = ImageRawPages::new(&data, 4, 3);
+///
+/// Image::new(&raw, Point::zero()).draw(&mut display)?;
+///
+/// display.assert_pattern(&[
+///     "#...", //
+///     ".#..", //
+///     "..#.", //
+/// ]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`PageBuffer`]: ../draw_target/struct.PageBuffer.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ImageRawPages<'a> {
+    /// Image data, organized in pages of 8 rows.
+    data: &'a [u8],
+
+    /// Image size in pixels
+    size: Size,
+}
+
+impl<'a> ImageRawPages<'a> {
+    /// Creates a new image.
+    ///
+    /// # Panics
+    ///
+    /// If `data` doesn't have the correct length.
+    pub fn new(data: &'a [u8], width: u32, height: u32) -> Self {
+        assert_eq!(data.len(), width as usize * pages(height));
+
+        Self {
+            data,
+            size: Size::new(width, height),
+        }
+    }
+
+    /// Returns the image data.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Returns the color of a pixel inside the image.
+    fn pixel(&self, point: Point) -> BinaryColor {
+        let (x, y) = (point.x as usize, point.y as usize);
+        let byte = self.data[y / 8 * self.size.width as usize + x];
+
+        BinaryColor::from(byte & (1 << (y % 8)) != 0)
+    }
+}
+
+/// Returns the number of pages required to store the given number of rows.
+pub(crate) fn pages(height: u32) -> usize {
+    (height as usize + 7) / 8
+}
+
+impl ImageDrawable for ImageRawPages<'_> {
+    type Color = BinaryColor;
+
+    fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = BinaryColor>,
+    {
+        let area = self.bounding_box();
+
+        target.fill_contiguous(&area, area.points().map(|point| self.pixel(point)))
+    }
+
+    fn draw_sub_image<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.draw(&mut target.translated(-area.top_left).clipped(area))
+    }
+}
+
+impl OriginDimensions for ImageRawPages<'_> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{image::Image, mock_display::MockDisplay, Drawable};
+
+    #[test]
+    fn dimensions() {
+        let image = ImageRawPages::new(&[0; 20], 10, 9);
+
+        assert_eq!(image.size(), Size::new(10, 9));
+    }
+
+    #[test]
+    #[should_panic]
+    fn incorrect_length() {
+        ImageRawPages::new(&[0; 10], 10, 9);
+    }
+
+    #[test]
+    fn multiple_pages() {
+        let data = [
+            0x81, 0xFF, 0x00, // page 0
+            0x01, 0x00, 0x02, // page 1
+        ];
+        let image = ImageRawPages::new(&data, 3, 10);
+
+        let mut display = MockDisplay::new();
+        Image::new(&image, Point::zero())
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "##.", //
+            ".#.", //
+            ".#.", //
+            ".#.", //
+            ".#.", //
+            ".#.", //
+            ".#.", //
+            "##.", //
+            "#..", //
+            "..#", //
+        ]);
+    }
+
+    #[test]
+    fn sub_image() {
+        let data = [0x01, 0x02, 0x04, 0x08];
+        let image = ImageRawPages::new(&data, 4, 4);
+
+        let mut display = MockDisplay::new();
+        image
+            .draw_sub_image(
+                &mut display,
+                &Rectangle::new(Point::new(1, 1), Size::new(2, 2)),
+            )
+            .unwrap();
+
+        display.assert_pattern(&[
+            "#.", //
+            ".#", //
+        ]);
+    }
+}
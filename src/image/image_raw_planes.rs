@@ -0,0 +1,144 @@
+use crate::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::{Dimensions, OriginDimensions, Size},
+    image::{image_raw::ContiguousPixels, ImageDrawable, ImageRaw},
+    pixelcolor::{BinaryColor, TriColor},
+    primitives::Rectangle,
+};
+
+/// A tri-color image stored in separate black/white and red planes.
+///
+/// Most controllers for black, white and red e-paper displays expect the image data in two
+/// separate planes with 1 bit per pixel. `ImageRawPlanes` can be used to draw images in this
+/// format. Both planes use the same layout as an [`ImageRaw`] with [`BinaryColor`] pixels: each
+/// row starts at a byte boundary and the pixels are packed with the most significant bit first.
+///
+/// See the [`TriColor`] documentation for the meaning of the bits in both planes.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     image::{Image, ImageRawPlanes},
+///     pixelcolor::TriColor,
+///     prelude::*,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<TriColor> = Display::default();
+///
+/// let black_white = [0b1010_0000, 0b0101_0000];
+/// let red = [0b1100_0000, 0b0000_0000];
+///
+/// let raw = ImageRawPlanes::new(&black_white, &red, 4);
+///
+/// Image::new(&raw, Point::zero()).draw(&mut display)?;
+///
+/// display.assert_pattern(&[
+///     "RRWK", //
+///     "KWKW", //
+/// ]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`ImageRaw`]: struct.ImageRaw.html
+/// [`BinaryColor`]: ../pixelcolor/enum.BinaryColor.html
+/// [`TriColor`]: ../pixelcolor/struct.TriColor.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ImageRawPlanes<'a> {
+    black_white: ImageRaw<'a, BinaryColor>,
+    red: ImageRaw<'a, BinaryColor>,
+}
+
+impl<'a> ImageRawPlanes<'a> {
+    /// Creates a new image.
+    ///
+    /// The height of the image is calculated from the length of the black/white plane.
+    ///
+    /// # Panics
+    ///
+    /// If the planes don't have the same length or the length isn't a multiple of the number of
+    /// bytes per row.
+    pub fn new(black_white: &'a [u8], red: &'a [u8], width: u32) -> Self {
+        assert_eq!(black_white.len(), red.len());
+
+        let bytes_per_row = (width as usize + 7) / 8;
+        let height = black_white.len().checked_div(bytes_per_row).unwrap_or(0) as u32;
+
+        Self {
+            black_white: ImageRaw::new(black_white, width, height),
+            red: ImageRaw::new(red, width, height),
+        }
+    }
+}
+
+impl ImageDrawable for ImageRawPlanes<'_> {
+    type Color = TriColor;
+
+    fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = TriColor>,
+    {
+        let colors = ContiguousPixels::new(&self.black_white)
+            .zip(ContiguousPixels::new(&self.red))
+            .map(|(black_white, red)| TriColor::from_planes(black_white.is_on(), red.is_on()));
+
+        target.fill_contiguous(&self.bounding_box(), colors)
+    }
+
+    fn draw_sub_image<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.draw(&mut target.translated(-area.top_left).clipped(area))
+    }
+}
+
+impl OriginDimensions for ImageRawPlanes<'_> {
+    fn size(&self) -> Size {
+        self.black_white.size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{geometry::Point, image::Image, mock_display::MockDisplay, Drawable};
+
+    #[test]
+    fn dimensions() {
+        let data = [0u8; 6];
+        let image = ImageRawPlanes::new(&data, &data, 10);
+
+        assert_eq!(image.size(), Size::new(10, 3));
+    }
+
+    #[test]
+    fn draw() {
+        let black_white = [
+            0xF0, 0x80, //
+            0x0F, 0x00, //
+        ];
+        let red = [
+            0x81, 0x80, //
+            0x00, 0x80, //
+        ];
+        let image = ImageRawPlanes::new(&black_white, &red, 9);
+
+        let mut display = MockDisplay::new();
+        Image::new(&image, Point::zero())
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "RWWWKKKRR", //
+            "KKKKWWWWR", //
+        ]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn different_plane_lengths() {
+        ImageRawPlanes::new(&[0; 2], &[0; 1], 8);
+    }
+}
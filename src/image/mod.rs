@@ -101,11 +101,16 @@
 
 mod image_drawable_ext;
 mod image_raw;
+mod image_raw_pages;
+mod image_raw_planes;
 mod sub_image;
 
 pub use embedded_graphics_core::image::ImageDrawable;
 pub use image_drawable_ext::ImageDrawableExt;
 pub use image_raw::{ImageRaw, ImageRawBE, ImageRawLE};
+pub(crate) use image_raw_pages::pages;
+pub use image_raw_pages::ImageRawPages;
+pub use image_raw_planes::ImageRawPlanes;
 pub use sub_image::SubImage;
 
 use crate::{
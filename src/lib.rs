@@ -107,7 +107,8 @@
 //! support to enable conversions from `nalgebra::Vector2` to [`Point`] and [`Size`].
 //!
 //! * `fixed_point` - use fixed point arithmetic instead of floating point for all trigonometric
-//! calculation.
+//! calculation. This feature also enables conversions between [`SubpixelPoint`] and
+//! `fixed::types::I24F8` pairs.
 //!
 //! # Migrating from 0.5 to 0.6
 //!
@@ -203,6 +204,7 @@
 //! [`MockDisplay`]: ./mock_display/struct.MockDisplay.html
 //! [`Point`]: ./geometry/struct.Point.html
 //! [`Size`]: ./geometry/struct.Size.html
+//! [`SubpixelPoint`]: ./geometry/struct.SubpixelPoint.html
 //! [`DrawTarget`]: https://docs.rs/embedded-graphics-core/latest/embedded_graphics_core/draw_target/trait.DrawTarget.html
 //! [embedded-graphics-core]: https://docs.rs/embedded-graphics-core/
 //! [`Drawable`]: ./drawable/trait.Drawable.html
@@ -233,7 +235,7 @@ mod styled;
 pub mod text;
 pub mod transform;
 
-pub use embedded_graphics_core::{pixelcolor, Drawable, Pixel};
+pub use embedded_graphics_core::{pixelcolor, rgb, Drawable, Pixel};
 pub use styled::Styled;
 
 /// Trait to convert unsigned into signed integer.
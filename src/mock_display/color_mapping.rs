@@ -1,6 +1,6 @@
 use embedded_graphics_core::pixelcolor::{
-    Bgr555, Bgr565, Bgr888, BinaryColor, Gray2, Gray4, Gray8, GrayColor, Rgb555, Rgb565, Rgb888,
-    RgbColor, WebColors,
+    Bgr233, Bgr444, Bgr555, Bgr565, Bgr666, Bgr888, BinaryColor, Gray2, Gray4, Gray8, GrayColor,
+    Rgb332, Rgb444, Rgb555, Rgb565, Rgb666, Rgb888, RgbColor, SevenColor, TriColor, WebColors,
 };
 
 /// Mapping between `char`s and colors.
@@ -124,13 +124,67 @@ macro_rules! impl_rgb_color_mapping {
     };
 }
 
+impl_rgb_color_mapping!(Rgb332);
+impl_rgb_color_mapping!(Bgr233);
+impl_rgb_color_mapping!(Rgb444);
+impl_rgb_color_mapping!(Bgr444);
 impl_rgb_color_mapping!(Rgb555);
 impl_rgb_color_mapping!(Bgr555);
 impl_rgb_color_mapping!(Rgb565);
 impl_rgb_color_mapping!(Bgr565);
+impl_rgb_color_mapping!(Rgb666);
+impl_rgb_color_mapping!(Bgr666);
 impl_rgb_color_mapping!(Rgb888);
 impl_rgb_color_mapping!(Bgr888);
 
+impl ColorMapping for TriColor {
+    fn char_to_color(c: char) -> Self {
+        match c {
+            'K' => TriColor::BLACK,
+            'W' => TriColor::WHITE,
+            'R' => TriColor::RED,
+            _ => panic!("Invalid char in pattern: '{}'", c),
+        }
+    }
+
+    fn color_to_char(color: Self) -> char {
+        match color {
+            TriColor::BLACK => 'K',
+            TriColor::WHITE => 'W',
+            TriColor::RED => 'R',
+            _ => '?',
+        }
+    }
+}
+
+impl ColorMapping for SevenColor {
+    fn char_to_color(c: char) -> Self {
+        match c {
+            'K' => SevenColor::BLACK,
+            'W' => SevenColor::WHITE,
+            'G' => SevenColor::GREEN,
+            'B' => SevenColor::BLUE,
+            'R' => SevenColor::RED,
+            'Y' => SevenColor::YELLOW,
+            'O' => SevenColor::ORANGE,
+            _ => panic!("Invalid char in pattern: '{}'", c),
+        }
+    }
+
+    fn color_to_char(color: Self) -> char {
+        match color {
+            SevenColor::BLACK => 'K',
+            SevenColor::WHITE => 'W',
+            SevenColor::GREEN => 'G',
+            SevenColor::BLUE => 'B',
+            SevenColor::RED => 'R',
+            SevenColor::YELLOW => 'Y',
+            SevenColor::ORANGE => 'O',
+            _ => '?',
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -162,6 +216,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn epaper_mapping() {
+        for &c in &['K', 'W', 'R'] {
+            assert_eq!(TriColor::color_to_char(TriColor::char_to_color(c)), c);
+        }
+
+        for &c in &['K', 'W', 'G', 'B', 'R', 'Y', 'O'] {
+            assert_eq!(SevenColor::color_to_char(SevenColor::char_to_color(c)), c);
+        }
+    }
+
     #[test]
     #[should_panic(expected = "invalid char in pattern: '4'")]
     fn invalid_gray2_char_4() {
@@ -110,6 +110,24 @@
 //!
 //! Note: The table used `C` as a placeholder for the actual color type, like `Rgb565::BLACK`.
 //!
+//! # Characters used in e-paper color patterns
+//!
+//! [`TriColor`] and [`SevenColor`] use the same characters as RGB colors, with an additional
+//! character for orange:
+//!
+//! | Character | Color                    | Description                             |
+//! |-----------|--------------------------|-----------------------------------------|
+//! | `' '`     | `None`                   | No drawing operation changed the pixel  |
+//! | `'K'`     | `Some(C::BLACK)`         | Pixel was changed to `C::BLACK`         |
+//! | `'W'`     | `Some(C::WHITE)`         | Pixel was changed to `C::WHITE`         |
+//! | `'R'`     | `Some(C::RED)`           | Pixel was changed to `C::RED`           |
+//! | `'G'`     | `Some(C::GREEN)`         | Pixel was changed to `C::GREEN`         |
+//! | `'B'`     | `Some(C::BLUE)`          | Pixel was changed to `C::BLUE`          |
+//! | `'Y'`     | `Some(C::YELLOW)`        | Pixel was changed to `C::YELLOW`        |
+//! | `'O'`     | `Some(C::ORANGE)`        | Pixel was changed to `C::ORANGE`        |
+//!
+//! Note: `TriColor` only supports the characters `'K'`, `'W'` and `'R'`.
+//!
 //! # Examples
 //!
 //! ## Assert that a modified display matches the expected value
@@ -186,7 +204,7 @@ mod color_mapping;
 mod fancy_panic;
 
 use crate::{
-    draw_target::DrawTarget,
+    draw_target::{DrawTarget, GetPixel},
     geometry::{Dimensions, OriginDimensions, Point, Size},
     pixelcolor::{BinaryColor, PixelColor, Rgb888, RgbColor},
     primitives::{PointsIter, Rectangle},
@@ -703,6 +721,19 @@ where
     }
 }
 
+impl<C> GetPixel for MockDisplay<C>
+where
+    C: PixelColor,
+{
+    fn pixel(&self, point: Point) -> Option<Self::Color> {
+        if self.bounding_box().contains(point) {
+            self.get_pixel(point)
+        } else {
+            None
+        }
+    }
+}
+
 impl<C> OriginDimensions for MockDisplay<C>
 where
     C: PixelColor,
@@ -0,0 +1,215 @@
+use core::marker::PhantomData;
+
+use crate::{
+    geometry::{Point, Size},
+    pixelcolor::Gray8,
+    primitives::{rectangle, PointsIter, Rectangle},
+    Pixel,
+};
+
+/// Number of coverage bits per pixel in a grayscale font.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum CoverageDepth {
+    /// 2 bits per pixel.
+    ///
+    /// Each pixel can have one of 4 coverage levels.
+    Bits2,
+
+    /// 4 bits per pixel.
+    ///
+    /// Each pixel can have one of 16 coverage levels.
+    Bits4,
+}
+
+impl CoverageDepth {
+    /// Returns the number of bits per pixel.
+    pub fn bits_per_pixel(self) -> u32 {
+        match self {
+            CoverageDepth::Bits2 => 2,
+            CoverageDepth::Bits4 => 4,
+        }
+    }
+
+    /// Returns the maximum coverage value.
+    fn max_value(self) -> u8 {
+        (1u8 << self.bits_per_pixel()) - 1
+    }
+}
+
+/// Monospaced anti-aliased font.
+///
+/// Unlike [`MonoFont`], which stores one bit per pixel, a `GrayMonoFont` stores the coverage of
+/// each glyph pixel with 2 or 4 bits. The coverage is used by [`GrayMonoTextStyle`] to blend the
+/// text color over the background color, which results in smooth glyph edges.
+///
+/// The glyph data is stored in the same layout as in a `MonoFont`, but each pixel uses
+/// `COVERAGE_DEPTH.bits_per_pixel()` bits. Pixels are packed into bytes with the most significant
+/// bits first and a coverage value of 0 is fully transparent.
+///
+/// Fonts in this format can be generated from TTF or OTF files by using the `ttf-to-gray-mono`
+/// tool in the `tools` directory of the embedded-graphics repository.
+///
+/// [`MonoFont`]: trait.MonoFont.html
+/// [`GrayMonoTextStyle`]: struct.GrayMonoTextStyle.html
+pub trait GrayMonoFont: Copy {
+    /// Raw image data containing the font.
+    const FONT_IMAGE: &'static [u8];
+
+    /// The width of the raw image data in pixels.
+    ///
+    /// The width must be divisible by `CHARACTER_SIZE.width` and the number of bits per image
+    /// row must be divisible by `8`.
+    const FONT_IMAGE_WIDTH: u32;
+
+    /// Number of coverage bits per pixel.
+    const COVERAGE_DEPTH: CoverageDepth;
+
+    /// Size of a single character in pixel.
+    const CHARACTER_SIZE: Size;
+
+    /// Spacing between characters.
+    ///
+    /// The spacing defines how many empty pixels are added horizontally between adjacent characters
+    /// on a single line of text.
+    const CHARACTER_SPACING: u32 = 0;
+
+    /// The baseline.
+    ///
+    /// Offset from the top of a character to the row which contains the baseline.
+    const BASELINE: Option<i32> = None;
+
+    /// Offset from top of a character to the top of the strikethrough.
+    const STRIKETHROUGH_OFFSET: i32 = Self::CHARACTER_SIZE.height as i32 / 2;
+
+    /// Height of the strikethrough.
+    const STRIKETHROUGH_HEIGHT: u32 = 1;
+
+    /// Offset from top of a character to the top of the underline.
+    const UNDERLINE_OFFSET: i32 = Self::CHARACTER_SIZE.height as i32;
+
+    /// Height of the underline.
+    const UNDERLINE_HEIGHT: u32 = 1;
+
+    /// Returns the position of a character in the font.
+    fn char_offset(_: char) -> u32;
+}
+
+/// Coverage iterator for a character in a grayscale font.
+///
+/// The coverage of each pixel is returned as a `Gray8` value, which is scaled to the full range
+/// from 0 (transparent) to 255 (opaque).
+#[derive(Debug)]
+pub(crate) struct GrayCharPixels<F>
+where
+    F: GrayMonoFont,
+{
+    points: rectangle::Points,
+    char_px_offset: u32,
+    font: PhantomData<F>,
+}
+
+impl<F> GrayCharPixels<F>
+where
+    F: GrayMonoFont,
+{
+    pub(crate) fn new(c: char) -> Self {
+        let char_per_row = F::FONT_IMAGE_WIDTH / F::CHARACTER_SIZE.width;
+
+        let char_offset = F::char_offset(c);
+        let row = char_offset / char_per_row;
+
+        // Top left corner of character, in pixels
+        let char_x = (char_offset - (row * char_per_row)) * F::CHARACTER_SIZE.width;
+        let char_y = row * F::CHARACTER_SIZE.height;
+
+        Self {
+            points: Rectangle::new(Point::zero(), F::CHARACTER_SIZE).points(),
+            char_px_offset: char_x + char_y * F::FONT_IMAGE_WIDTH,
+            font: PhantomData,
+        }
+    }
+}
+
+impl<F> Iterator for GrayCharPixels<F>
+where
+    F: GrayMonoFont,
+{
+    type Item = Pixel<Gray8>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.points.next().map(|point| {
+            let bits_per_pixel = F::COVERAGE_DEPTH.bits_per_pixel();
+            let max_value = F::COVERAGE_DEPTH.max_value();
+
+            let index = self.char_px_offset + point.x as u32 + point.y as u32 * F::FONT_IMAGE_WIDTH;
+            let bit_index = index * bits_per_pixel;
+
+            let byte = F::FONT_IMAGE[(bit_index / 8) as usize];
+            let shift = 8 - bits_per_pixel - bit_index % 8;
+            let value = (byte >> shift) & max_value;
+
+            let coverage = u16::from(value) * 255 / u16::from(max_value);
+
+            Pixel(point, Gray8::new(coverage as u8))
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::pixelcolor::GrayColor;
+
+    /// 2 bit test font with two 4x2 pixel glyphs.
+    ///
+    /// `A` contains all coverage levels in ascending order in the first row and in descending
+    /// order in the second row. `B` is fully opaque in the first row and transparent in the
+    /// second row.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct TestFont;
+
+    impl GrayMonoFont for TestFont {
+        const FONT_IMAGE: &'static [u8] = &[0b00_01_10_11, 0xFF, 0b11_10_01_00, 0x00];
+        const FONT_IMAGE_WIDTH: u32 = 8;
+        const COVERAGE_DEPTH: CoverageDepth = CoverageDepth::Bits2;
+        const CHARACTER_SIZE: Size = Size::new(4, 2);
+        const BASELINE: Option<i32> = Some(0);
+
+        fn char_offset(c: char) -> u32 {
+            if c == 'B' {
+                1
+            } else {
+                0
+            }
+        }
+    }
+
+    fn coverage<F: GrayMonoFont>(c: char) -> impl Iterator<Item = u8> {
+        GrayCharPixels::<F>::new(c).map(|Pixel(_, coverage)| coverage.luma())
+    }
+
+    #[test]
+    fn coverage_2bit() {
+        assert!(coverage::<TestFont>('A').eq([0, 85, 170, 255, 255, 170, 85, 0].iter().copied()));
+        assert!(coverage::<TestFont>('B').eq([255, 255, 255, 255, 0, 0, 0, 0].iter().copied()));
+    }
+
+    #[test]
+    fn coverage_4bit() {
+        #[derive(Copy, Clone, Debug)]
+        struct Font4;
+
+        impl GrayMonoFont for Font4 {
+            const FONT_IMAGE: &'static [u8] = &[0x0F, 0x5A];
+            const FONT_IMAGE_WIDTH: u32 = 2;
+            const COVERAGE_DEPTH: CoverageDepth = CoverageDepth::Bits4;
+            const CHARACTER_SIZE: Size = Size::new(2, 2);
+
+            fn char_offset(_: char) -> u32 {
+                0
+            }
+        }
+
+        assert!(coverage::<Font4>('x').eq([0, 255, 85, 170].iter().copied()));
+    }
+}
@@ -0,0 +1,633 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    mono_font::{gray_mono_font::GrayCharPixels, mono_text_style::UndefinedFont, GrayMonoFont},
+    pixelcolor::{ColorMath, GrayColor, PixelColor},
+    primitives::Rectangle,
+    text::{Baseline, CharacterStyle, DecorationColor, TextMetrics, TextRenderer},
+    Pixel, SaturatingCast,
+};
+
+/// Style properties for text using an anti-aliased monospaced font.
+///
+/// A `GrayMonoTextStyle` can be applied to a [`Text`] object to define how the text is drawn.
+///
+/// If a background color is set, the text color is blended over the background color based on
+/// the coverage of each glyph pixel by using [`ColorMath::mix`]. Without a background color the
+/// color of the draw target below the text is unknown and all pixels with a coverage of at least
+/// 50% are drawn in the text color.
+///
+/// Because `GrayMonoTextStyle` has the [`non_exhaustive`] attribute, it cannot be created using a
+/// struct literal. To create a `GrayMonoTextStyle` with a given text and background color, use
+/// the [`new`] method. For more complex text styles, use the [`GrayMonoTextStyleBuilder`].
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     mono_font::{CoverageDepth, GrayMonoFont, GrayMonoTextStyle},
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     text::Text,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+///
+/// /// Font with a single 2x2 pixel glyph.
+/// #[derive(Copy, Clone)]
+/// struct SmoothFont;
+///
+/// impl GrayMonoFont for SmoothFont {
+///     const FONT_IMAGE: &'static [u8] = &[0b1101_0000, 0b0111_0000];
+///     const FONT_IMAGE_WIDTH: u32 = 4;
+///     const COVERAGE_DEPTH: CoverageDepth = CoverageDepth::Bits2;
+///     const CHARACTER_SIZE: Size = Size::new(2, 2);
+///
+///     fn char_offset(_: char) -> u32 {
+///         0
+///     }
+/// }
+///
+/// let style = GrayMonoTextStyle::new(SmoothFont, Rgb565::WHITE, Rgb565::BLUE);
+///
+/// Text::new("x", Point::new(0, 1))
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`ColorMath::mix`]: ../pixelcolor/trait.ColorMath.html#method.mix
+/// [`Text`]: ../text/struct.Text.html
+/// [`non_exhaustive`]: https://blog.rust-lang.org/2019/12/19/Rust-1.40.0.html#[non_exhaustive]-structs,-enums,-and-variants
+/// [`GrayMonoTextStyleBuilder`]: ./struct.GrayMonoTextStyleBuilder.html
+/// [`new`]: #method.new
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub struct GrayMonoTextStyle<C, F> {
+    /// Text color.
+    pub text_color: Option<C>,
+
+    /// Background color.
+    pub background_color: Option<C>,
+
+    /// Underline color.
+    pub underline_color: DecorationColor<C>,
+
+    /// Strikethrough color.
+    pub strikethrough_color: DecorationColor<C>,
+
+    /// Font.
+    pub font: F,
+}
+
+impl<C, F> GrayMonoTextStyle<C, F>
+where
+    C: PixelColor,
+    F: GrayMonoFont,
+{
+    /// Creates a text style with the given text and background color.
+    pub fn new(font: F, text_color: C, background_color: C) -> Self {
+        GrayMonoTextStyleBuilder::new()
+            .font(font)
+            .text_color(text_color)
+            .background_color(background_color)
+            .build()
+    }
+
+    /// Returns `true` if the style is transparent.
+    ///
+    /// Drawing a `Text` with a transparent `GrayMonoTextStyle` will not draw any pixels.
+    ///
+    /// [`Text`]: ../text/struct.Text.html
+    pub fn is_transparent(&self) -> bool {
+        self.text_color.is_none()
+            && self.background_color.is_none()
+            && self.underline_color.is_none()
+            && self.strikethrough_color.is_none()
+    }
+
+    /// Resolves a decoration color.
+    fn resolve_decoration_color(&self, color: DecorationColor<C>) -> Option<C> {
+        match color {
+            DecorationColor::None => None,
+            DecorationColor::TextColor => self.text_color,
+            DecorationColor::Custom(c) => Some(c),
+        }
+    }
+
+    fn draw_background<D>(
+        &self,
+        width: u32,
+        position: Point,
+        target: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if width == 0 {
+            return Ok(());
+        }
+
+        if let Some(background_color) = self.background_color {
+            target.fill_solid(
+                &Rectangle::new(position, Size::new(width, F::CHARACTER_SIZE.height)),
+                background_color,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn draw_strikethrough<D>(
+        &self,
+        width: u32,
+        position: Point,
+        target: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if let Some(strikethrough_color) = self.resolve_decoration_color(self.strikethrough_color) {
+            let top_left = position + Point::new(0, F::STRIKETHROUGH_OFFSET);
+            let size = Size::new(width, F::STRIKETHROUGH_HEIGHT);
+
+            target.fill_solid(&Rectangle::new(top_left, size), strikethrough_color)?;
+        }
+
+        Ok(())
+    }
+
+    fn draw_underline<D>(&self, width: u32, position: Point, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if let Some(underline_color) = self.resolve_decoration_color(self.underline_color) {
+            let top_left = position + Point::new(0, F::UNDERLINE_OFFSET);
+            let size = Size::new(width, F::UNDERLINE_HEIGHT);
+
+            target.fill_solid(&Rectangle::new(top_left, size), underline_color)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the vertical offset between the line position and the top edge of the bounding box.
+    fn baseline_offset(&self, baseline: Baseline) -> i32 {
+        match baseline {
+            Baseline::Top => 0,
+            Baseline::Bottom => F::CHARACTER_SIZE.height.saturating_sub(1).saturating_cast(),
+            Baseline::Middle => (F::CHARACTER_SIZE.height.saturating_sub(1) / 2).saturating_cast(),
+            Baseline::Alphabetic => F::BASELINE
+                .unwrap_or_else(|| F::CHARACTER_SIZE.height.saturating_sub(1).saturating_cast()),
+        }
+    }
+}
+
+impl<C, F> TextRenderer for GrayMonoTextStyle<C, F>
+where
+    C: ColorMath,
+    F: GrayMonoFont,
+{
+    type Color = C;
+
+    fn draw_string<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let position = position - Point::new(0, self.baseline_offset(baseline));
+
+        let mut first = true;
+        let mut p = position;
+
+        let mut width = 0;
+
+        for c in text.chars() {
+            if first {
+                first = false;
+            } else if F::CHARACTER_SPACING > 0 {
+                // Fill space between characters if background color is set.
+                self.draw_background(F::CHARACTER_SPACING, p, target)?;
+                p += Size::new(F::CHARACTER_SPACING, 0);
+                width += F::CHARACTER_SPACING;
+            }
+
+            let pixels = GrayCharPixels::<F>::new(c);
+
+            match (self.text_color, self.background_color) {
+                (Some(text_color), Some(background_color)) => {
+                    let bounding_box = Rectangle::new(p, F::CHARACTER_SIZE);
+
+                    // The glyph is opaque if both colors are set and `fill_contiguous` can be used.
+                    target.fill_contiguous(
+                        &bounding_box,
+                        pixels.map(|Pixel(_, coverage)| {
+                            background_color.mix(text_color, coverage.luma())
+                        }),
+                    )?;
+                }
+                (Some(text_color), None) => {
+                    target.draw_iter(
+                        pixels
+                            .filter(|Pixel(_, coverage)| coverage.luma() >= 128)
+                            .map(|Pixel(delta_p, _)| Pixel(p + delta_p, text_color)),
+                    )?;
+                }
+                (None, Some(background_color)) => {
+                    target.draw_iter(
+                        pixels
+                            .filter(|Pixel(_, coverage)| coverage.luma() < 128)
+                            .map(|Pixel(delta_p, _)| Pixel(p + delta_p, background_color)),
+                    )?;
+                }
+                (None, None) => {}
+            }
+
+            p += F::CHARACTER_SIZE.x_axis();
+            width += F::CHARACTER_SIZE.width;
+        }
+
+        self.draw_strikethrough(width, position, target)?;
+        self.draw_underline(width, position, target)?;
+
+        p.y += self.baseline_offset(baseline);
+
+        Ok(p)
+    }
+
+    fn draw_whitespace<D>(
+        &self,
+        width: u32,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let position = position - Point::new(0, self.baseline_offset(baseline));
+
+        self.draw_background(width, position, target)?;
+        self.draw_strikethrough(width, position, target)?;
+        self.draw_underline(width, position, target)?;
+
+        Ok(position + Point::new(width.saturating_cast(), self.baseline_offset(baseline)))
+    }
+
+    fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
+        let bb_position = position - Point::new(0, self.baseline_offset(baseline));
+
+        let bb_width = (text.len() as u32 * (F::CHARACTER_SIZE.width + F::CHARACTER_SPACING))
+            .saturating_sub(F::CHARACTER_SPACING);
+
+        let bb_height = if self.underline_color != DecorationColor::None {
+            F::UNDERLINE_HEIGHT + F::UNDERLINE_OFFSET as u32
+        } else {
+            F::CHARACTER_SIZE.height
+        };
+
+        let bb_size = Size::new(bb_width, bb_height);
+
+        TextMetrics {
+            bounding_box: Rectangle::new(bb_position, bb_size),
+            next_position: position + bb_size.x_axis(),
+        }
+    }
+
+    fn line_height(&self) -> u32 {
+        F::CHARACTER_SIZE.height
+    }
+}
+
+impl<C, F> CharacterStyle for GrayMonoTextStyle<C, F>
+where
+    C: ColorMath,
+    F: GrayMonoFont,
+{
+    type Color = C;
+
+    fn set_text_color(&mut self, text_color: Option<Self::Color>) {
+        self.text_color = text_color;
+    }
+
+    fn set_background_color(&mut self, background_color: Option<Self::Color>) {
+        self.background_color = background_color;
+    }
+
+    fn set_underline_color(&mut self, underline_color: DecorationColor<Self::Color>) {
+        self.underline_color = underline_color;
+    }
+
+    fn set_strikethrough_color(&mut self, strikethrough_color: DecorationColor<Self::Color>) {
+        self.strikethrough_color = strikethrough_color;
+    }
+}
+
+/// Text style builder for anti-aliased monospaced fonts.
+///
+/// Use this builder to create [`GrayMonoTextStyle`]s for [`Text`].
+///
+/// [`Text`]: ../text/struct.Text.html
+/// [`GrayMonoTextStyle`]: struct.GrayMonoTextStyle.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct GrayMonoTextStyleBuilder<C, F> {
+    style: GrayMonoTextStyle<C, F>,
+}
+
+impl<C> GrayMonoTextStyleBuilder<C, UndefinedFont> {
+    /// Creates a new text style builder.
+    pub fn new() -> Self {
+        Self {
+            style: GrayMonoTextStyle {
+                font: UndefinedFont,
+                background_color: None,
+                text_color: None,
+                underline_color: DecorationColor::None,
+                strikethrough_color: DecorationColor::None,
+            },
+        }
+    }
+}
+
+impl<C, F> GrayMonoTextStyleBuilder<C, F> {
+    /// Sets the font.
+    pub fn font<Font>(self, font: Font) -> GrayMonoTextStyleBuilder<C, Font> {
+        let style = GrayMonoTextStyle {
+            font,
+            background_color: self.style.background_color,
+            text_color: self.style.text_color,
+            underline_color: self.style.underline_color,
+            strikethrough_color: self.style.strikethrough_color,
+        };
+
+        GrayMonoTextStyleBuilder { style }
+    }
+
+    /// Enables underline using the text color.
+    pub fn underline(mut self) -> Self {
+        self.style.underline_color = DecorationColor::TextColor;
+
+        self
+    }
+
+    /// Enables strikethrough using the text color.
+    pub fn strikethrough(mut self) -> Self {
+        self.style.strikethrough_color = DecorationColor::TextColor;
+
+        self
+    }
+}
+
+impl<C, F> GrayMonoTextStyleBuilder<C, F>
+where
+    C: PixelColor,
+{
+    /// Sets the text color.
+    pub fn text_color(mut self, text_color: C) -> Self {
+        self.style.text_color = Some(text_color);
+
+        self
+    }
+
+    /// Sets the background color.
+    pub fn background_color(mut self, background_color: C) -> Self {
+        self.style.background_color = Some(background_color);
+
+        self
+    }
+
+    /// Enables underline with a custom color.
+    pub fn underline_with_color(mut self, underline_color: C) -> Self {
+        self.style.underline_color = DecorationColor::Custom(underline_color);
+
+        self
+    }
+
+    /// Enables strikethrough with a custom color.
+    pub fn strikethrough_with_color(mut self, strikethrough_color: C) -> Self {
+        self.style.strikethrough_color = DecorationColor::Custom(strikethrough_color);
+
+        self
+    }
+}
+
+impl<C, F> GrayMonoTextStyleBuilder<C, F>
+where
+    C: PixelColor,
+    F: GrayMonoFont,
+{
+    /// Builds the text style.
+    ///
+    /// This method can only be called after a font was set by using the [`font`] method. All other
+    /// settings are optional and they will be set to their default value if they are missing.
+    ///
+    /// [`font`]: #method.font
+    pub fn build(self) -> GrayMonoTextStyle<C, F> {
+        self.style
+    }
+}
+
+impl<C, F> From<&GrayMonoTextStyle<C, F>> for GrayMonoTextStyleBuilder<C, F>
+where
+    C: PixelColor,
+    F: GrayMonoFont,
+{
+    fn from(style: &GrayMonoTextStyle<C, F>) -> Self {
+        Self { style: *style }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Dimensions,
+        mock_display::MockDisplay,
+        mono_font::gray_mono_font::tests::TestFont,
+        pixelcolor::{BinaryColor, Gray4, Rgb888, RgbColor},
+        text::Text,
+        Drawable,
+    };
+
+    mod generated {
+        use crate::{
+            geometry::Size,
+            mono_font::{CoverageDepth, GrayMonoFont},
+        };
+
+        include!("../../fonts/gray/DejaVuSansMono10.rs");
+    }
+
+    use generated::FontDejaVuSansMono10;
+
+    /// Tests a font which was generated by the `ttf-to-gray-mono` tool.
+    #[test]
+    fn generated_font() {
+        let style = GrayMonoTextStyle::new(FontDejaVuSansMono10, Gray4::WHITE, Gray4::BLACK);
+
+        let mut display = MockDisplay::new();
+        Text::new("Hg", Point::new(0, 7))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "000000000000",
+            "000000000000",
+            "5505A0000000",
+            "5505A00AAA50",
+            "5AAAA05A0A50",
+            "5505A0550550",
+            "5505A05A0A50",
+            "5505A00AAA50",
+            "000000000A50",
+            "0000000AAA00",
+            "000000000000",
+        ]);
+    }
+
+    #[test]
+    fn builder_default() {
+        assert_eq!(
+            GrayMonoTextStyleBuilder::<Gray4, _>::new()
+                .font(TestFont)
+                .build(),
+            GrayMonoTextStyle {
+                font: TestFont,
+                text_color: None,
+                background_color: None,
+                underline_color: DecorationColor::None,
+                strikethrough_color: DecorationColor::None,
+            }
+        );
+    }
+
+    #[test]
+    fn blended() {
+        let style = GrayMonoTextStyle::new(TestFont, Gray4::WHITE, Gray4::BLACK);
+
+        let mut display = MockDisplay::new();
+        Text::new("AB", Point::zero())
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "05AFFFFF", //
+            "FA500000", //
+        ]);
+    }
+
+    #[test]
+    fn blended_matches_color_math() {
+        let style = GrayMonoTextStyle::new(TestFont, Rgb888::RED, Rgb888::BLUE);
+
+        let mut display = MockDisplay::new();
+        Text::new("A", Point::zero())
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        for (x, &coverage) in [0, 85, 170, 255].iter().enumerate() {
+            assert_eq!(
+                display.get_pixel(Point::new(x as i32, 0)),
+                Some(Rgb888::BLUE.mix(Rgb888::RED, coverage))
+            );
+        }
+    }
+
+    #[test]
+    fn blended_inverted() {
+        let style = GrayMonoTextStyle::new(TestFont, Gray4::new(3), Gray4::new(12));
+
+        let mut display = MockDisplay::new();
+        Text::new("A", Point::zero())
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "C963", //
+            "369C", //
+        ]);
+    }
+
+    #[test]
+    fn transparent_background() {
+        let style = GrayMonoTextStyleBuilder::new()
+            .font(TestFont)
+            .text_color(BinaryColor::On)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Text::new("AB", Point::zero())
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "  ######", //
+            "##      ", //
+        ]);
+    }
+
+    #[test]
+    fn transparent_text() {
+        let style = GrayMonoTextStyleBuilder::new()
+            .font(TestFont)
+            .background_color(BinaryColor::Off)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Text::new("A", Point::zero())
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "..  ", //
+            "  ..", //
+        ]);
+    }
+
+    #[test]
+    fn underline() {
+        let style = GrayMonoTextStyleBuilder::new()
+            .font(TestFont)
+            .text_color(Gray4::WHITE)
+            .background_color(Gray4::BLACK)
+            .underline_with_color(Gray4::new(7))
+            .build();
+
+        let mut display = MockDisplay::new();
+        let text = Text::new("B", Point::zero()).into_styled(style);
+        text.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "FFFF", //
+            "0000", //
+            "7777", //
+        ]);
+
+        assert_eq!(text.bounding_box(), display.affected_area());
+    }
+
+    #[test]
+    fn metrics() {
+        let style = GrayMonoTextStyle::new(TestFont, Gray4::WHITE, Gray4::BLACK);
+
+        assert_eq!(style.line_height(), 2);
+
+        let metrics = style.measure_string("ABA", Point::new(1, 2), Baseline::Bottom);
+        assert_eq!(
+            metrics.bounding_box,
+            Rectangle::new(Point::new(1, 1), Size::new(12, 2))
+        );
+        assert_eq!(metrics.next_position, Point::new(13, 2));
+    }
+}
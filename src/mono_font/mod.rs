@@ -121,21 +121,35 @@
 //! | `Font12x16` | ![12x16 font spritemap screenshot](https://raw.githubusercontent.com/embedded-graphics/embedded-graphics/master/data/font12x16.png) |
 //! | `Font24x32` | The 24x32 font is a pixel doubled version of the 12x16 font. |
 //!
+//! # Anti-aliased fonts
+//!
+//! Fonts which implement the [`GrayMonoFont`] trait store 2 or 4 bits of coverage per pixel
+//! instead of a single bit. Text in these fonts is drawn by using a [`GrayMonoTextStyle`], which
+//! blends the text color over the background color to smooth the glyph edges. The
+//! `ttf-to-gray-mono` tool in the `tools` directory can be used to convert TTF and OTF fonts into
+//! this format.
+//!
 //! [built-in fonts]: #built-in-fonts
 //! [`Text`]: ../text/struct.Text.html
 //! [`Styled`]: ../struct.Styled.html
 //! [`MonoTextStyle`]: struct.MonoTextStyle.html
+//! [`GrayMonoFont`]: trait.GrayMonoFont.html
+//! [`GrayMonoTextStyle`]: struct.GrayMonoTextStyle.html
 //! [`ArrayString`]: https://docs.rs/arrayvec/0.4.11/arrayvec/struct.ArrayString.html
 //! [`write!()`]: https://doc.rust-lang.org/nightly/std/macro.write.html
 
 pub mod ascii;
+mod gray_mono_font;
+mod gray_mono_text_style;
 pub mod latin1;
 mod mono_char_pixels;
 mod mono_text_style;
 
 pub(crate) use mono_char_pixels::MonoCharPixels;
 
-pub use mono_text_style::{MonoTextStyle, MonoTextStyleBuilder};
+pub use gray_mono_font::{CoverageDepth, GrayMonoFont};
+pub use gray_mono_text_style::{GrayMonoTextStyle, GrayMonoTextStyleBuilder};
+pub use mono_text_style::{MonoTextStyle, MonoTextStyleBuilder, TextOrientation};
 
 use crate::geometry::Size;
 
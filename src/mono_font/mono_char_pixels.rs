@@ -2,8 +2,8 @@ use core::marker::PhantomData;
 use embedded_graphics_core::{pixelcolor::BinaryColor, primitives::Rectangle};
 
 use crate::{
-    geometry::Point,
-    mono_font::MonoFont,
+    geometry::{Point, Size},
+    mono_font::{MonoFont, TextOrientation},
     primitives::{rectangle, PointsIter},
     Pixel,
 };
@@ -15,6 +15,9 @@ where
     F: MonoFont,
 {
     points: rectangle::Points,
+    size: Size,
+    scale: Size,
+    orientation: TextOrientation,
 
     char_px_offset: u32,
     byte_index: usize,
@@ -27,7 +30,15 @@ impl<F> MonoCharPixels<F>
 where
     F: MonoFont,
 {
-    pub(crate) fn new(c: char) -> Self {
+    /// Creates a pixel iterator for a character.
+    ///
+    /// Each pixel in the glyph is magnified into a block of `scale.width` x `scale.height`
+    /// pixels. All components of `scale` must be greater than 0.
+    ///
+    /// Rotated glyphs are returned in row-major order of the rotated glyph, which makes the
+    /// iterator usable with `fill_contiguous`. The returned points are relative to the top left
+    /// corner of the rotated glyph.
+    pub(crate) fn new(c: char, scale: Size, orientation: TextOrientation) -> Self {
         let char_per_row = F::FONT_IMAGE_WIDTH / F::CHARACTER_SIZE.width;
 
         // Char _code_ offset from first char, most often a space
@@ -39,8 +50,18 @@ where
         let char_x = (char_offset - (row * char_per_row)) * F::CHARACTER_SIZE.width;
         let char_y = row * F::CHARACTER_SIZE.height;
 
+        let size = F::CHARACTER_SIZE.component_mul(scale);
+        let size = if orientation == TextOrientation::Horizontal {
+            size
+        } else {
+            Size::new(size.height, size.width)
+        };
+
         Self {
-            points: Rectangle::new(Point::zero(), F::CHARACTER_SIZE).points(),
+            points: Rectangle::new(Point::zero(), size).points(),
+            size,
+            scale,
+            orientation,
             char_px_offset: char_x + char_y * F::FONT_IMAGE_WIDTH,
             byte_index: 0,
             bit_mask: 0,
@@ -59,6 +80,41 @@ where
         self.byte_index = (index / 8) as usize;
         self.bit_mask = 0x80 >> (index % 8);
     }
+
+    /// Returns the size of the glyph after scaling and rotation.
+    pub(crate) fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns `true` if the glyph pixel at the given point is set.
+    ///
+    /// The point is relative to the top left corner of the rotated glyph. Points outside the glyph
+    /// are never set.
+    pub(crate) fn is_set(&self, point: Point) -> bool {
+        point.x >= 0
+            && point.y >= 0
+            && (point.x as u32) < self.size.width
+            && (point.y as u32) < self.size.height
+            && self.pixel(point) == BinaryColor::On
+    }
+
+    /// Returns the color of a pixel by random access.
+    fn pixel(&self, point: Point) -> BinaryColor {
+        let (x, y) = (point.x as u32, point.y as u32);
+
+        // Map the point in the rotated glyph back to the unrotated glyph.
+        let (x, y) = match self.orientation {
+            TextOrientation::Horizontal => (x, y),
+            TextOrientation::TopToBottom => (y, self.size.width - 1 - x),
+            TextOrientation::BottomToTop => (self.size.height - 1 - y, x),
+        };
+
+        let index = self.char_px_offset
+            + x / self.scale.width
+            + y / self.scale.height * F::FONT_IMAGE_WIDTH;
+
+        BinaryColor::from(F::FONT_IMAGE[(index / 8) as usize] & (0x80 >> (index % 8)) != 0)
+    }
 }
 
 impl<F> Iterator for MonoCharPixels<F>
@@ -68,18 +124,28 @@ where
     type Item = Pixel<BinaryColor>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.orientation != TextOrientation::Horizontal {
+            return self
+                .points
+                .next()
+                .map(|point| Pixel(point, self.pixel(point)));
+        }
+
         self.points.next().map(|point| {
             if point.x == 0 {
-                self.start_row(point.y);
+                self.start_row(point.y / self.scale.height as i32);
             }
 
             let color = BinaryColor::from(F::FONT_IMAGE[self.byte_index] & self.bit_mask != 0);
 
-            if self.bit_mask != 0x01 {
-                self.bit_mask >>= 1;
-            } else {
-                self.bit_mask = 0x80;
-                self.byte_index += 1;
+            // Advance to the next glyph pixel after the last column of the magnified block.
+            if (point.x as u32 + 1) % self.scale.width == 0 {
+                if self.bit_mask != 0x01 {
+                    self.bit_mask >>= 1;
+                } else {
+                    self.bit_mask = 0x80;
+                    self.byte_index += 1;
+                }
             }
 
             Pixel(point, color)
@@ -3,7 +3,7 @@ use crate::{
     geometry::{Point, Size},
     mono_font::{MonoCharPixels, MonoFont},
     pixelcolor::{BinaryColor, PixelColor},
-    primitives::Rectangle,
+    primitives::{PointsIter, Rectangle},
     text::{Baseline, CharacterStyle, DecorationColor, TextMetrics, TextRenderer},
     Pixel, SaturatingCast,
 };
@@ -38,6 +38,63 @@ pub struct MonoTextStyle<C, F> {
 
     /// Font.
     pub font: F,
+
+    /// Glyph scale factor.
+    ///
+    /// Each pixel of a glyph is magnified into a block of `scale.width` x `scale.height` pixels.
+    /// The character spacing and the underline and strikethrough geometry are scaled by the same
+    /// factors.
+    ///
+    /// Both components of the scale factor must be at least 1, which is ensured if the style is
+    /// created by using [`MonoTextStyleBuilder::scale`].
+    ///
+    /// [`MonoTextStyleBuilder::scale`]: struct.MonoTextStyleBuilder.html#method.scale
+    pub scale: Size,
+
+    /// Text orientation.
+    pub orientation: TextOrientation,
+
+    /// Outline color.
+    ///
+    /// If an outline color is set, a halo of `outline_width` pixels is drawn around each glyph.
+    pub outline_color: Option<C>,
+
+    /// Outline width in pixels.
+    pub outline_width: u32,
+
+    /// Shadow color.
+    ///
+    /// If a shadow color is set, a copy of each glyph is drawn behind the text, offset by
+    /// `shadow_offset`.
+    pub shadow_color: Option<C>,
+
+    /// Shadow offset in pixels.
+    pub shadow_offset: Point,
+}
+
+/// Text orientation.
+///
+/// Rotated text is drawn by rotating each line around the position which is passed to the text
+/// renderer. The baseline and the decorations are rotated together with the glyphs.
+///
+/// Only single lines of rotated text are supported, because [`Text`] always places additional
+/// lines below the previous line.
+///
+/// [`Text`]: ../text/struct.Text.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum TextOrientation {
+    /// Horizontal text.
+    Horizontal,
+
+    /// Text which is read from top to bottom.
+    ///
+    /// The glyphs are rotated by 90 degrees clockwise.
+    TopToBottom,
+
+    /// Text which is read from bottom to top.
+    ///
+    /// The glyphs are rotated by 90 degrees counterclockwise.
+    BottomToTop,
 }
 
 impl<C, F> MonoTextStyle<C, F>
@@ -63,6 +120,138 @@ where
             && self.background_color.is_none()
             && self.underline_color.is_none()
             && self.strikethrough_color.is_none()
+            && !self.has_outline()
+            && self.shadow_color.is_none()
+    }
+
+    /// Returns `true` if the outline is enabled.
+    fn has_outline(&self) -> bool {
+        self.outline_color.is_some() && self.outline_width > 0
+    }
+
+    /// Returns the pixel iterators and the top left corners of all glyphs in a string.
+    fn glyphs<'t>(
+        &self,
+        text: &'t str,
+        position: Point,
+        origin: Point,
+    ) -> impl Iterator<Item = (MonoCharPixels<F>, Point)> + 't
+    where
+        C: 't,
+        F: 't,
+    {
+        let style = *self;
+        let character_size = self.character_size();
+        let pitch = character_size.width + self.character_spacing();
+
+        text.chars().enumerate().map(move |(index, c)| {
+            let p = position + Point::new((index as u32 * pitch).saturating_cast(), 0);
+            let area = style.rotate_rectangle(origin, Rectangle::new(p, character_size));
+
+            (
+                MonoCharPixels::new(c, style.scale, style.orientation),
+                area.top_left,
+            )
+        })
+    }
+
+    /// Draws a string with an outline or a shadow.
+    ///
+    /// The string is drawn in layers, without using a framebuffer. The background is drawn first,
+    /// followed by the shadows and the outlines of all characters. The glyphs are drawn last to
+    /// make sure that they aren't covered by the outlines of adjacent characters.
+    fn draw_string_with_effects<D>(
+        &self,
+        text: &str,
+        position: Point,
+        origin: Point,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let character_count = text.chars().count() as u32;
+        let width = (character_count * (self.character_size().width + self.character_spacing()))
+            .saturating_sub(self.character_spacing());
+
+        self.draw_background(width, position, origin, target)?;
+
+        if let Some(shadow_color) = self.shadow_color {
+            for (pixels, top_left) in self.glyphs(text, position, origin) {
+                let top_left = top_left + self.shadow_offset;
+
+                target.draw_iter(
+                    pixels
+                        .filter(|Pixel(_, c)| *c == BinaryColor::On)
+                        .map(|Pixel(delta_p, _)| Pixel(top_left + delta_p, shadow_color)),
+                )?;
+            }
+        }
+
+        if let (Some(outline_color), true) = (self.outline_color, self.has_outline()) {
+            let radius: i32 = self.outline_width.saturating_cast();
+            let neighborhood = Size::new(2 * self.outline_width + 1, 2 * self.outline_width + 1);
+
+            for (pixels, top_left) in self.glyphs(text, position, origin) {
+                let area = Rectangle::new(
+                    Point::new(-radius, -radius),
+                    pixels.size() + neighborhood - Size::new(1, 1),
+                );
+
+                // Unset pixels are part of the outline if a set pixel is in the neighborhood.
+                let pixels = &pixels;
+                target.draw_iter(
+                    area.points()
+                        .filter(|&point| {
+                            !pixels.is_set(point)
+                                && Rectangle::new(point - Point::new(radius, radius), neighborhood)
+                                    .points()
+                                    .any(|neighbor| pixels.is_set(neighbor))
+                        })
+                        .map(|point| Pixel(top_left + point, outline_color)),
+                )?;
+            }
+        }
+
+        if let Some(text_color) = self.text_color {
+            for (pixels, top_left) in self.glyphs(text, position, origin) {
+                target.draw_iter(
+                    pixels
+                        .filter(|Pixel(_, c)| *c == BinaryColor::On)
+                        .map(|Pixel(delta_p, _)| Pixel(top_left + delta_p, text_color)),
+                )?;
+            }
+        }
+
+        self.draw_strikethrough(width, position, origin, target)?;
+        self.draw_underline(width, position, origin, target)?;
+
+        Ok(self.rotate_point(origin, origin + Point::new(width.saturating_cast(), 0)))
+    }
+
+    /// Extends a bounding box by the area which is covered by the outline and the shadow.
+    fn extend_bounding_box(&self, bounding_box: Rectangle) -> Rectangle {
+        let (top_left, bottom_right) = match bounding_box.bottom_right() {
+            Some(bottom_right) => (bounding_box.top_left, bottom_right),
+            None => return bounding_box,
+        };
+
+        let mut min = top_left;
+        let mut max = bottom_right;
+
+        if self.has_outline() {
+            let radius: i32 = self.outline_width.saturating_cast();
+
+            min -= Point::new(radius, radius);
+            max += Point::new(radius, radius);
+        }
+
+        if self.shadow_color.is_some() {
+            min = min.component_min(top_left + self.shadow_offset);
+            max = max.component_max(bottom_right + self.shadow_offset);
+        }
+
+        Rectangle::with_corners(min, max)
     }
 
     /// Resolves a decoration color.
@@ -74,10 +263,51 @@ where
         }
     }
 
+    /// Converts a point in the horizontal text layout into the text orientation.
+    ///
+    /// The text is rotated around `origin`.
+    fn rotate_point(&self, origin: Point, point: Point) -> Point {
+        let delta = point - origin;
+
+        origin
+            + match self.orientation {
+                TextOrientation::Horizontal => delta,
+                TextOrientation::TopToBottom => Point::new(-delta.y, delta.x),
+                TextOrientation::BottomToTop => Point::new(delta.y, -delta.x),
+            }
+    }
+
+    /// Converts a rectangle in the horizontal text layout into the text orientation.
+    fn rotate_rectangle(&self, origin: Point, rectangle: Rectangle) -> Rectangle {
+        match rectangle.bottom_right() {
+            Some(bottom_right) if self.orientation != TextOrientation::Horizontal => {
+                Rectangle::with_corners(
+                    self.rotate_point(origin, rectangle.top_left),
+                    self.rotate_point(origin, bottom_right),
+                )
+            }
+            _ => Rectangle::new(
+                self.rotate_point(origin, rectangle.top_left),
+                rectangle.size,
+            ),
+        }
+    }
+
+    /// Returns the size of a scaled character.
+    fn character_size(&self) -> Size {
+        F::CHARACTER_SIZE.component_mul(self.scale)
+    }
+
+    /// Returns the scaled character spacing.
+    fn character_spacing(&self) -> u32 {
+        F::CHARACTER_SPACING * self.scale.width
+    }
+
     fn draw_background<D>(
         &self,
         width: u32,
         position: Point,
+        origin: Point,
         target: &mut D,
     ) -> Result<(), D::Error>
     where
@@ -88,10 +318,9 @@ where
         }
 
         if let Some(background_color) = self.background_color {
-            target.fill_solid(
-                &Rectangle::new(position, Size::new(width, F::CHARACTER_SIZE.height)),
-                background_color,
-            )?;
+            let area = Rectangle::new(position, Size::new(width, self.character_size().height));
+
+            target.fill_solid(&self.rotate_rectangle(origin, area), background_color)?;
         }
 
         Ok(())
@@ -101,30 +330,40 @@ where
         &self,
         width: u32,
         position: Point,
+        origin: Point,
         target: &mut D,
     ) -> Result<(), D::Error>
     where
         D: DrawTarget<Color = C>,
     {
         if let Some(strikethrough_color) = self.resolve_decoration_color(self.strikethrough_color) {
-            let top_left = position + Point::new(0, F::STRIKETHROUGH_OFFSET);
-            let size = Size::new(width, F::STRIKETHROUGH_HEIGHT);
+            let top_left =
+                position + Point::new(0, F::STRIKETHROUGH_OFFSET * self.scale.height as i32);
+            let size = Size::new(width, F::STRIKETHROUGH_HEIGHT * self.scale.height);
+            let area = self.rotate_rectangle(origin, Rectangle::new(top_left, size));
 
-            target.fill_solid(&Rectangle::new(top_left, size), strikethrough_color)?;
+            target.fill_solid(&area, strikethrough_color)?;
         }
 
         Ok(())
     }
 
-    fn draw_underline<D>(&self, width: u32, position: Point, target: &mut D) -> Result<(), D::Error>
+    fn draw_underline<D>(
+        &self,
+        width: u32,
+        position: Point,
+        origin: Point,
+        target: &mut D,
+    ) -> Result<(), D::Error>
     where
         D: DrawTarget<Color = C>,
     {
         if let Some(underline_color) = self.resolve_decoration_color(self.underline_color) {
-            let top_left = position + Point::new(0, F::UNDERLINE_OFFSET);
-            let size = Size::new(width, F::UNDERLINE_HEIGHT);
+            let top_left = position + Point::new(0, F::UNDERLINE_OFFSET * self.scale.height as i32);
+            let size = Size::new(width, F::UNDERLINE_HEIGHT * self.scale.height);
+            let area = self.rotate_rectangle(origin, Rectangle::new(top_left, size));
 
-            target.fill_solid(&Rectangle::new(top_left, size), underline_color)?;
+            target.fill_solid(&area, underline_color)?;
         }
 
         Ok(())
@@ -132,12 +371,17 @@ where
 
     /// Returns the vertical offset between the line position and the top edge of the bounding box.
     fn baseline_offset(&self, baseline: Baseline) -> i32 {
+        let height = self.character_size().height;
+
         match baseline {
             Baseline::Top => 0,
-            Baseline::Bottom => F::CHARACTER_SIZE.height.saturating_sub(1).saturating_cast(),
-            Baseline::Middle => (F::CHARACTER_SIZE.height.saturating_sub(1) / 2).saturating_cast(),
-            Baseline::Alphabetic => F::BASELINE
-                .unwrap_or_else(|| F::CHARACTER_SIZE.height.saturating_sub(1).saturating_cast()),
+            Baseline::Bottom => height.saturating_sub(1).saturating_cast(),
+            Baseline::Middle => (height.saturating_sub(1) / 2).saturating_cast(),
+            // The baseline is located at the bottom row of the magnified baseline pixels.
+            Baseline::Alphabetic => F::BASELINE.map_or_else(
+                || height.saturating_sub(1).saturating_cast(),
+                |baseline| (baseline + 1) * self.scale.height as i32 - 1,
+            ),
         }
     }
 }
@@ -159,29 +403,37 @@ where
     where
         D: DrawTarget<Color = Self::Color>,
     {
+        let origin = position;
         let position = position - Point::new(0, self.baseline_offset(baseline));
 
+        if self.has_outline() || self.shadow_color.is_some() {
+            return self.draw_string_with_effects(text, position, origin, target);
+        }
+
         let mut first = true;
         let mut p = position;
 
         let mut width = 0;
 
+        let character_size = self.character_size();
+        let character_spacing = self.character_spacing();
+
         for c in text.chars() {
             if first {
                 first = false;
-            } else if F::CHARACTER_SPACING > 0 {
+            } else if character_spacing > 0 {
                 // Fill space between characters if background color is set.
-                self.draw_background(F::CHARACTER_SPACING, p, target)?;
-                p += Size::new(F::CHARACTER_SPACING, 0);
-                width += F::CHARACTER_SPACING;
+                self.draw_background(character_spacing, p, origin, target)?;
+                p += Size::new(character_spacing, 0);
+                width += character_spacing;
             }
 
-            let pixels = MonoCharPixels::<F>::new(c);
+            let pixels = MonoCharPixels::<F>::new(c, self.scale, self.orientation);
+            let bounding_box = self.rotate_rectangle(origin, Rectangle::new(p, character_size));
+            let top_left = bounding_box.top_left;
 
             match (self.text_color, self.background_color) {
                 (Some(text_color), Some(background_color)) => {
-                    let bounding_box = Rectangle::new(p, F::CHARACTER_SIZE);
-
                     // The glyph is opaque if both colors are set and `fill_contiguous` can be used.
                     target.fill_contiguous(
                         &bounding_box,
@@ -195,29 +447,29 @@ where
                     target.draw_iter(
                         pixels
                             .filter(|Pixel(_, c)| *c == BinaryColor::On)
-                            .map(|Pixel(delta_p, _)| Pixel(p + delta_p, text_color)),
+                            .map(|Pixel(delta_p, _)| Pixel(top_left + delta_p, text_color)),
                     )?;
                 }
                 (None, Some(background_color)) => {
                     target.draw_iter(
                         pixels
                             .filter(|Pixel(_, c)| *c == BinaryColor::Off)
-                            .map(|Pixel(delta_p, _)| Pixel(p + delta_p, background_color)),
+                            .map(|Pixel(delta_p, _)| Pixel(top_left + delta_p, background_color)),
                     )?;
                 }
                 (None, None) => {}
             }
 
-            p += F::CHARACTER_SIZE.x_axis();
-            width += F::CHARACTER_SIZE.width;
+            p += character_size.x_axis();
+            width += character_size.width;
         }
 
-        self.draw_strikethrough(width, position, target)?;
-        self.draw_underline(width, position, target)?;
+        self.draw_strikethrough(width, position, origin, target)?;
+        self.draw_underline(width, position, origin, target)?;
 
         p.y += self.baseline_offset(baseline);
 
-        Ok(p)
+        Ok(self.rotate_point(origin, p))
     }
 
     fn draw_whitespace<D>(
@@ -230,37 +482,43 @@ where
     where
         D: DrawTarget<Color = Self::Color>,
     {
+        let origin = position;
         let position = position - Point::new(0, self.baseline_offset(baseline));
 
-        self.draw_background(width, position, target)?;
-        self.draw_strikethrough(width, position, target)?;
-        self.draw_underline(width, position, target)?;
+        self.draw_background(width, position, origin, target)?;
+        self.draw_strikethrough(width, position, origin, target)?;
+        self.draw_underline(width, position, origin, target)?;
 
-        Ok(position + Point::new(width.saturating_cast(), self.baseline_offset(baseline)))
+        Ok(self.rotate_point(origin, origin + Point::new(width.saturating_cast(), 0)))
     }
 
     fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
         let bb_position = position - Point::new(0, self.baseline_offset(baseline));
 
-        let bb_width = (text.len() as u32 * (F::CHARACTER_SIZE.width + F::CHARACTER_SPACING))
-            .saturating_sub(F::CHARACTER_SPACING);
+        let character_size = self.character_size();
+        let character_spacing = self.character_spacing();
+
+        let bb_width = (text.len() as u32 * (character_size.width + character_spacing))
+            .saturating_sub(character_spacing);
 
         let bb_height = if self.underline_color != DecorationColor::None {
-            F::UNDERLINE_HEIGHT + F::UNDERLINE_OFFSET as u32
+            (F::UNDERLINE_HEIGHT + F::UNDERLINE_OFFSET as u32) * self.scale.height
         } else {
-            F::CHARACTER_SIZE.height
+            character_size.height
         };
 
         let bb_size = Size::new(bb_width, bb_height);
 
         TextMetrics {
-            bounding_box: Rectangle::new(bb_position, bb_size),
-            next_position: position + bb_size.x_axis(),
+            bounding_box: self.extend_bounding_box(
+                self.rotate_rectangle(position, Rectangle::new(bb_position, bb_size)),
+            ),
+            next_position: self.rotate_point(position, position + bb_size.x_axis()),
         }
     }
 
     fn line_height(&self) -> u32 {
-        F::CHARACTER_SIZE.height
+        self.character_size().height
     }
 }
 
@@ -375,6 +633,12 @@ impl<C> MonoTextStyleBuilder<C, UndefinedFont> {
                 text_color: None,
                 underline_color: DecorationColor::None,
                 strikethrough_color: DecorationColor::None,
+                scale: Size::new(1, 1),
+                orientation: TextOrientation::Horizontal,
+                outline_color: None,
+                outline_width: 0,
+                shadow_color: None,
+                shadow_offset: Point::zero(),
             },
         }
     }
@@ -389,6 +653,12 @@ impl<C, F> MonoTextStyleBuilder<C, F> {
             text_color: self.style.text_color,
             underline_color: self.style.underline_color,
             strikethrough_color: self.style.strikethrough_color,
+            scale: self.style.scale,
+            orientation: self.style.orientation,
+            outline_color: self.style.outline_color,
+            outline_width: self.style.outline_width,
+            shadow_color: self.style.shadow_color,
+            shadow_offset: self.style.shadow_offset,
         };
 
         MonoTextStyleBuilder { style }
@@ -407,6 +677,73 @@ impl<C, F> MonoTextStyleBuilder<C, F> {
 
         self
     }
+
+    /// Sets the glyph scale factor.
+    ///
+    /// Each pixel of a glyph is magnified into a block of `x` x `y` pixels. A factor of 0 is
+    /// treated as 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mono_font::{ascii::Font6x9, MonoTextStyleBuilder},
+    ///     pixelcolor::Rgb565,
+    ///     prelude::*,
+    ///     text::TextRenderer,
+    /// };
+    ///
+    /// let style = MonoTextStyleBuilder::new()
+    ///     .font(Font6x9)
+    ///     .text_color(Rgb565::WHITE)
+    ///     .scale(2, 3)
+    ///     .build();
+    ///
+    /// assert_eq!(style.line_height(), 27);
+    /// ```
+    pub fn scale(mut self, x: u32, y: u32) -> Self {
+        self.style.scale = Size::new(x.max(1), y.max(1));
+
+        self
+    }
+
+    /// Sets the text orientation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mono_font::{ascii::Font6x9, MonoTextStyleBuilder, TextOrientation},
+    ///     pixelcolor::Rgb565,
+    ///     prelude::*,
+    ///     primitives::Rectangle,
+    ///     text::{Baseline, Text, TextStyleBuilder},
+    /// };
+    /// # let mut display = embedded_graphics::mock_display::MockDisplay::new();
+    ///
+    /// let character_style = MonoTextStyleBuilder::new()
+    ///     .font(Font6x9)
+    ///     .text_color(Rgb565::WHITE)
+    ///     .orientation(TextOrientation::BottomToTop)
+    ///     .build();
+    ///
+    /// let text_style = TextStyleBuilder::new()
+    ///     .character_style(character_style)
+    ///     .baseline(Baseline::Top)
+    ///     .build();
+    ///
+    /// // Draw a label which is read from bottom to top, starting at the bottom left corner.
+    /// let text = Text::new("42%", Point::new(0, 17)).into_styled(text_style);
+    /// assert_eq!(text.bounding_box(), Rectangle::new(Point::zero(), Size::new(9, 18)));
+    ///
+    /// text.draw(&mut display)?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    pub fn orientation(mut self, orientation: TextOrientation) -> Self {
+        self.style.orientation = orientation;
+
+        self
+    }
 }
 
 impl<C, F> MonoTextStyleBuilder<C, F>
@@ -440,6 +777,55 @@ where
 
         self
     }
+
+    /// Enables an outline around the glyphs.
+    ///
+    /// The outline is drawn with the given color around all set glyph pixels. The width is
+    /// specified in display pixels and isn't affected by the glyph scale. Outlines with a width of
+    /// 1 or 2 pixels are recommended, because the cost of drawing the outline grows with the
+    /// square of the width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mono_font::{ascii::Font6x9, MonoTextStyleBuilder},
+    ///     pixelcolor::Rgb565,
+    ///     prelude::*,
+    ///     text::Text,
+    /// };
+    /// # let mut display = embedded_graphics::mock_display::MockDisplay::new();
+    /// # display.set_allow_overdraw(true);
+    ///
+    /// let style = MonoTextStyleBuilder::new()
+    ///     .font(Font6x9)
+    ///     .text_color(Rgb565::WHITE)
+    ///     .outline(Rgb565::BLACK, 1)
+    ///     .shadow(Rgb565::CSS_DARK_GRAY, Point::new(2, 2))
+    ///     .build();
+    ///
+    /// Text::new("Readable", Point::new(2, 10))
+    ///     .into_styled(style)
+    ///     .draw(&mut display)?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    pub fn outline(mut self, outline_color: C, width: u32) -> Self {
+        self.style.outline_color = Some(outline_color);
+        self.style.outline_width = width;
+
+        self
+    }
+
+    /// Enables a drop shadow.
+    ///
+    /// The shadow is a copy of the glyphs, which is drawn in the shadow color behind the text.
+    /// The offset is specified in display pixels.
+    pub fn shadow(mut self, shadow_color: C, offset: Point) -> Self {
+        self.style.shadow_color = Some(shadow_color);
+        self.style.shadow_offset = offset;
+
+        self
+    }
 }
 
 impl<C, F> MonoTextStyleBuilder<C, F>
@@ -483,7 +869,8 @@ mod tests {
             tests::*,
         },
         pixelcolor::{BinaryColor, Rgb888, RgbColor},
-        text::{Text, TextStyleBuilder},
+        primitives::PointsIter,
+        text::{Alignment, Text, TextStyleBuilder},
         Drawable,
     };
 
@@ -514,6 +901,12 @@ mod tests {
                 background_color: None,
                 underline_color: DecorationColor::None,
                 strikethrough_color: DecorationColor::None,
+                scale: Size::new(1, 1),
+                orientation: TextOrientation::Horizontal,
+                outline_color: None,
+                outline_width: 0,
+                shadow_color: None,
+                shadow_offset: Point::zero(),
             }
         );
     }
@@ -948,6 +1341,12 @@ mod tests {
                 underline_color: DecorationColor::TextColor,
                 strikethrough_color: DecorationColor::Custom(BinaryColor::On),
                 font: Font6x9,
+                scale: Size::new(1, 1),
+                orientation: TextOrientation::Horizontal,
+                outline_color: None,
+                outline_width: 0,
+                shadow_color: None,
+                shadow_offset: Point::zero(),
             }
         );
     }
@@ -1005,4 +1404,322 @@ mod tests {
             );
         }
     }
+
+    /// Draws a text and magnifies each pixel of the result to create the expected scaled output.
+    fn scaled_reference<F>(style: MonoTextStyle<Rgb888, F>, text: &str) -> MockDisplay<Rgb888>
+    where
+        F: MonoFont,
+    {
+        let unscaled = MonoTextStyleBuilder::from(&style).scale(1, 1).build();
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        unscaled
+            .draw_string(text, Point::zero(), Baseline::Top, &mut display)
+            .unwrap();
+
+        let mut expected = MockDisplay::new();
+        for point in display.affected_area().points() {
+            let color = display.get_pixel(point);
+            let scale = style.scale;
+
+            for block_point in Rectangle::new(
+                point.component_mul(Point::new(scale.width as i32, scale.height as i32)),
+                scale,
+            )
+            .points()
+            {
+                expected.set_pixel(block_point, color);
+            }
+        }
+
+        expected
+    }
+
+    #[test]
+    fn scaled() {
+        let base = MonoTextStyleBuilder::new()
+            .font(SpacedFont)
+            .text_color(Rgb888::WHITE)
+            .underline_with_color(Rgb888::RED)
+            .strikethrough_with_color(Rgb888::GREEN);
+
+        for &(x, y) in &[(1, 1), (2, 2), (3, 1), (1, 4)] {
+            for style in [
+                base.scale(x, y).build(),
+                base.background_color(Rgb888::BLUE).scale(x, y).build(),
+            ]
+            .iter()
+            {
+                let mut display = MockDisplay::new();
+                display.set_allow_overdraw(true);
+                style
+                    .draw_string("Ab", Point::zero(), Baseline::Top, &mut display)
+                    .unwrap();
+
+                display.assert_eq_with_message(&scaled_reference(*style, "Ab"), |f| {
+                    write!(f, "scale: {}x{}", x, y)
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn scaled_metrics() {
+        let style = MonoTextStyleBuilder::new()
+            .font(SpacedFont)
+            .text_color(BinaryColor::On)
+            .scale(2, 3)
+            .build();
+
+        assert_eq!(style.line_height(), 27);
+
+        let metrics = style.measure_string("ABC", Point::new(1, 2), Baseline::Top);
+        assert_eq!(
+            metrics.bounding_box,
+            Rectangle::new(Point::new(1, 2), Size::new(3 * 12 + 2 * 10, 27))
+        );
+        assert_eq!(metrics.next_position, Point::new(1 + 56, 2));
+
+        let underlined = MonoTextStyleBuilder::from(&style).underline().build();
+        let metrics = underlined.measure_string("A", Point::zero(), Baseline::Top);
+        assert_eq!(metrics.bounding_box.size, Size::new(12, 30));
+    }
+
+    #[test]
+    fn scaled_baseline() {
+        let style = MonoTextStyleBuilder::new()
+            .font(Font6x9)
+            .text_color(BinaryColor::On)
+            .scale(2, 2)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Text::new("A", Point::new(0, 20))
+            .into_styled(
+                TextStyleBuilder::new()
+                    .character_style(style)
+                    .baseline(Baseline::Alphabetic)
+                    .build(),
+            )
+            .draw(&mut display)
+            .unwrap();
+
+        // The bottom of the `A` glyph is located on the baseline.
+        assert_eq!(display.affected_area().bottom_right().unwrap().y, 20);
+    }
+
+    #[test]
+    fn zero_scale() {
+        let style = MonoTextStyleBuilder::new()
+            .font(Font6x9)
+            .text_color(BinaryColor::On)
+            .scale(0, 0)
+            .build();
+
+        assert_eq!(style.scale, Size::new(1, 1));
+    }
+
+    /// Draws a reference by rotating horizontal text pixel by pixel.
+    fn rotated_reference<F: MonoFont>(
+        style: MonoTextStyle<Rgb888, F>,
+        text: &str,
+        origin: Point,
+    ) -> MockDisplay<Rgb888> {
+        let horizontal_style = MonoTextStyleBuilder::from(&style)
+            .orientation(TextOrientation::Horizontal)
+            .build();
+
+        let horizontal_origin = Point::new(0, 30);
+
+        let mut horizontal = MockDisplay::new();
+        horizontal.set_allow_overdraw(true);
+        horizontal_style
+            .draw_string(
+                text,
+                horizontal_origin,
+                Baseline::Alphabetic,
+                &mut horizontal,
+            )
+            .unwrap();
+
+        let mut expected = MockDisplay::new();
+        for point in horizontal.affected_area().points() {
+            if let Some(color) = horizontal.get_pixel(point) {
+                let point = origin + (point - horizontal_origin);
+                expected.set_pixel(style.rotate_point(origin, point), Some(color));
+            }
+        }
+
+        expected
+    }
+
+    #[test]
+    fn rotated() {
+        let base = MonoTextStyleBuilder::new()
+            .font(SpacedFont)
+            .text_color(Rgb888::WHITE)
+            .underline_with_color(Rgb888::RED)
+            .strikethrough_with_color(Rgb888::GREEN);
+
+        for &(orientation, origin) in &[
+            (TextOrientation::TopToBottom, Point::new(30, 5)),
+            (TextOrientation::BottomToTop, Point::new(30, 58)),
+        ] {
+            for &(x, y) in &[(1, 1), (2, 1), (1, 2)] {
+                for style in [
+                    base.scale(x, y).orientation(orientation).build(),
+                    base.background_color(Rgb888::BLUE)
+                        .scale(x, y)
+                        .orientation(orientation)
+                        .build(),
+                ]
+                .iter()
+                {
+                    let mut display = MockDisplay::new();
+                    display.set_allow_overdraw(true);
+                    let next = style
+                        .draw_string("Ab", origin, Baseline::Alphabetic, &mut display)
+                        .unwrap();
+
+                    display.assert_eq_with_message(&rotated_reference(*style, "Ab", origin), |f| {
+                        write!(f, "{:?}, scale: {}x{}", orientation, x, y)
+                    });
+
+                    let metrics = style.measure_string("Ab", origin, Baseline::Alphabetic);
+                    assert_eq!(next, metrics.next_position);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rotated_metrics() {
+        let style = MonoTextStyleBuilder::new()
+            .font(Font6x9)
+            .text_color(BinaryColor::On)
+            .orientation(TextOrientation::TopToBottom)
+            .build();
+
+        let metrics = style.measure_string("ABC", Point::new(10, 0), Baseline::Top);
+        assert_eq!(
+            metrics.bounding_box,
+            Rectangle::new(Point::new(2, 0), Size::new(9, 18))
+        );
+        assert_eq!(metrics.next_position, Point::new(10, 18));
+
+        let style = MonoTextStyleBuilder::from(&style)
+            .orientation(TextOrientation::BottomToTop)
+            .build();
+
+        let metrics = style.measure_string("ABC", Point::new(0, 20), Baseline::Top);
+        assert_eq!(
+            metrics.bounding_box,
+            Rectangle::new(Point::new(0, 3), Size::new(9, 18))
+        );
+        assert_eq!(metrics.next_position, Point::new(0, 2));
+    }
+
+    #[test]
+    fn rotated_alignment() {
+        let character_style = MonoTextStyleBuilder::new()
+            .font(Font6x9)
+            .text_color(BinaryColor::On)
+            .orientation(TextOrientation::TopToBottom)
+            .build();
+
+        let text_style = TextStyleBuilder::new()
+            .character_style(character_style)
+            .baseline(Baseline::Top)
+            .alignment(Alignment::Right)
+            .build();
+
+        // Right aligned text which is read from top to bottom ends at the text position.
+        let text = Text::new("ABC", Point::new(10, 30)).into_styled(text_style);
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::new(2, 13), Size::new(9, 18))
+        );
+    }
+
+    #[test]
+    fn outline() {
+        let style = MonoTextStyleBuilder::new()
+            .font(Font6x9)
+            .text_color(Rgb888::WHITE)
+            .outline(Rgb888::RED, 1)
+            .build();
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        style
+            .draw_string("-.", Point::new(1, 0), Baseline::Top, &mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "            ",
+            "            ",
+            "            ",
+            "RRRRRRR     ",
+            "RWWWWWR RRRR",
+            "RRRRRRR RWWR",
+            "        RWWR",
+            "        RRRR",
+        ]);
+    }
+
+    #[test]
+    fn shadow() {
+        let style = MonoTextStyleBuilder::new()
+            .font(Font6x9)
+            .text_color(Rgb888::WHITE)
+            .background_color(Rgb888::BLUE)
+            .shadow(Rgb888::GREEN, Point::new(1, 1))
+            .build();
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        let next = style
+            .draw_string("-.", Point::zero(), Baseline::Top, &mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "BBBBBBBBBBBB",
+            "BBBBBBBBBBBB",
+            "BBBBBBBBBBBB",
+            "BBBBBBBBBBBB",
+            "WWWWWBBBBBBB",
+            "BGGGGGBBWWBB",
+            "BBBBBBBBWWGB",
+            "BBBBBBBBBGGB",
+            "BBBBBBBBBBBB",
+        ]);
+        assert_eq!(next, Point::new(12, 0));
+    }
+
+    #[test]
+    fn effects_metrics() {
+        let style = MonoTextStyleBuilder::new()
+            .font(Font6x9)
+            .text_color(BinaryColor::On)
+            .outline(BinaryColor::Off, 2)
+            .build();
+
+        let metrics = style.measure_string("AB", Point::new(10, 10), Baseline::Top);
+        assert_eq!(
+            metrics.bounding_box,
+            Rectangle::new(Point::new(8, 8), Size::new(12 + 4, 9 + 4))
+        );
+        assert_eq!(metrics.next_position, Point::new(22, 10));
+
+        let style = MonoTextStyleBuilder::from(&style)
+            .shadow(BinaryColor::On, Point::new(3, -4))
+            .build();
+
+        let metrics = style.measure_string("AB", Point::new(10, 10), Baseline::Top);
+        assert_eq!(
+            metrics.bounding_box,
+            Rectangle::with_corners(Point::new(8, 6), Point::new(24, 20))
+        );
+    }
 }
@@ -11,7 +11,10 @@ pub use crate::{
         raw::{RawData, ToBytes as _},
         GrayColor, IntoStorage, PixelColor, RgbColor, WebColors,
     },
-    primitives::{ContainsPoint, OffsetOutline, PointsIter, Primitive, StyledPrimitiveAreas},
+    primitives::{
+        ContainsPoint, OffsetOutline, PointsIter, Primitive, StyledContainsPoint,
+        StyledPrimitiveAreas,
+    },
     transform::Transform,
     Drawable, Pixel,
 };
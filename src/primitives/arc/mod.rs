@@ -5,7 +5,7 @@ mod styled;
 
 use crate::{
     geometry::{Angle, Dimensions, Point, Size},
-    primitives::{Circle, PointsIter, Primitive, Rectangle},
+    primitives::{Circle, OffsetOutline, PointsIter, Primitive, Rectangle},
     transform::Transform,
 };
 pub use points::Points;
@@ -110,6 +110,14 @@ impl Arc {
     }
 }
 
+impl OffsetOutline for Arc {
+    fn offset(&self, offset: i32) -> Self {
+        let circle = self.to_circle().offset(offset);
+
+        Self::from_circle(circle, self.angle_start, self.angle_sweep)
+    }
+}
+
 impl Primitive for Arc {}
 
 impl PointsIter for Arc {
@@ -214,4 +222,30 @@ mod tests {
         let arc = Arc::with_center(Point::new(10, 10), 6, 0.0.deg(), 90.0.deg());
         assert_eq!(arc.center(), Point::new(10, 10));
     }
+
+    #[test]
+    fn offset() {
+        let center = Point::new(5, 7);
+        let arc = Arc::with_center(center, 3, 0.0.deg(), 90.0.deg());
+
+        assert_eq!(arc.offset(0), arc);
+
+        assert_eq!(
+            arc.offset(1),
+            Arc::with_center(center, 5, 0.0.deg(), 90.0.deg())
+        );
+        assert_eq!(
+            arc.offset(2),
+            Arc::with_center(center, 7, 0.0.deg(), 90.0.deg())
+        );
+
+        assert_eq!(
+            arc.offset(-1),
+            Arc::with_center(center, 1, 0.0.deg(), 90.0.deg())
+        );
+        assert_eq!(
+            arc.offset(-2),
+            Arc::with_center(center, 0, 0.0.deg(), 90.0.deg())
+        );
+    }
 }
@@ -1,12 +1,12 @@
 use crate::{
     draw_target::DrawTarget,
-    geometry::Dimensions,
+    geometry::{Dimensions, Point, PointExt},
     iterator::IntoPixels,
     pixelcolor::PixelColor,
     primitives::{
         arc::Arc,
         common::{DistanceIterator, PlaneSector},
-        OffsetOutline, PrimitiveStyle, Rectangle, Styled,
+        OffsetOutline, PrimitiveStyle, Rectangle, Styled, StyledContainsPoint,
     },
     Drawable, Pixel, SaturatingCast,
 };
@@ -56,6 +56,13 @@ where
             stroke_color: styled.style.stroke_color,
         }
     }
+
+    /// Returns `true` if a point with the given delta and squared distance is part of the arc.
+    fn is_stroke(&self, delta: Point, distance: u32) -> bool {
+        distance < self.outer_threshold
+            && distance >= self.inner_threshold
+            && self.plane_sector.contains(delta)
+    }
 }
 
 impl<C> Iterator for StyledPixels<C>
@@ -66,17 +73,14 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         let stroke_color = self.stroke_color?;
-        let outer_threshold = self.outer_threshold;
-        let inner_threshold = self.inner_threshold;
-        let plane_sector = self.plane_sector;
-
-        self.iter
-            .find(|(_, delta, distance)| {
-                *distance < outer_threshold
-                    && *distance >= inner_threshold
-                    && plane_sector.contains(*delta)
-            })
-            .map(|(point, ..)| Pixel(point, stroke_color))
+
+        while let Some((point, delta, distance)) = self.iter.next() {
+            if self.is_stroke(delta, distance) {
+                return Some(Pixel(point, stroke_color));
+            }
+        }
+
+        None
     }
 }
 
@@ -108,6 +112,22 @@ where
     }
 }
 
+impl<C> StyledContainsPoint for Styled<Arc, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn contains(&self, point: Point) -> bool {
+        if self.style.effective_stroke_color().is_none() {
+            return false;
+        }
+
+        let delta = point * 2 - self.primitive.to_circle().center_2x();
+        let distance = delta.length_squared() as u32;
+
+        StyledPixels::new(self).is_stroke(delta, distance)
+    }
+}
+
 impl<C> Dimensions for Styled<Arc, PrimitiveStyle<C>>
 where
     C: PixelColor,
@@ -128,7 +148,9 @@ mod tests {
         geometry::{AnchorPoint, AngleUnit, Point, Size},
         mock_display::MockDisplay,
         pixelcolor::BinaryColor,
-        primitives::{Circle, Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment},
+        primitives::{
+            Circle, PointsIter, Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment,
+        },
     };
 
     // Check the rendering of a simple arc
@@ -273,4 +295,29 @@ mod tests {
 
         assert_eq!(transparent_arc.bounding_box(), stroked_arc.bounding_box(),);
     }
+
+    #[test]
+    fn contains() {
+        let shape = Arc::new(Point::new(5, 8), 30, 30.0.deg(), 200.0.deg());
+
+        for &stroke_width in &[1, 2, 5] {
+            let style = PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width);
+            let styled = shape.into_styled(style);
+
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+
+            let contained = MockDisplay::from_points(
+                display
+                    .bounding_box()
+                    .points()
+                    .filter(|p| styled.contains(*p)),
+            );
+
+            contained.assert_eq_with_message(&display, |f| write!(f, "{:?}", style));
+        }
+
+        let transparent = shape.into_styled(PrimitiveStyle::<BinaryColor>::new());
+        assert!(!transparent.contains(Point::new(20, 8)));
+    }
 }
@@ -2,6 +2,7 @@
 
 mod points;
 mod styled;
+mod subpixel;
 
 use crate::{
     geometry::{Dimensions, Point, PointExt, Size},
@@ -12,6 +13,7 @@ use crate::{
 };
 pub use points::Points;
 pub use styled::StyledPixels;
+pub use subpixel::{SubpixelCircle, SubpixelPoints, SubpixelStyledPixels};
 
 /// Circle primitive
 ///
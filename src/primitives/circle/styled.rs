@@ -1,11 +1,11 @@
 use crate::{
     draw_target::DrawTarget,
-    geometry::Dimensions,
+    geometry::{Dimensions, Point},
     iterator::IntoPixels,
     pixelcolor::PixelColor,
     primitives::{
         circle::Circle, common::DistanceIterator, rectangle::Rectangle, PrimitiveStyle,
-        StyledPrimitiveAreas,
+        StyledContainsPoint, StyledPrimitiveAreas,
     },
     Drawable, Pixel, SaturatingCast, Styled,
 };
@@ -102,6 +102,15 @@ where
     }
 }
 
+impl<C> StyledContainsPoint for Styled<Circle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn contains(&self, point: Point) -> bool {
+        self.areas_contain(point)
+    }
+}
+
 impl<C> Dimensions for Styled<Circle, PrimitiveStyle<C>>
 where
     C: PixelColor,
@@ -308,4 +317,45 @@ mod tests {
             filled_circle.bounding_box(),
         );
     }
+
+    #[test]
+    fn contains() {
+        let shape = Circle::new(Point::new(5, 8), 20);
+
+        for &stroke_alignment in &[
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            let stroke = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(3)
+                .stroke_alignment(stroke_alignment)
+                .build();
+            let stroke_and_fill = PrimitiveStyleBuilder::from(&stroke)
+                .fill_color(BinaryColor::Off)
+                .build();
+
+            for &style in &[
+                stroke,
+                stroke_and_fill,
+                PrimitiveStyle::with_fill(BinaryColor::On),
+            ] {
+                let styled = shape.into_styled(style);
+
+                let mut display = MockDisplay::new();
+                styled.draw(&mut display).unwrap();
+
+                let expected = display.map(|_| BinaryColor::On);
+                let contained = MockDisplay::from_points(
+                    expected
+                        .bounding_box()
+                        .points()
+                        .filter(|p| styled.contains(*p)),
+                );
+
+                contained.assert_eq_with_message(&expected, |f| write!(f, "{:?}", style));
+            }
+        }
+    }
 }
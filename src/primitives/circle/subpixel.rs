@@ -0,0 +1,355 @@
+//! Circles with sub-pixel precision.
+
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size, SubpixelPoint},
+    iterator::IntoPixels,
+    pixelcolor::PixelColor,
+    primitives::{
+        circle::{diameter_to_threshold, Circle},
+        rectangle::{self, Rectangle},
+        ContainsPoint, PointsIter, Primitive, PrimitiveStyle,
+    },
+    transform::Transform,
+    Drawable, Pixel, Styled,
+};
+
+/// Circle with a sub-pixel precise center point.
+///
+/// Unlike [`Circle`], which is aligned to the pixel grid, the center of a `SubpixelCircle` can be
+/// placed between pixel centers. A `SubpixelCircle` which is converted from a `Circle` results in
+/// the same pixels as the original circle.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::{
+///     geometry::SubpixelPoint,
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PrimitiveStyle, SubpixelCircle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// // Circle with a diameter of 20 centered at (30.75, 20.25)
+/// SubpixelCircle::new(SubpixelPoint::from_bits(30 * 256 + 192, 20 * 256 + 64), 20)
+///     .into_styled(PrimitiveStyle::with_fill(Rgb565::RED))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Circle`]: struct.Circle.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct SubpixelCircle {
+    /// Center point
+    pub center: SubpixelPoint,
+
+    /// Diameter of the circle
+    pub diameter: u32,
+}
+
+impl SubpixelCircle {
+    /// Create a new subpixel circle with the given center and diameter.
+    pub const fn new(center: SubpixelPoint, diameter: u32) -> Self {
+        Self { center, diameter }
+    }
+
+    /// Returns a circle with the same center and a different diameter.
+    fn with_diameter(&self, diameter: u32) -> Self {
+        Self::new(self.center, diameter)
+    }
+
+    /// Returns the threshold for the squared distance in doubled subpixel units.
+    fn threshold(&self) -> i64 {
+        i64::from(diameter_to_threshold(self.diameter)) * i64::from(SubpixelPoint::ONE).pow(2)
+    }
+
+    /// Returns the squared distance of the pixel center in doubled subpixel units.
+    fn distance(&self, point: Point) -> i64 {
+        let delta = SubpixelPoint::from(point) - self.center;
+
+        (2 * i64::from(delta.x)).pow(2) + (2 * i64::from(delta.y)).pow(2)
+    }
+}
+
+impl From<Circle> for SubpixelCircle {
+    fn from(circle: Circle) -> Self {
+        let center_2x = circle.center_2x();
+
+        Self::new(
+            SubpixelPoint::from_bits(
+                center_2x.x * SubpixelPoint::ONE / 2,
+                center_2x.y * SubpixelPoint::ONE / 2,
+            ),
+            circle.diameter,
+        )
+    }
+}
+
+impl Primitive for SubpixelCircle {}
+
+impl PointsIter for SubpixelCircle {
+    type Iter = SubpixelPoints;
+
+    fn points(&self) -> Self::Iter {
+        SubpixelPoints {
+            points: self.bounding_box().points(),
+            circle: *self,
+        }
+    }
+}
+
+impl ContainsPoint for SubpixelCircle {
+    fn contains(&self, point: Point) -> bool {
+        self.distance(point) < self.threshold()
+    }
+}
+
+impl Dimensions for SubpixelCircle {
+    fn bounding_box(&self) -> Rectangle {
+        if self.diameter == 0 {
+            return Rectangle::new(self.center.round(), Size::zero());
+        }
+
+        let one = i64::from(SubpixelPoint::ONE);
+        let radius = i64::from(self.diameter) * one / 2;
+        let center = (i64::from(self.center.x), i64::from(self.center.y));
+
+        let min = Point::new(
+            -(radius - center.0).div_euclid(one) as i32,
+            -(radius - center.1).div_euclid(one) as i32,
+        );
+        let max = Point::new(
+            (center.0 + radius).div_euclid(one) as i32,
+            (center.1 + radius).div_euclid(one) as i32,
+        );
+
+        Rectangle::with_corners(min, max)
+    }
+}
+
+impl Transform for SubpixelCircle {
+    /// Translate the circle from its current position to a new position by (x, y) pixels,
+    /// returning a new `SubpixelCircle`. For a mutating transform, see `translate_mut`.
+    fn translate(&self, by: Point) -> Self {
+        Self::new(self.center + by.into(), self.diameter)
+    }
+
+    /// Translate the circle from its current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.center += by.into();
+
+        self
+    }
+}
+
+/// Iterator over all points inside a subpixel circle.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct SubpixelPoints {
+    points: rectangle::Points,
+    circle: SubpixelCircle,
+}
+
+impl Iterator for SubpixelPoints {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let circle = self.circle;
+
+        self.points.find(|p| circle.contains(*p))
+    }
+}
+
+/// Pixel iterator for a styled subpixel circle.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct SubpixelStyledPixels<C>
+where
+    C: PixelColor,
+{
+    points: rectangle::Points,
+
+    circle: SubpixelCircle,
+
+    outer_threshold: i64,
+    outer_color: Option<C>,
+
+    inner_threshold: i64,
+    inner_color: Option<C>,
+}
+
+impl<C> SubpixelStyledPixels<C>
+where
+    C: PixelColor,
+{
+    fn new(styled: &Styled<SubpixelCircle, PrimitiveStyle<C>>) -> Self {
+        let Styled { primitive, style } = styled;
+
+        let stroke_area = primitive.with_diameter(
+            primitive
+                .diameter
+                .saturating_add(2 * style.outside_stroke_width()),
+        );
+        let fill_area = primitive.with_diameter(
+            primitive
+                .diameter
+                .saturating_sub(2 * style.inside_stroke_width()),
+        );
+
+        let points = if !style.is_transparent() {
+            stroke_area.bounding_box().points()
+        } else {
+            rectangle::Points::empty()
+        };
+
+        Self {
+            points,
+            circle: *primitive,
+            outer_threshold: stroke_area.threshold(),
+            outer_color: style.stroke_color,
+            inner_threshold: fill_area.threshold(),
+            inner_color: style.fill_color,
+        }
+    }
+}
+
+impl<C> Iterator for SubpixelStyledPixels<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for point in &mut self.points {
+            let distance = self.circle.distance(point);
+
+            let color = if distance < self.inner_threshold {
+                self.inner_color
+            } else if distance < self.outer_threshold {
+                self.outer_color
+            } else {
+                None
+            };
+
+            if let Some(color) = color {
+                return Some(Pixel(point, color));
+            }
+        }
+
+        None
+    }
+}
+
+impl<C> IntoPixels for &Styled<SubpixelCircle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    type Color = C;
+
+    type Iter = SubpixelStyledPixels<Self::Color>;
+
+    fn into_pixels(self) -> Self::Iter {
+        SubpixelStyledPixels::new(self)
+    }
+}
+
+impl<C> Drawable for Styled<SubpixelCircle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        display.draw_iter(self.into_pixels())
+    }
+}
+
+impl<C> Dimensions for Styled<SubpixelCircle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.primitive
+            .with_diameter(
+                self.primitive
+                    .diameter
+                    .saturating_add(2 * self.style.outside_stroke_width()),
+            )
+            .bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{PrimitiveStyleBuilder, StrokeAlignment},
+    };
+
+    #[test]
+    fn matches_circle() {
+        for diameter in 0..12 {
+            let circle = Circle::new(Point::new(3, -2), diameter);
+            let subpixel = SubpixelCircle::from(circle);
+
+            assert!(subpixel.points().eq(circle.points()), "{}", diameter);
+        }
+    }
+
+    #[test]
+    fn styled_matches_circle() {
+        for &alignment in &[
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            let style = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(3)
+                .stroke_alignment(alignment)
+                .fill_color(BinaryColor::Off)
+                .build();
+
+            let circle = Circle::new(Point::new(4, 5), 11).into_styled(style);
+            let subpixel = SubpixelCircle::from(circle.primitive).into_styled(style);
+
+            let mut expected = MockDisplay::new();
+            circle.draw(&mut expected).unwrap();
+
+            let mut display = MockDisplay::new();
+            subpixel.draw(&mut display).unwrap();
+
+            display.assert_eq(&expected);
+            assert_eq!(subpixel.bounding_box(), circle.bounding_box());
+        }
+    }
+
+    #[test]
+    fn subpixel_center() {
+        // Centered at (2.0, 2.25)
+        let circle = SubpixelCircle::new(SubpixelPoint::from_bits(512, 512 + 64), 4);
+
+        MockDisplay::from_points(circle.points()).assert_pattern(&[
+            "     ", //
+            " ### ", //
+            " ### ", //
+            " ### ", //
+            "  #  ", //
+        ]);
+    }
+
+    #[test]
+    fn bounding_box_contains_points() {
+        let circle = SubpixelCircle::new(SubpixelPoint::from_bits(-300, 1000), 7);
+        let bounding_box = circle.bounding_box();
+
+        assert!(circle.points().all(|p| bounding_box.contains(p)));
+        assert_eq!(circle.bounding_box().size, Size::new(7, 7));
+    }
+}
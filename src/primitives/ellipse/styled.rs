@@ -5,7 +5,7 @@ use crate::{
     pixelcolor::PixelColor,
     primitives::{
         ellipse::{compute_threshold, is_point_inside_ellipse, points::Points, Ellipse},
-        PrimitiveStyle, Rectangle, StyledPrimitiveAreas,
+        PrimitiveStyle, Rectangle, StyledContainsPoint, StyledPrimitiveAreas,
     },
     Drawable, Pixel, SaturatingCast, Styled,
 };
@@ -106,6 +106,15 @@ where
     }
 }
 
+impl<C> StyledContainsPoint for Styled<Ellipse, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn contains(&self, point: Point) -> bool {
+        self.areas_contain(point)
+    }
+}
+
 impl<C> Dimensions for Styled<Ellipse, PrimitiveStyle<C>>
 where
     C: PixelColor,
@@ -124,7 +133,9 @@ mod tests {
         geometry::{Point, Size},
         mock_display::MockDisplay,
         pixelcolor::BinaryColor,
-        primitives::{Circle, Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment},
+        primitives::{
+            Circle, PointsIter, Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment,
+        },
         Drawable, SaturatingCast,
     };
 
@@ -350,4 +361,45 @@ mod tests {
             filled_ellipse.bounding_box(),
         );
     }
+
+    #[test]
+    fn contains() {
+        let shape = Ellipse::new(Point::new(5, 8), Size::new(25, 15));
+
+        for &stroke_alignment in &[
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            let stroke = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(3)
+                .stroke_alignment(stroke_alignment)
+                .build();
+            let stroke_and_fill = PrimitiveStyleBuilder::from(&stroke)
+                .fill_color(BinaryColor::Off)
+                .build();
+
+            for &style in &[
+                stroke,
+                stroke_and_fill,
+                PrimitiveStyle::with_fill(BinaryColor::On),
+            ] {
+                let styled = shape.into_styled(style);
+
+                let mut display = MockDisplay::new();
+                styled.draw(&mut display).unwrap();
+
+                let expected = display.map(|_| BinaryColor::On);
+                let contained = MockDisplay::from_points(
+                    expected
+                        .bounding_box()
+                        .points()
+                        .filter(|p| styled.contains(*p)),
+                );
+
+                contained.assert_eq_with_message(&expected, |f| write!(f, "{:?}", style));
+            }
+        }
+    }
 }
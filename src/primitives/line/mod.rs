@@ -4,13 +4,17 @@ mod bresenham;
 pub(in crate::primitives) mod intersection_params;
 mod points;
 mod styled;
+mod subpixel;
 mod thick_points;
 
 use crate::{
     geometry::{Dimensions, Point},
     primitives::{
         common::StrokeOffset,
-        line::thick_points::{ParallelLineType, ParallelsIterator},
+        line::{
+            intersection_params::{Intersection, IntersectionParams},
+            thick_points::{ParallelLineType, ParallelsIterator},
+        },
         PointsIter, Primitive, Rectangle,
     },
     transform::Transform,
@@ -18,6 +22,8 @@ use crate::{
 };
 pub use points::Points;
 pub use styled::StyledPixels;
+pub(in crate::primitives) use subpixel::ThickSegment;
+pub use subpixel::{SubpixelLine, SubpixelPoints, SubpixelStyledPixels};
 
 /// Line primitive
 ///
@@ -152,6 +158,36 @@ impl Line {
         (left_line, right_line)
     }
 
+    /// Returns a parallel line with the given offset.
+    ///
+    /// Positive offsets move the line to the left and negative offsets move the line to the
+    /// right side. The offset is measured in the same way as the stroke of a thick line, which
+    /// makes the returned line coincide with the outermost pixels of a stroke that is
+    /// `offset.abs() + 1` pixels wide.
+    pub(in crate::primitives) fn parallel(&self, offset: i32) -> Self {
+        if offset > 0 {
+            let width = (offset as u32).saturating_add(1);
+
+            self.extents(width, StrokeOffset::Left).0
+        } else if offset < 0 {
+            let width = ((-i64::from(offset)) as u32).saturating_add(1);
+
+            self.extents(width, StrokeOffset::Right).1
+        } else {
+            *self
+        }
+    }
+
+    /// Returns the intersection point of the infinite lines through `self` and `other`.
+    ///
+    /// `None` is returned if the lines are parallel.
+    pub(in crate::primitives) fn line_intersection(&self, other: &Line) -> Option<Point> {
+        match IntersectionParams::from_lines(self, other).intersection() {
+            Intersection::Point { point, .. } => Some(point),
+            Intersection::Colinear => None,
+        }
+    }
+
     /// Compute the midpoint of the line.
     pub fn midpoint(&self) -> Point {
         self.start + (self.end - self.start) / 2
@@ -435,6 +471,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parallel() {
+        let line = Line::new(Point::new(10, 20), Point::new(30, 20));
+
+        assert_eq!(line.parallel(0), line);
+        assert_eq!(
+            line.parallel(3),
+            Line::new(Point::new(10, 17), Point::new(30, 17))
+        );
+        assert_eq!(
+            line.parallel(-2),
+            Line::new(Point::new(10, 22), Point::new(30, 22))
+        );
+
+        let line = Line::new(Point::new(10, 20), Point::new(10, 0));
+
+        assert_eq!(
+            line.parallel(1),
+            Line::new(Point::new(9, 20), Point::new(9, 0))
+        );
+        assert_eq!(
+            line.parallel(-4),
+            Line::new(Point::new(14, 20), Point::new(14, 0))
+        );
+    }
+
+    #[test]
+    fn line_intersection() {
+        let horizontal = Line::new(Point::new(0, 5), Point::new(1, 5));
+        let vertical = Line::new(Point::new(-3, 10), Point::new(-3, 20));
+
+        assert_eq!(
+            horizontal.line_intersection(&vertical),
+            Some(Point::new(-3, 5))
+        );
+        assert_eq!(
+            horizontal.line_intersection(&horizontal.translate(Point::new(0, 1))),
+            None
+        );
+    }
+
     #[test]
     fn extents_zero_thickness() {
         let line = Line::new(Point::new(10, 20), Point::new(20, 10));
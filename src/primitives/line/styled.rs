@@ -1,11 +1,11 @@
 use crate::{
     draw_target::DrawTarget,
-    geometry::Dimensions,
+    geometry::{Dimensions, Point},
     iterator::IntoPixels,
     pixelcolor::PixelColor,
     primitives::{
         line::{thick_points::ThickPoints, Line, StrokeOffset},
-        PrimitiveStyle, Rectangle,
+        PrimitiveStyle, Rectangle, StyledContainsPoint,
     },
     Drawable, Pixel, SaturatingCast, Styled,
 };
@@ -83,6 +83,22 @@ where
     }
 }
 
+impl<C> StyledContainsPoint for Styled<Line, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn contains(&self, point: Point) -> bool {
+        if self.style.effective_stroke_color().is_none() || !self.bounding_box().contains(point) {
+            return false;
+        }
+
+        // PERF: Checking the point against the line edges would be faster than iterating over
+        // all points.
+        ThickPoints::new(&self.primitive, self.style.stroke_width.saturating_cast())
+            .any(|p| p == point)
+    }
+}
+
 impl<C> Dimensions for Styled<Line, PrimitiveStyle<C>>
 where
     C: PixelColor,
@@ -113,8 +129,8 @@ mod tests {
     use crate::{
         geometry::Point,
         mock_display::MockDisplay,
-        pixelcolor::{Rgb888, RgbColor},
-        primitives::{Primitive, PrimitiveStyleBuilder},
+        pixelcolor::{BinaryColor, Rgb888, RgbColor},
+        primitives::{PointsIter, Primitive, PrimitiveStyleBuilder},
     };
 
     #[test]
@@ -171,4 +187,29 @@ mod tests {
 
         assert_eq!(transparent_line.bounding_box(), stroked_line.bounding_box(),);
     }
+
+    #[test]
+    fn contains() {
+        let shape = Line::new(Point::new(5, 8), Point::new(30, 20));
+
+        for &stroke_width in &[1, 2, 5] {
+            let style = PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width);
+            let styled = shape.into_styled(style);
+
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+
+            let contained = MockDisplay::from_points(
+                display
+                    .bounding_box()
+                    .points()
+                    .filter(|p| styled.contains(*p)),
+            );
+
+            contained.assert_eq_with_message(&display, |f| write!(f, "{:?}", style));
+        }
+
+        let transparent = shape.into_styled(PrimitiveStyle::<BinaryColor>::new());
+        assert!(!transparent.contains(Point::new(5, 8)));
+    }
 }
@@ -0,0 +1,569 @@
+//! Lines with sub-pixel precision.
+
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, SubpixelPoint},
+    iterator::IntoPixels,
+    pixelcolor::PixelColor,
+    primitives::{
+        line::Line,
+        rectangle::{self, Rectangle},
+        PointsIter, Primitive, PrimitiveStyle,
+    },
+    transform::Transform,
+    Drawable, Pixel, Styled,
+};
+
+/// Line with sub-pixel precise start and end points.
+///
+/// Unlike [`Line`], which always starts and ends at the center of a pixel, the start and end
+/// points of a `SubpixelLine` can be placed between pixel centers. The rasterized pixels take the
+/// exact position of the line into account, which avoids jitter when a line is moved by less than
+/// one pixel per frame during animations.
+///
+/// A `SubpixelLine` with integer start and end points and a stroke width of 1 results in the same
+/// pixels as the equivalent `Line`. Styled subpixel lines with a stroke width greater than 1 are
+/// drawn with butt ends, by drawing all pixels whose centers are inside the stroke, which can
+/// result in different pixels than a thick `Line`, even for integer start and end points.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::{
+///     geometry::SubpixelPoint,
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PrimitiveStyle, SubpixelLine},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// // Line from (10.25, 20.5) to (40.75, 30.0)
+/// SubpixelLine::new(
+///     SubpixelPoint::from_bits(10 * 256 + 64, 20 * 256 + 128),
+///     SubpixelPoint::from_bits(40 * 256 + 192, 30 * 256),
+/// )
+/// .into_styled(PrimitiveStyle::with_stroke(Rgb565::GREEN, 1))
+/// .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Line`]: struct.Line.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct SubpixelLine {
+    /// Start point
+    pub start: SubpixelPoint,
+
+    /// End point
+    pub end: SubpixelPoint,
+}
+
+impl SubpixelLine {
+    /// Create a new subpixel line.
+    pub const fn new(start: SubpixelPoint, end: SubpixelPoint) -> Self {
+        Self { start, end }
+    }
+}
+
+impl From<Line> for SubpixelLine {
+    fn from(line: Line) -> Self {
+        Self::new(line.start.into(), line.end.into())
+    }
+}
+
+impl Primitive for SubpixelLine {}
+
+impl PointsIter for SubpixelLine {
+    type Iter = SubpixelPoints;
+
+    fn points(&self) -> Self::Iter {
+        SubpixelPoints::new(self)
+    }
+}
+
+impl Dimensions for SubpixelLine {
+    fn bounding_box(&self) -> Rectangle {
+        let parameters = Parameters::new(self);
+
+        let (start, _) = parameters.point_at(parameters.first);
+        let (end, _) = parameters.point_at(parameters.last);
+
+        Rectangle::with_corners(start, end)
+    }
+}
+
+impl Transform for SubpixelLine {
+    /// Translate the line from its current position to a new position by (x, y) pixels, returning
+    /// a new `SubpixelLine`. For a mutating transform, see `translate_mut`.
+    fn translate(&self, by: Point) -> Self {
+        let by = SubpixelPoint::from(by);
+
+        Self::new(self.start + by, self.end + by)
+    }
+
+    /// Translate the line from its current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        let by = SubpixelPoint::from(by);
+
+        self.start += by;
+        self.end += by;
+
+        self
+    }
+}
+
+/// Rasterization parameters.
+///
+/// The parameters are normalized to a coordinate system in which the line goes in positive
+/// direction along both axes and the major axis is the axis with the larger delta.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+struct Parameters {
+    /// Position change for a step along the normalized major axis.
+    major_step: Point,
+
+    /// Position change for a step along the normalized minor axis.
+    minor_step: Point,
+
+    /// Normalized start point.
+    start: (i64, i64),
+
+    /// Normalized delta.
+    delta: (i64, i64),
+
+    /// First and last pixel on the major axis.
+    first: i32,
+    last: i32,
+}
+
+impl Parameters {
+    fn new(line: &SubpixelLine) -> Self {
+        let delta = line.end - line.start;
+
+        let direction = Point::new(
+            if delta.x >= 0 { 1 } else { -1 },
+            if delta.y >= 0 { 1 } else { -1 },
+        );
+
+        // Determine major and minor directions.
+        let (major_step, minor_step, start, end) = if delta.y.abs() >= delta.x.abs() {
+            (
+                direction.y_axis(),
+                direction.x_axis(),
+                (line.start.y * direction.y, line.start.x * direction.x),
+                (line.end.y * direction.y, line.end.x * direction.x),
+            )
+        } else {
+            (
+                direction.x_axis(),
+                direction.y_axis(),
+                (line.start.x * direction.x, line.start.y * direction.y),
+                (line.end.x * direction.x, line.end.y * direction.y),
+            )
+        };
+
+        let half = SubpixelPoint::ONE / 2;
+
+        Self {
+            major_step,
+            minor_step,
+            start: (i64::from(start.0), i64::from(start.1)),
+            delta: (
+                i64::from(end.0) - i64::from(start.0),
+                i64::from(end.1) - i64::from(start.1),
+            ),
+            first: (start.0 + half) >> SubpixelPoint::FRACTIONAL_BITS,
+            last: (end.0 + half) >> SubpixelPoint::FRACTIONAL_BITS,
+        }
+    }
+
+    /// Error denominator for the minor axis position.
+    fn error_denominator(&self) -> i64 {
+        2 * i64::from(SubpixelPoint::ONE) * self.delta.0
+    }
+
+    /// Error change for a major step.
+    fn error_step(&self) -> i64 {
+        2 * i64::from(SubpixelPoint::ONE) * self.delta.1
+    }
+
+    /// Returns the point at the given normalized major axis pixel and the remaining error.
+    fn point_at(&self, major: i32) -> (Point, i64) {
+        let one = i64::from(SubpixelPoint::ONE);
+
+        let (minor, error) = if self.delta.0 == 0 {
+            let minor = (self.start.1 + one / 2).div_euclid(one);
+
+            (minor, 0)
+        } else {
+            // Position of the line at the pixel center on the major axis, scaled by delta.0.
+            let position = self.start.1 * self.delta.0
+                + (i64::from(major) * one - self.start.0) * self.delta.1;
+
+            // Round to the nearest pixel, rounding ties towards the start of the line.
+            let numerator = 2 * position - one * self.delta.0;
+            let denominator = self.error_denominator();
+            let minor = -(-numerator).div_euclid(denominator);
+
+            (minor, numerator - minor * denominator)
+        };
+
+        (
+            self.major_step * major + self.minor_step * minor as i32,
+            error,
+        )
+    }
+}
+
+/// Iterator over all points on a subpixel line.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct SubpixelPoints {
+    parameters: Parameters,
+    point: Point,
+    error: i64,
+    points_remaining: u32,
+}
+
+impl SubpixelPoints {
+    pub(in crate::primitives) fn new(line: &SubpixelLine) -> Self {
+        let parameters = Parameters::new(line);
+        let (point, error) = parameters.point_at(parameters.first);
+
+        Self {
+            parameters,
+            point,
+            error,
+            points_remaining: (parameters.last - parameters.first) as u32 + 1,
+        }
+    }
+
+    pub(in crate::primitives) fn empty() -> Self {
+        let mut self_ = Self::new(&SubpixelLine::default());
+        self_.points_remaining = 0;
+
+        self_
+    }
+}
+
+impl Iterator for SubpixelPoints {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.points_remaining == 0 {
+            return None;
+        }
+
+        let ret = self.point;
+
+        self.points_remaining -= 1;
+        self.point += self.parameters.major_step;
+        self.error += self.parameters.error_step();
+        if self.error > 0 {
+            self.point += self.parameters.minor_step;
+            self.error -= self.parameters.error_denominator();
+        }
+
+        Some(ret)
+    }
+}
+
+/// Area covered by a thick subpixel line segment.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub(in crate::primitives) struct ThickSegment {
+    start: SubpixelPoint,
+    delta: (i64, i64),
+    length_squared: i64,
+    width: i64,
+}
+
+impl ThickSegment {
+    pub(in crate::primitives) fn new(line: &SubpixelLine, stroke_width: u32) -> Self {
+        let delta = line.end - line.start;
+        let delta = (i64::from(delta.x), i64::from(delta.y));
+
+        Self {
+            start: line.start,
+            delta,
+            length_squared: delta.0.pow(2) + delta.1.pow(2),
+            width: i64::from(stroke_width) * i64::from(SubpixelPoint::ONE),
+        }
+    }
+
+    /// Returns `true` if the center of the given pixel is inside the segment.
+    pub(in crate::primitives) fn contains(&self, point: Point) -> bool {
+        if self.length_squared == 0 {
+            return false;
+        }
+
+        let point = SubpixelPoint::from(point) - self.start;
+        let point = (i64::from(point.x), i64::from(point.y));
+
+        let projection = point.0 * self.delta.0 + point.1 * self.delta.1;
+        if projection < 0 || projection > self.length_squared {
+            return false;
+        }
+
+        // The signed distance from the line is `cross / length`, which must be in the half open
+        // range `[-width / 2, width / 2)` to draw exactly `width` pixels for axis aligned lines.
+        let cross = i128::from(point.0 * self.delta.1 - point.1 * self.delta.0);
+        let distance_squared = 4 * cross.pow(2);
+        let limit = i128::from(self.width).pow(2) * i128::from(self.length_squared);
+
+        if cross >= 0 {
+            distance_squared < limit
+        } else {
+            distance_squared <= limit
+        }
+    }
+
+    /// Returns the bounding box of all pixels which can be inside the segment.
+    pub(in crate::primitives) fn bounding_box(&self) -> Rectangle {
+        let end = (
+            i64::from(self.start.x) + self.delta.0,
+            i64::from(self.start.y) + self.delta.1,
+        );
+        let start = (i64::from(self.start.x), i64::from(self.start.y));
+        let half_width = self.width / 2;
+        let one = i64::from(SubpixelPoint::ONE);
+
+        let min = Point::new(
+            -(-(start.0.min(end.0) - half_width)).div_euclid(one) as i32,
+            -(-(start.1.min(end.1) - half_width)).div_euclid(one) as i32,
+        );
+        let max = Point::new(
+            (start.0.max(end.0) + half_width).div_euclid(one) as i32,
+            (start.1.max(end.1) + half_width).div_euclid(one) as i32,
+        );
+
+        Rectangle::with_corners(min, max)
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+enum StyledIter {
+    Thin(SubpixelPoints),
+    Thick {
+        points: rectangle::Points,
+        segment: ThickSegment,
+    },
+}
+
+/// Styled subpixel line iterator.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct SubpixelStyledPixels<C>
+where
+    C: PixelColor,
+{
+    stroke_color: Option<C>,
+    iter: StyledIter,
+}
+
+impl<C> SubpixelStyledPixels<C>
+where
+    C: PixelColor,
+{
+    fn new(styled: &Styled<SubpixelLine, PrimitiveStyle<C>>) -> Self {
+        let Styled { primitive, style } = styled;
+
+        let iter = if style.stroke_width <= 1 {
+            StyledIter::Thin(primitive.points())
+        } else {
+            let segment = ThickSegment::new(primitive, style.stroke_width);
+
+            StyledIter::Thick {
+                points: segment.bounding_box().points(),
+                segment,
+            }
+        };
+
+        Self {
+            // Note: stroke color will be None if stroke width is 0
+            stroke_color: style.effective_stroke_color(),
+            iter,
+        }
+    }
+}
+
+impl<C> Iterator for SubpixelStyledPixels<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let stroke_color = self.stroke_color?;
+
+        let point = match &mut self.iter {
+            StyledIter::Thin(points) => points.next(),
+            StyledIter::Thick { points, segment } => points.find(|p| segment.contains(*p)),
+        }?;
+
+        Some(Pixel(point, stroke_color))
+    }
+}
+
+impl<C> IntoPixels for &Styled<SubpixelLine, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    type Color = C;
+
+    type Iter = SubpixelStyledPixels<Self::Color>;
+
+    fn into_pixels(self) -> Self::Iter {
+        SubpixelStyledPixels::new(self)
+    }
+}
+
+impl<C> Drawable for Styled<SubpixelLine, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        display.draw_iter(self.into_pixels())
+    }
+}
+
+impl<C> Dimensions for Styled<SubpixelLine, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        if self.style.stroke_width <= 1 {
+            self.primitive.bounding_box()
+        } else {
+            ThickSegment::new(&self.primitive, self.style.stroke_width).bounding_box()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Size,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Line, Primitive},
+    };
+
+    fn subpixel(x: i32, y: i32) -> SubpixelPoint {
+        SubpixelPoint::from_bits(x, y)
+    }
+
+    #[test]
+    fn integer_points_match_line() {
+        let start = Point::new(10, 10);
+
+        for x in -12..=12 {
+            for y in -12..=12 {
+                let line = Line::new(start, start + Point::new(x, y));
+
+                assert!(
+                    SubpixelLine::from(line).points().eq(line.points()),
+                    "{:?}",
+                    line
+                );
+                assert_eq!(
+                    SubpixelLine::from(line).bounding_box(),
+                    line.bounding_box(),
+                    "{:?}",
+                    line
+                );
+
+                let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+                assert!(
+                    SubpixelLine::from(line)
+                        .into_styled(style)
+                        .into_pixels()
+                        .eq(line.into_styled(style).into_pixels()),
+                    "{:?}",
+                    line
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn subpixel_start() {
+        // Line from (0.0, 0.0) to (8.0, 2.0)
+        let line = SubpixelLine::new(subpixel(0, 0), subpixel(8 * 256, 2 * 256));
+        MockDisplay::from_points(line.points()).assert_pattern(&[
+            "###      ", //
+            "   ####  ", //
+            "       ##", //
+        ]);
+
+        // The same line moved down by 0.25 pixels
+        let line = SubpixelLine::new(subpixel(0, 64), subpixel(8 * 256, 2 * 256 + 64));
+        MockDisplay::from_points(line.points()).assert_pattern(&[
+            "##       ", //
+            "  ####   ", //
+            "      ###", //
+        ]);
+    }
+
+    #[test]
+    fn reversed() {
+        let line = SubpixelLine::new(subpixel(100, 30), subpixel(8 * 256 - 30, 3 * 256 + 10));
+        let reversed = SubpixelLine::new(line.end, line.start);
+
+        MockDisplay::from_points(reversed.points())
+            .assert_eq(&MockDisplay::from_points(line.points()));
+    }
+
+    #[test]
+    fn single_point() {
+        let line = SubpixelLine::new(subpixel(300, 400), subpixel(300, 400));
+
+        assert!(line.points().eq(core::iter::once(Point::new(1, 2))));
+        assert_eq!(
+            line.bounding_box(),
+            Rectangle::new(Point::new(1, 2), Size::new(1, 1))
+        );
+    }
+
+    #[test]
+    fn bounding_box_contains_points() {
+        let line = SubpixelLine::new(subpixel(-100, 0), subpixel(1000, 2200));
+        let bounding_box = line.bounding_box();
+
+        assert!(line.points().all(|p| bounding_box.contains(p)));
+    }
+
+    #[test]
+    fn thick() {
+        let line = SubpixelLine::new(subpixel(0, 2 * 256 + 128), subpixel(6 * 256, 2 * 256 + 128))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 2));
+
+        let mut display = MockDisplay::new();
+        line.draw(&mut display).unwrap();
+        display.assert_pattern(&[
+            "       ", //
+            "       ", //
+            "#######", //
+            "#######", //
+        ]);
+    }
+
+    #[test]
+    fn thick_bounding_box() {
+        let line = SubpixelLine::new(subpixel(270, 300), subpixel(2000, 1100))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 5));
+
+        let mut display = MockDisplay::new();
+        line.draw(&mut display).unwrap();
+
+        assert!(line
+            .bounding_box()
+            .contains(display.affected_area().top_left));
+        assert!(line
+            .bounding_box()
+            .contains(display.affected_area().bottom_right().unwrap()));
+    }
+}
@@ -16,10 +16,10 @@ pub mod triangle;
 pub use self::rectangle::Rectangle;
 pub use self::{
     arc::Arc,
-    circle::Circle,
+    circle::{Circle, SubpixelCircle},
     ellipse::Ellipse,
-    line::Line,
-    polyline::Polyline,
+    line::{Line, SubpixelLine},
+    polyline::{Polyline, SubpixelPolyline},
     primitive_style::{
         PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment, StyledPrimitiveAreas,
     },
@@ -52,6 +52,37 @@ pub trait ContainsPoint {
     fn contains(&self, point: Point) -> bool;
 }
 
+/// Trait to check if a point is covered by a styled primitive.
+///
+/// Unlike [`ContainsPoint`], which only checks the geometry of closed shapes, this trait takes the
+/// style into account. A point is covered if it is part of the drawn stroke or fill, which makes
+/// this trait usable for hit testing of open shapes like lines, polylines and arcs, too.
+///
+/// The result is consistent with the pixels that are drawn by the styled primitive. Points inside
+/// transparent areas, e.g. the fill area of a primitive without a fill color, are not covered.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::{Line, PrimitiveStyle, StyledContainsPoint},
+/// };
+///
+/// let line = Line::new(Point::new(10, 10), Point::new(30, 10))
+///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 5));
+///
+/// assert!(line.contains(Point::new(20, 12)));
+/// assert!(!line.contains(Point::new(20, 13)));
+/// ```
+///
+/// [`ContainsPoint`]: trait.ContainsPoint.html
+pub trait StyledContainsPoint {
+    /// Returns `true` if the given point is covered by the stroke or fill.
+    fn contains(&self, point: Point) -> bool;
+}
+
 /// Offset outline trait.
 pub trait OffsetOutline {
     /// Offsets the outline of the shape.
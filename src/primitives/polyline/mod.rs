@@ -4,14 +4,16 @@ mod points;
 pub(in crate::primitives) mod scanline_intersections;
 mod scanline_iterator;
 mod styled;
+mod subpixel;
 
 use crate::{
     geometry::{Dimensions, Point, Size},
-    primitives::{PointsIter, Primitive, Rectangle},
+    primitives::{Line, PointsIter, Primitive, Rectangle},
     transform::Transform,
 };
 pub use points::Points;
 pub use styled::StyledPixels;
+pub use subpixel::{SubpixelPoints, SubpixelPolyline, SubpixelStyledPixels};
 
 /// Polyline primitive
 ///
@@ -71,6 +73,118 @@ impl<'a> Polyline<'a> {
             translate: Point::zero(),
         }
     }
+
+    /// Returns `true` if the polyline is closed.
+    ///
+    /// A polyline is closed if it contains at least four vertices and the first and the last
+    /// vertex are equal.
+    pub fn is_closed(&self) -> bool {
+        self.vertices.len() >= 4 && self.vertices.first() == self.vertices.last()
+    }
+
+    /// Offsets the outline of a closed polyline.
+    ///
+    /// The offset is applied perpendicular to each segment, in the same way as
+    /// [`OffsetOutline::offset`] is applied to other closed shapes. Offset values greater than
+    /// zero will expand the shape and values less than zero will shrink the shape. Corners are
+    /// mitered, i.e. the new vertices are placed at the intersections of the moved segments.
+    ///
+    /// Because a polyline doesn't own its vertices, the offset vertices are written into `buffer`
+    /// and the returned polyline borrows its vertices from `buffer`. The returned polyline uses
+    /// the same `translate` value as `self`.
+    ///
+    /// Consecutive duplicate vertices don't form an edge and are moved to the same offset vertex.
+    ///
+    /// `None` is returned if the polyline isn't [closed], if all vertices are equal or if `buffer`
+    /// is shorter than `vertices`.
+    ///
+    /// Large negative offsets or large positive offsets for concave shapes can result in a self
+    /// intersecting outline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{prelude::*, primitives::Polyline};
+    ///
+    /// let vertices = [
+    ///     Point::new(10, 10),
+    ///     Point::new(20, 10),
+    ///     Point::new(20, 20),
+    ///     Point::new(10, 20),
+    ///     Point::new(10, 10),
+    /// ];
+    ///
+    /// let mut buffer = [Point::zero(); 5];
+    /// let outline = Polyline::new(&vertices).offset_closed(2, &mut buffer).unwrap();
+    ///
+    /// assert_eq!(
+    ///     outline.vertices,
+    ///     &[
+    ///         Point::new(8, 8),
+    ///         Point::new(22, 8),
+    ///         Point::new(22, 22),
+    ///         Point::new(8, 22),
+    ///         Point::new(8, 8),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// [`OffsetOutline::offset`]: ../trait.OffsetOutline.html#tymethod.offset
+    /// [closed]: #method.is_closed
+    pub fn offset_closed<'b>(&self, offset: i32, buffer: &'b mut [Point]) -> Option<Polyline<'b>> {
+        if !self.is_closed() || buffer.len() < self.vertices.len() {
+            return None;
+        }
+
+        let buffer = &mut buffer[0..self.vertices.len()];
+
+        // The last vertex is equal to the first vertex and isn't included in the corners.
+        let corners = &self.vertices[0..self.vertices.len() - 1];
+
+        // The outside of a clockwise polygon is on the left side of the segments.
+        let offset = if area_doubled(corners) >= 0 {
+            offset
+        } else {
+            offset.checked_neg().unwrap_or(core::i32::MAX)
+        };
+
+        for (i, vertex) in corners.iter().enumerate() {
+            // Duplicate vertices are skipped to prevent zero length edges.
+            let previous = (1..corners.len())
+                .map(|d| corners[(i + corners.len() - d) % corners.len()])
+                .find(|previous| previous != vertex)?;
+            let next = (1..corners.len())
+                .map(|d| corners[(i + d) % corners.len()])
+                .find(|next| next != vertex)?;
+
+            let first_edge = Line::new(previous, *vertex).parallel(offset);
+            let second_edge = Line::new(*vertex, next).parallel(offset);
+
+            // Colinear segments don't intersect, but the start point of the moved second segment
+            // is the correctly offset vertex in this case.
+            buffer[i] = first_edge
+                .line_intersection(&second_edge)
+                .unwrap_or(second_edge.start);
+        }
+
+        buffer[corners.len()] = buffer[0];
+
+        Some(Polyline {
+            translate: self.translate,
+            vertices: buffer,
+        })
+    }
+}
+
+/// Returns twice the signed area of a polygon.
+///
+/// The returned value is positive for polygons with clockwise vertices.
+fn area_doubled(vertices: &[Point]) -> i64 {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| i64::from(a.x) * i64::from(b.y) - i64::from(b.x) * i64::from(a.y))
+        .sum()
 }
 
 impl<'a> Primitive for Polyline<'a> {}
@@ -277,4 +391,151 @@ mod tests {
             polyline.bounding_box().translate(Point::new(20, 24))
         );
     }
+
+    const SQUARE: [Point; 5] = [
+        Point::new(10, 10),
+        Point::new(20, 10),
+        Point::new(20, 20),
+        Point::new(10, 20),
+        Point::new(10, 10),
+    ];
+
+    #[test]
+    fn is_closed() {
+        assert!(Polyline::new(&SQUARE).is_closed());
+        assert!(!Polyline::new(&SQUARE[0..4]).is_closed());
+        assert!(!Polyline::new(&[Point::zero(), Point::new(1, 1), Point::zero()]).is_closed());
+        assert!(!Polyline::new(&[]).is_closed());
+    }
+
+    #[test]
+    fn offset_closed() {
+        let mut buffer = [Point::zero(); 5];
+        let polyline = Polyline::new(&SQUARE).translate(Point::new(1, 2));
+
+        let outline = polyline.offset_closed(3, &mut buffer).unwrap();
+        assert_eq!(outline.translate, Point::new(1, 2));
+        assert_eq!(
+            outline.vertices,
+            &[
+                Point::new(7, 7),
+                Point::new(23, 7),
+                Point::new(23, 23),
+                Point::new(7, 23),
+                Point::new(7, 7),
+            ]
+        );
+
+        let outline = polyline.offset_closed(-3, &mut buffer).unwrap();
+        assert_eq!(
+            outline.vertices,
+            &[
+                Point::new(13, 13),
+                Point::new(17, 13),
+                Point::new(17, 17),
+                Point::new(13, 17),
+                Point::new(13, 13),
+            ]
+        );
+    }
+
+    #[test]
+    fn offset_closed_counterclockwise() {
+        let mut vertices = SQUARE;
+        vertices.reverse();
+
+        let mut buffer = [Point::zero(); 5];
+        let outline = Polyline::new(&vertices)
+            .offset_closed(1, &mut buffer)
+            .unwrap();
+
+        assert_eq!(
+            outline.vertices,
+            &[
+                Point::new(9, 9),
+                Point::new(9, 21),
+                Point::new(21, 21),
+                Point::new(21, 9),
+                Point::new(9, 9),
+            ]
+        );
+    }
+
+    #[test]
+    fn offset_closed_colinear_segments() {
+        let vertices = [
+            Point::new(10, 10),
+            Point::new(15, 10),
+            Point::new(20, 10),
+            Point::new(20, 20),
+            Point::new(10, 20),
+            Point::new(10, 10),
+        ];
+
+        let mut buffer = [Point::zero(); 6];
+        let outline = Polyline::new(&vertices)
+            .offset_closed(2, &mut buffer)
+            .unwrap();
+
+        assert_eq!(
+            outline.vertices,
+            &[
+                Point::new(8, 8),
+                Point::new(15, 8),
+                Point::new(22, 8),
+                Point::new(22, 22),
+                Point::new(8, 22),
+                Point::new(8, 8),
+            ]
+        );
+    }
+
+    #[test]
+    fn offset_closed_duplicate_vertices() {
+        let vertices = [
+            Point::new(10, 10),
+            Point::new(10, 10),
+            Point::new(20, 10),
+            Point::new(20, 20),
+            Point::new(20, 20),
+            Point::new(10, 20),
+            Point::new(10, 10),
+        ];
+
+        let mut buffer = [Point::zero(); 7];
+        let outline = Polyline::new(&vertices)
+            .offset_closed(2, &mut buffer)
+            .unwrap();
+
+        assert_eq!(
+            outline.vertices,
+            &[
+                Point::new(8, 8),
+                Point::new(8, 8),
+                Point::new(22, 8),
+                Point::new(22, 22),
+                Point::new(22, 22),
+                Point::new(8, 22),
+                Point::new(8, 8),
+            ]
+        );
+    }
+
+    #[test]
+    fn offset_closed_invalid() {
+        let mut buffer = [Point::zero(); 5];
+
+        assert_eq!(
+            Polyline::new(&SQUARE[0..4]).offset_closed(1, &mut buffer),
+            None
+        );
+        assert_eq!(
+            Polyline::new(&SQUARE).offset_closed(1, &mut buffer[0..4]),
+            None
+        );
+        assert_eq!(
+            Polyline::new(&[Point::new(1, 2); 4]).offset_closed(1, &mut buffer),
+            None
+        );
+    }
 }
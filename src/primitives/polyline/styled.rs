@@ -5,8 +5,11 @@ use crate::{
     pixelcolor::PixelColor,
     primitives::{
         common::{Scanline, StrokeOffset, ThickSegmentIter},
-        polyline::{self, scanline_iterator::ScanlineIterator, Polyline},
-        PointsIter, PrimitiveStyle, Rectangle,
+        polyline::{
+            self, scanline_intersections::ScanlineIntersections,
+            scanline_iterator::ScanlineIterator, Polyline,
+        },
+        PointsIter, PrimitiveStyle, Rectangle, StyledContainsPoint,
     },
     transform::Transform,
     Drawable, Pixel, Styled,
@@ -189,6 +192,32 @@ where
     }
 }
 
+impl<C> StyledContainsPoint for Styled<Polyline<'_>, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn contains(&self, point: Point) -> bool {
+        match self
+            .style
+            .effective_stroke_color()
+            .map(|_| self.style.stroke_width)
+        {
+            None => false,
+            Some(1) => self.primitive.points().any(|p| p == point),
+            Some(stroke_width) => {
+                if !self.bounding_box().contains(point) {
+                    return false;
+                }
+
+                let point = point - self.primitive.translate;
+
+                ScanlineIntersections::new(self.primitive.vertices, stroke_width, point.y)
+                    .any(|line| line.x.contains(&point.x))
+            }
+        }
+    }
+}
+
 impl<C> Dimensions for Styled<Polyline<'_>, PrimitiveStyle<C>>
 where
     C: PixelColor,
@@ -649,4 +678,29 @@ mod tests {
         // Check no pixels are drawn outside bounding box
         assert_eq!(display.affected_area(), bb);
     }
+
+    #[test]
+    fn contains() {
+        let shape = Polyline::new(&PATTERN);
+
+        for &stroke_width in &[1, 2, 5] {
+            let style = PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width);
+            let styled = shape.into_styled(style);
+
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+
+            let contained = MockDisplay::from_points(
+                display
+                    .bounding_box()
+                    .points()
+                    .filter(|p| styled.contains(*p)),
+            );
+
+            contained.assert_eq_with_message(&display, |f| write!(f, "{:?}", style));
+        }
+
+        let transparent = shape.into_styled(PrimitiveStyle::<BinaryColor>::new());
+        assert!(!transparent.contains(Point::new(13, 5)));
+    }
 }
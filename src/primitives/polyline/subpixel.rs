@@ -0,0 +1,424 @@
+//! Polylines with sub-pixel precision.
+
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size, SubpixelPoint},
+    iterator::IntoPixels,
+    pixelcolor::PixelColor,
+    primitives::{
+        line::{self, SubpixelLine, ThickSegment},
+        rectangle::{self, Rectangle},
+        PointsIter, Primitive, PrimitiveStyle,
+    },
+    transform::Transform,
+    Drawable, Pixel, Styled,
+};
+
+/// Polyline with sub-pixel precise vertices.
+///
+/// This is the sub-pixel precise equivalent of [`Polyline`]. The segments are rasterized like
+/// [`SubpixelLine`]s and a `SubpixelPolyline` with integer vertices results in the same pixels as
+/// the equivalent `Polyline` if it is drawn with a stroke width of 1. Wider strokes are drawn
+/// with round joins between the segments.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::{
+///     geometry::SubpixelPoint,
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PrimitiveStyle, SubpixelPolyline},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let points = [
+///     SubpixelPoint::from_bits(10 * 256, 20 * 256 + 128),
+///     SubpixelPoint::from_bits(16 * 256 + 64, 10 * 256),
+///     SubpixelPoint::from_bits(22 * 256, 20 * 256 + 192),
+/// ];
+///
+/// let mut polyline = SubpixelPolyline::new(&points);
+///
+/// // Move the polyline by (0.5, 0.25) pixels.
+/// polyline.translate = SubpixelPoint::from_bits(128, 64);
+///
+/// polyline
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::GREEN, 3))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Polyline`]: struct.Polyline.html
+/// [`SubpixelLine`]: ../line/struct.SubpixelLine.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct SubpixelPolyline<'a> {
+    /// An offset to apply to the polyline as a whole
+    pub translate: SubpixelPoint,
+
+    /// All vertices in the line
+    pub vertices: &'a [SubpixelPoint],
+}
+
+impl<'a> SubpixelPolyline<'a> {
+    /// Create a new subpixel polyline from a list of vertices
+    ///
+    /// If fewer than two vertices are provided, the line will not render anything when drawn.
+    pub const fn new(vertices: &'a [SubpixelPoint]) -> Self {
+        Self {
+            vertices,
+            translate: SubpixelPoint::zero(),
+        }
+    }
+
+    /// Returns an iterator over all translated segments.
+    fn segments(&self) -> impl Iterator<Item = SubpixelLine> + 'a {
+        let translate = self.translate;
+
+        self.vertices
+            .windows(2)
+            .map(move |v| SubpixelLine::new(v[0] + translate, v[1] + translate))
+    }
+}
+
+impl Primitive for SubpixelPolyline<'_> {}
+
+impl<'a> PointsIter for SubpixelPolyline<'a> {
+    type Iter = SubpixelPoints<'a>;
+
+    fn points(&self) -> Self::Iter {
+        SubpixelPoints::new(self)
+    }
+}
+
+impl Dimensions for SubpixelPolyline<'_> {
+    fn bounding_box(&self) -> Rectangle {
+        match self.vertices {
+            [] => Rectangle::zero(),
+            [v] => Rectangle::new((*v + self.translate).round(), Size::zero()),
+            _ => self
+                .segments()
+                .map(|segment| segment.bounding_box())
+                .fold(None, |bounding_box, segment| {
+                    Some(envelope(bounding_box, &segment))
+                })
+                .unwrap_or_else(Rectangle::zero),
+        }
+    }
+}
+
+impl Transform for SubpixelPolyline<'_> {
+    /// Translate the polyline from its current position to a new position by (x, y) pixels,
+    /// returning a new `SubpixelPolyline`. For a mutating transform, see `translate_mut`.
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            translate: self.translate + by.into(),
+            vertices: self.vertices,
+        }
+    }
+
+    /// Translate the polyline from its current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.translate += by.into();
+
+        self
+    }
+}
+
+/// Returns the smallest rectangle which contains both rectangles.
+fn envelope(a: Option<Rectangle>, b: &Rectangle) -> Rectangle {
+    match a {
+        Some(a) => Rectangle::with_corners(
+            a.top_left.component_min(b.top_left),
+            a.bottom_right()
+                .unwrap_or(a.top_left)
+                .component_max(b.bottom_right().unwrap_or(b.top_left)),
+        ),
+        None => *b,
+    }
+}
+
+/// An iterator over all pixel positions on a subpixel polyline.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct SubpixelPoints<'a> {
+    vertices: &'a [SubpixelPoint],
+    translate: SubpixelPoint,
+    segment_iter: line::SubpixelPoints,
+}
+
+impl<'a> SubpixelPoints<'a> {
+    fn new(polyline: &SubpixelPolyline<'a>) -> Self {
+        let translate = polyline.translate;
+
+        match polyline.vertices {
+            [start, end, ..] => Self {
+                vertices: &polyline.vertices[1..],
+                translate,
+                segment_iter: SubpixelLine::new(*start + translate, *end + translate).points(),
+            },
+            _ => Self {
+                vertices: &[],
+                translate,
+                segment_iter: line::SubpixelPoints::empty(),
+            },
+        }
+    }
+}
+
+impl<'a> Iterator for SubpixelPoints<'a> {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(p) = self.segment_iter.next() {
+            Some(p)
+        } else {
+            let (start, rest) = self.vertices.split_first()?;
+            let end = rest.first()?;
+
+            self.vertices = rest;
+
+            self.segment_iter =
+                SubpixelLine::new(*start + self.translate, *end + self.translate).points();
+
+            // Skip first point of next line, otherwise we overlap with the previous line
+            self.nth(1)
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+enum StyledIter<'a> {
+    Thin(SubpixelPoints<'a>),
+    Thick {
+        points: rectangle::Points,
+        polyline: SubpixelPolyline<'a>,
+        stroke_width: u32,
+    },
+}
+
+/// Pixel iterator for a styled subpixel polyline.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct SubpixelStyledPixels<'a, C>
+where
+    C: PixelColor,
+{
+    stroke_color: Option<C>,
+    iter: StyledIter<'a>,
+}
+
+impl<'a, C> SubpixelStyledPixels<'a, C>
+where
+    C: PixelColor,
+{
+    fn new(styled: &Styled<SubpixelPolyline<'a>, PrimitiveStyle<C>>) -> Self {
+        let Styled { primitive, style } = styled;
+
+        let iter = if style.stroke_width <= 1 {
+            StyledIter::Thin(primitive.points())
+        } else {
+            StyledIter::Thick {
+                points: styled.bounding_box().points(),
+                polyline: *primitive,
+                stroke_width: style.stroke_width,
+            }
+        };
+
+        Self {
+            // Note: stroke color will be None if stroke width is 0
+            stroke_color: style.effective_stroke_color(),
+            iter,
+        }
+    }
+}
+
+/// Returns `true` if the center of the given pixel is inside the thick polyline.
+fn thick_contains(polyline: &SubpixelPolyline<'_>, stroke_width: u32, point: Point) -> bool {
+    if polyline
+        .segments()
+        .any(|segment| ThickSegment::new(&segment, stroke_width).contains(point))
+    {
+        return true;
+    }
+
+    // Round joins between the segments.
+    let inner_vertices = polyline
+        .vertices
+        .get(1..polyline.vertices.len().saturating_sub(1))
+        .unwrap_or(&[]);
+
+    let width = i64::from(stroke_width) * i64::from(SubpixelPoint::ONE);
+
+    inner_vertices.iter().any(|vertex| {
+        let delta = SubpixelPoint::from(point) - (*vertex + polyline.translate);
+        let distance = i64::from(delta.x).pow(2) + i64::from(delta.y).pow(2);
+
+        4 * distance < width.pow(2)
+    })
+}
+
+impl<'a, C> Iterator for SubpixelStyledPixels<'a, C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let stroke_color = self.stroke_color?;
+
+        let point = match &mut self.iter {
+            StyledIter::Thin(points) => points.next(),
+            StyledIter::Thick {
+                points,
+                polyline,
+                stroke_width,
+            } => {
+                let (polyline, stroke_width) = (*polyline, *stroke_width);
+
+                points.find(|p| thick_contains(&polyline, stroke_width, *p))
+            }
+        }?;
+
+        Some(Pixel(point, stroke_color))
+    }
+}
+
+impl<'a, C> IntoPixels for &Styled<SubpixelPolyline<'a>, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    type Color = C;
+
+    type Iter = SubpixelStyledPixels<'a, Self::Color>;
+
+    fn into_pixels(self) -> Self::Iter {
+        SubpixelStyledPixels::new(self)
+    }
+}
+
+impl<C> Drawable for Styled<SubpixelPolyline<'_>, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        display.draw_iter(self.into_pixels())
+    }
+}
+
+impl<C> Dimensions for Styled<SubpixelPolyline<'_>, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        if self.style.stroke_width <= 1 {
+            return self.primitive.bounding_box();
+        }
+
+        // The round joins are contained in the bounding boxes of the adjacent segments.
+        self.primitive
+            .segments()
+            .map(|segment| ThickSegment::new(&segment, self.style.stroke_width).bounding_box())
+            .fold(None, |bounding_box, segment| {
+                Some(envelope(bounding_box, &segment))
+            })
+            .unwrap_or_else(|| self.primitive.bounding_box())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock_display::MockDisplay, pixelcolor::BinaryColor, primitives::Polyline};
+
+    const VERTICES: [Point; 4] = [
+        Point::new(1, 1),
+        Point::new(8, 4),
+        Point::new(3, 9),
+        Point::new(3, 5),
+    ];
+
+    fn subpixel_vertices() -> [SubpixelPoint; 4] {
+        [
+            VERTICES[0].into(),
+            VERTICES[1].into(),
+            VERTICES[2].into(),
+            VERTICES[3].into(),
+        ]
+    }
+
+    #[test]
+    fn integer_points_match_polyline() {
+        let vertices = subpixel_vertices();
+        let polyline = SubpixelPolyline::new(&vertices);
+
+        assert!(polyline.points().eq(Polyline::new(&VERTICES).points()));
+        assert_eq!(
+            polyline.bounding_box(),
+            Polyline::new(&VERTICES).bounding_box()
+        );
+    }
+
+    #[test]
+    fn translate() {
+        let vertices = subpixel_vertices();
+        let mut polyline = SubpixelPolyline::new(&vertices);
+        polyline.translate = SubpixelPoint::from_bits(128, 0);
+
+        let moved = polyline.translate(Point::new(1, 2));
+        assert_eq!(moved.translate, SubpixelPoint::from_bits(384, 512));
+
+        assert!(polyline
+            .translate(Point::new(-1, 0))
+            .points()
+            .map(|p| p + Point::new(1, 0))
+            .eq(polyline.points()));
+    }
+
+    #[test]
+    fn too_few_vertices() {
+        let vertices = [SubpixelPoint::from_bits(300, 300)];
+
+        assert_eq!(SubpixelPolyline::new(&[]).points().next(), None);
+        assert_eq!(SubpixelPolyline::new(&vertices).points().next(), None);
+        assert_eq!(
+            SubpixelPolyline::new(&vertices).bounding_box(),
+            Rectangle::new(Point::new(1, 1), Size::zero())
+        );
+    }
+
+    #[test]
+    fn thick() {
+        let vertices = [
+            SubpixelPoint::from_bits(0, 256 + 128),
+            SubpixelPoint::from_bits(5 * 256, 256 + 128),
+            SubpixelPoint::from_bits(5 * 256, 6 * 256),
+        ];
+
+        let styled = SubpixelPolyline::new(&vertices)
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 2));
+
+        let mut display = MockDisplay::new();
+        styled.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "      ", //
+            "######", //
+            "######", //
+            "    ##", //
+            "    ##", //
+            "    ##", //
+            "    ##", //
+        ]);
+
+        let bounding_box = styled.bounding_box();
+        let affected_area = display.affected_area();
+        assert!(bounding_box.contains(affected_area.top_left));
+        assert!(bounding_box.contains(affected_area.bottom_right().unwrap()));
+    }
+}
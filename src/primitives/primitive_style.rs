@@ -1,6 +1,7 @@
 use crate::{
+    geometry::Point,
     pixelcolor::PixelColor,
-    primitives::{OffsetOutline, Primitive},
+    primitives::{ContainsPoint, OffsetOutline, Primitive},
     SaturatingCast, Styled,
 };
 
@@ -286,6 +287,28 @@ where
     }
 }
 
+impl<T, C> Styled<T, PrimitiveStyle<C>>
+where
+    T: Primitive + OffsetOutline + ContainsPoint,
+    C: PixelColor,
+{
+    /// Returns `true` if the point is covered by the stroke or fill area.
+    ///
+    /// Points inside the fill area are only covered if a fill color is set and points between the
+    /// fill and stroke area are only covered if a stroke color is set.
+    pub(in crate::primitives) fn areas_contain(&self, point: Point) -> bool {
+        if !self.stroke_area().contains(point) {
+            return false;
+        }
+
+        if self.fill_area().contains(point) {
+            self.style.fill_color.is_some()
+        } else {
+            self.style.stroke_color.is_some()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5,7 +5,7 @@ use crate::{
     pixelcolor::PixelColor,
     primitives::{
         rectangle::{Points, Rectangle},
-        PointsIter, PrimitiveStyle, StyledPrimitiveAreas,
+        PointsIter, PrimitiveStyle, StyledContainsPoint, StyledPrimitiveAreas,
     },
     transform::Transform,
     Drawable, Pixel, SaturatingCast, Styled,
@@ -154,6 +154,15 @@ where
     }
 }
 
+impl<C> StyledContainsPoint for Styled<Rectangle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn contains(&self, point: Point) -> bool {
+        self.areas_contain(point)
+    }
+}
+
 impl<C> Dimensions for Styled<Rectangle, PrimitiveStyle<C>>
 where
     C: PixelColor,
@@ -424,4 +433,45 @@ mod tests {
 
         assert_eq!(transparent_rect.bounding_box(), filled_rect.bounding_box(),);
     }
+
+    #[test]
+    fn contains() {
+        let shape = Rectangle::new(Point::new(5, 8), Size::new(20, 15));
+
+        for &stroke_alignment in &[
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            let stroke = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(3)
+                .stroke_alignment(stroke_alignment)
+                .build();
+            let stroke_and_fill = PrimitiveStyleBuilder::from(&stroke)
+                .fill_color(BinaryColor::Off)
+                .build();
+
+            for &style in &[
+                stroke,
+                stroke_and_fill,
+                PrimitiveStyle::with_fill(BinaryColor::On),
+            ] {
+                let styled = shape.into_styled(style);
+
+                let mut display = MockDisplay::new();
+                styled.draw(&mut display).unwrap();
+
+                let expected = display.map(|_| BinaryColor::On);
+                let contained = MockDisplay::from_points(
+                    expected
+                        .bounding_box()
+                        .points()
+                        .filter(|p| styled.contains(*p)),
+                );
+
+                contained.assert_eq_with_message(&expected, |f| write!(f, "{:?}", style));
+            }
+        }
+    }
 }
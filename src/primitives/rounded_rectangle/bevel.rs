@@ -0,0 +1,772 @@
+//! Bevel style for rounded rectangles.
+
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    iterator::IntoPixels,
+    pixelcolor::PixelColor,
+    primitives::{
+        rectangle::Rectangle,
+        rounded_rectangle::{CornerRadii, Points, RoundedRectangle},
+        ContainsPoint, StyledContainsPoint,
+    },
+    transform::Transform,
+    Drawable, Pixel, Styled,
+};
+
+/// Stroke of a single side of a bevel styled rounded rectangle.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct SideStroke<C>
+where
+    C: PixelColor,
+{
+    /// Stroke color.
+    ///
+    /// If `color` is set to `None` the side will be transparent, but still occupy `width` pixels.
+    pub color: Option<C>,
+
+    /// Stroke width in pixels.
+    pub width: u32,
+}
+
+impl<C> SideStroke<C>
+where
+    C: PixelColor,
+{
+    /// Creates a new side stroke.
+    pub const fn new(color: C, width: u32) -> Self {
+        Self {
+            color: Some(color),
+            width,
+        }
+    }
+}
+
+impl<C> Default for SideStroke<C>
+where
+    C: PixelColor,
+{
+    fn default() -> Self {
+        Self {
+            color: None,
+            width: 0,
+        }
+    }
+}
+
+/// Shadow kind.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum ShadowKind {
+    /// Inner shadow.
+    ///
+    /// The shadow is drawn inside the fill area, along the edges which face away from the shadow
+    /// offset.
+    Inner,
+
+    /// Drop shadow.
+    ///
+    /// The shadow is drawn outside the rounded rectangle, along the edges which face towards the
+    /// shadow offset.
+    Drop,
+}
+
+/// Shadow of a bevel styled rounded rectangle.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Shadow<C>
+where
+    C: PixelColor,
+{
+    /// Shadow kind.
+    pub kind: ShadowKind,
+
+    /// Offset of the shadow.
+    pub offset: Point,
+
+    /// Shadow color.
+    pub color: C,
+}
+
+/// Bevel style for rounded rectangles.
+///
+/// `BevelStyle` draws a rounded rectangle with a separate stroke for each side and an optional
+/// shadow, which can be used to give widgets like buttons a raised or sunken look. Unlike
+/// [`PrimitiveStyle`] the strokes are always drawn on the inside of the rounded rectangle and the
+/// corners between two sides are split diagonally, like CSS borders.
+///
+/// To create a `BevelStyle`, use the [`BevelStyleBuilder`].
+///
+/// # Examples
+///
+/// ## Draw a raised button
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{rounded_rectangle::BevelStyleBuilder, Rectangle, RoundedRectangle},
+///     Styled,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let style = BevelStyleBuilder::new()
+///     .fill_color(Rgb565::CSS_LIGHT_GRAY)
+///     .stroke_width(2)
+///     .light_color(Rgb565::WHITE)
+///     .dark_color(Rgb565::CSS_DIM_GRAY)
+///     .drop_shadow(Point::new(2, 2), Rgb565::BLACK)
+///     .build();
+///
+/// let button = RoundedRectangle::with_equal_corners(
+///     Rectangle::new(Point::new(5, 5), Size::new(40, 16)),
+///     Size::new(4, 4),
+/// );
+///
+/// Styled::new(button, style).draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`PrimitiveStyle`]: ../struct.PrimitiveStyle.html
+/// [`BevelStyleBuilder`]: struct.BevelStyleBuilder.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub struct BevelStyle<C>
+where
+    C: PixelColor,
+{
+    /// Fill color.
+    ///
+    /// If `fill_color` is set to `None` no fill will be drawn.
+    pub fill_color: Option<C>,
+
+    /// Top side stroke.
+    pub top: SideStroke<C>,
+
+    /// Right side stroke.
+    pub right: SideStroke<C>,
+
+    /// Bottom side stroke.
+    pub bottom: SideStroke<C>,
+
+    /// Left side stroke.
+    pub left: SideStroke<C>,
+
+    /// Optional shadow.
+    pub shadow: Option<Shadow<C>>,
+}
+
+impl<C> Default for BevelStyle<C>
+where
+    C: PixelColor,
+{
+    fn default() -> Self {
+        Self {
+            fill_color: None,
+            top: SideStroke::default(),
+            right: SideStroke::default(),
+            bottom: SideStroke::default(),
+            left: SideStroke::default(),
+            shadow: None,
+        }
+    }
+}
+
+/// Bevel style builder.
+///
+/// See [`BevelStyle`] for an example.
+///
+/// [`BevelStyle`]: struct.BevelStyle.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct BevelStyleBuilder<C>
+where
+    C: PixelColor,
+{
+    style: BevelStyle<C>,
+}
+
+impl<C> BevelStyleBuilder<C>
+where
+    C: PixelColor,
+{
+    /// Creates a new bevel style builder.
+    pub fn new() -> Self {
+        Self {
+            style: BevelStyle::default(),
+        }
+    }
+
+    /// Sets the fill color.
+    pub fn fill_color(mut self, fill_color: C) -> Self {
+        self.style.fill_color = Some(fill_color);
+
+        self
+    }
+
+    /// Sets the stroke width of all sides.
+    pub fn stroke_width(mut self, stroke_width: u32) -> Self {
+        self.style.top.width = stroke_width;
+        self.style.right.width = stroke_width;
+        self.style.bottom.width = stroke_width;
+        self.style.left.width = stroke_width;
+
+        self
+    }
+
+    /// Sets the stroke color of the top and left sides.
+    pub fn light_color(mut self, light_color: C) -> Self {
+        self.style.top.color = Some(light_color);
+        self.style.left.color = Some(light_color);
+
+        self
+    }
+
+    /// Sets the stroke color of the bottom and right sides.
+    pub fn dark_color(mut self, dark_color: C) -> Self {
+        self.style.bottom.color = Some(dark_color);
+        self.style.right.color = Some(dark_color);
+
+        self
+    }
+
+    /// Sets the top side stroke.
+    pub fn top(mut self, top: SideStroke<C>) -> Self {
+        self.style.top = top;
+
+        self
+    }
+
+    /// Sets the right side stroke.
+    pub fn right(mut self, right: SideStroke<C>) -> Self {
+        self.style.right = right;
+
+        self
+    }
+
+    /// Sets the bottom side stroke.
+    pub fn bottom(mut self, bottom: SideStroke<C>) -> Self {
+        self.style.bottom = bottom;
+
+        self
+    }
+
+    /// Sets the left side stroke.
+    pub fn left(mut self, left: SideStroke<C>) -> Self {
+        self.style.left = left;
+
+        self
+    }
+
+    /// Adds an inner shadow.
+    pub fn inner_shadow(mut self, offset: Point, color: C) -> Self {
+        self.style.shadow = Some(Shadow {
+            kind: ShadowKind::Inner,
+            offset,
+            color,
+        });
+
+        self
+    }
+
+    /// Adds a drop shadow.
+    pub fn drop_shadow(mut self, offset: Point, color: C) -> Self {
+        self.style.shadow = Some(Shadow {
+            kind: ShadowKind::Drop,
+            offset,
+            color,
+        });
+
+        self
+    }
+
+    /// Builds the bevel style.
+    pub fn build(self) -> BevelStyle<C> {
+        self.style
+    }
+}
+
+impl<C> From<&BevelStyle<C>> for BevelStyleBuilder<C>
+where
+    C: PixelColor,
+{
+    fn from(style: &BevelStyle<C>) -> Self {
+        Self { style: *style }
+    }
+}
+
+/// Areas of a bevel styled rounded rectangle.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+struct Areas<C>
+where
+    C: PixelColor,
+{
+    outer: RoundedRectangle,
+    inner: RoundedRectangle,
+    shadow: Option<(ShadowKind, RoundedRectangle, C)>,
+    style: BevelStyle<C>,
+}
+
+impl<C> Areas<C>
+where
+    C: PixelColor,
+{
+    fn new(styled: &Styled<RoundedRectangle, BevelStyle<C>>) -> Self {
+        let style = styled.style;
+        let outer = styled.primitive.confine_radii();
+        let inner = inner_area(&outer, &style);
+
+        let shadow = style.shadow.map(|shadow| {
+            let area = match shadow.kind {
+                ShadowKind::Inner => inner.translate(shadow.offset),
+                ShadowKind::Drop => outer.translate(shadow.offset),
+            };
+
+            (shadow.kind, area, shadow.color)
+        });
+
+        Self {
+            outer,
+            inner,
+            shadow,
+            style,
+        }
+    }
+
+    /// Returns the color of the given point or `None` if the point isn't drawn.
+    fn color(&self, point: Point) -> Option<C> {
+        if self.outer.contains(point) {
+            self.inner_color(point)
+        } else {
+            self.drop_shadow_color(point)
+        }
+    }
+
+    /// Returns the drop shadow color of a point outside the rounded rectangle.
+    fn drop_shadow_color(&self, point: Point) -> Option<C> {
+        match self.shadow {
+            Some((ShadowKind::Drop, area, color)) if area.contains(point) => Some(color),
+            _ => None,
+        }
+    }
+
+    /// Returns the color of a point inside the rounded rectangle.
+    fn inner_color(&self, point: Point) -> Option<C> {
+        if self.inner.contains(point) {
+            return match self.shadow {
+                Some((ShadowKind::Inner, area, color)) if !area.contains(point) => Some(color),
+                _ => self.style.fill_color,
+            };
+        }
+
+        self.side(point).color
+    }
+
+    /// Returns the side the given stroke point belongs to.
+    ///
+    /// The side is determined by the distance of the pixel center to each edge, relative to the
+    /// stroke width of that edge. This splits the corners between two sides along the diagonal
+    /// from the outer to the inner corner. Pixels on the diagonal belong to the top or bottom
+    /// side.
+    fn side(&self, point: Point) -> SideStroke<C> {
+        let rectangle = self.outer.rectangle;
+        let top_left = rectangle.top_left;
+        let bottom_right = rectangle.bottom_right().unwrap_or(top_left);
+
+        let sides = [
+            (point.y - top_left.y, self.style.top),
+            (bottom_right.x - point.x, self.style.right),
+            (bottom_right.y - point.y, self.style.bottom),
+            (point.x - top_left.x, self.style.left),
+        ];
+
+        let mut closest: Option<(i64, SideStroke<C>)> = None;
+        for &(distance, side) in sides.iter() {
+            if side.width == 0 {
+                continue;
+            }
+
+            // Doubled distance to the pixel center.
+            let distance = i64::from(distance) * 2 + 1;
+
+            let is_closer = match closest {
+                Some((closest_distance, closest_side)) => {
+                    distance * i64::from(closest_side.width)
+                        < closest_distance * i64::from(side.width)
+                }
+                None => true,
+            };
+
+            if is_closer {
+                closest = Some((distance, side));
+            }
+        }
+
+        closest.map(|(_, side)| side).unwrap_or_default()
+    }
+
+    fn bounding_box(&self) -> Rectangle {
+        let rectangle = self.outer.rectangle;
+
+        match self.shadow {
+            Some((ShadowKind::Drop, area, _)) => envelope(&rectangle, &area.rectangle),
+            _ => rectangle,
+        }
+    }
+}
+
+/// Returns the fill area inside the side strokes.
+fn inner_area<C>(outer: &RoundedRectangle, style: &BevelStyle<C>) -> RoundedRectangle
+where
+    C: PixelColor,
+{
+    let Rectangle { top_left, size } = outer.rectangle;
+    let CornerRadii {
+        top_left: top_left_radius,
+        top_right,
+        bottom_right,
+        bottom_left,
+    } = outer.corners;
+
+    let top = style.top.width;
+    let right = style.right.width;
+    let bottom = style.bottom.width;
+    let left = style.left.width;
+
+    let rectangle = Rectangle::new(
+        top_left + Size::new(left, top),
+        size.saturating_sub(Size::new(
+            left.saturating_add(right),
+            top.saturating_add(bottom),
+        )),
+    );
+
+    let corners = CornerRadii {
+        top_left: top_left_radius.saturating_sub(Size::new(left, top)),
+        top_right: top_right.saturating_sub(Size::new(right, top)),
+        bottom_right: bottom_right.saturating_sub(Size::new(right, bottom)),
+        bottom_left: bottom_left.saturating_sub(Size::new(left, bottom)),
+    };
+
+    RoundedRectangle::new(rectangle, corners)
+}
+
+/// Returns the smallest rectangle which contains both rectangles.
+fn envelope(a: &Rectangle, b: &Rectangle) -> Rectangle {
+    match (a.bottom_right(), b.bottom_right()) {
+        (Some(a_bottom_right), Some(b_bottom_right)) => Rectangle::with_corners(
+            a.top_left.component_min(b.top_left),
+            a_bottom_right.component_max(b_bottom_right),
+        ),
+        (Some(_), None) => *a,
+        _ => *b,
+    }
+}
+
+/// Pixel iterator for a bevel styled rounded rectangle.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BevelPixels<C>
+where
+    C: PixelColor,
+{
+    iter: Points,
+    shadow_iter: Points,
+    areas: Areas<C>,
+}
+
+impl<C> BevelPixels<C>
+where
+    C: PixelColor,
+{
+    fn new(styled: &Styled<RoundedRectangle, BevelStyle<C>>) -> Self {
+        let areas = Areas::new(styled);
+
+        let shadow_iter = match areas.shadow {
+            Some((ShadowKind::Drop, area, _)) => Points::new(&area),
+            _ => Points::empty(),
+        };
+
+        Self {
+            iter: Points::new(&areas.outer),
+            shadow_iter,
+            areas,
+        }
+    }
+}
+
+impl<C> Iterator for BevelPixels<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for point in &mut self.iter {
+            if let Some(color) = self.areas.inner_color(point) {
+                return Some(Pixel(point, color));
+            }
+        }
+
+        for point in &mut self.shadow_iter {
+            if !self.areas.outer.contains(point) {
+                if let Some(color) = self.areas.drop_shadow_color(point) {
+                    return Some(Pixel(point, color));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<C> IntoPixels for &Styled<RoundedRectangle, BevelStyle<C>>
+where
+    C: PixelColor,
+{
+    type Color = C;
+
+    type Iter = BevelPixels<Self::Color>;
+
+    fn into_pixels(self) -> Self::Iter {
+        BevelPixels::new(self)
+    }
+}
+
+impl<C> Drawable for Styled<RoundedRectangle, BevelStyle<C>>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        display.draw_iter(self.into_pixels())
+    }
+}
+
+impl<C> StyledContainsPoint for Styled<RoundedRectangle, BevelStyle<C>>
+where
+    C: PixelColor,
+{
+    fn contains(&self, point: Point) -> bool {
+        Areas::new(self).color(point).is_some()
+    }
+}
+
+impl<C> Dimensions for Styled<RoundedRectangle, BevelStyle<C>>
+where
+    C: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        Areas::new(self).bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock_display::MockDisplay,
+        pixelcolor::{BinaryColor, Rgb888},
+        prelude::*,
+        primitives::{PrimitiveStyleBuilder, StrokeAlignment},
+    };
+
+    fn button() -> RoundedRectangle {
+        RoundedRectangle::with_equal_corners(
+            Rectangle::new(Point::new(1, 1), Size::new(8, 6)),
+            Size::new(3, 3),
+        )
+    }
+
+    fn bevel_builder() -> BevelStyleBuilder<Rgb888> {
+        BevelStyleBuilder::new()
+            .fill_color(Rgb888::BLUE)
+            .light_color(Rgb888::WHITE)
+            .dark_color(Rgb888::RED)
+    }
+
+    #[test]
+    fn bevel() {
+        let mut display = MockDisplay::new();
+        Styled::new(button(), bevel_builder().stroke_width(1).build())
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "          ",
+            "  WWWWWW  ",
+            " WWBBBBWR ",
+            " WBBBBBBR ",
+            " WBBBBBBR ",
+            " WRBBBBRR ",
+            "  RRRRRR  ",
+        ]);
+    }
+
+    #[test]
+    fn bevel_without_fill() {
+        let style = BevelStyleBuilder::new()
+            .light_color(Rgb888::WHITE)
+            .dark_color(Rgb888::RED)
+            .stroke_width(1)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Styled::new(button(), style).draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "          ",
+            "  WWWWWW  ",
+            " WW    WR ",
+            " W      R ",
+            " W      R ",
+            " WR    RR ",
+            "  RRRRRR  ",
+        ]);
+    }
+
+    #[test]
+    fn per_side_widths() {
+        let style = bevel_builder()
+            .top(SideStroke::new(Rgb888::WHITE, 2))
+            .left(SideStroke::new(Rgb888::WHITE, 1))
+            .bottom(SideStroke::new(Rgb888::RED, 2))
+            .right(SideStroke::new(Rgb888::RED, 3))
+            .build();
+
+        let rectangle = RoundedRectangle::with_equal_corners(
+            Rectangle::new(Point::zero(), Size::new(8, 7)),
+            Size::zero(),
+        );
+
+        let mut display = MockDisplay::new();
+        Styled::new(rectangle, style).draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "WWWWWWWR", //
+            "WWWWWWRR", //
+            "WBBBBRRR", //
+            "WBBBBRRR", //
+            "WBBBBRRR", //
+            "WRRRRRRR", //
+            "RRRRRRRR", //
+        ]);
+    }
+
+    #[test]
+    fn inner_shadow() {
+        let style = bevel_builder()
+            .stroke_width(1)
+            .inner_shadow(Point::new(1, 1), Rgb888::GREEN)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Styled::new(button(), style).draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "          ",
+            "  WWWWWW  ",
+            " WWGGGGWR ",
+            " WGGBBBBR ",
+            " WGBBBBBR ",
+            " WRBBBBRR ",
+            "  RRRRRR  ",
+        ]);
+    }
+
+    #[test]
+    fn drop_shadow() {
+        let style = bevel_builder()
+            .stroke_width(1)
+            .drop_shadow(Point::new(1, 1), Rgb888::GREEN)
+            .build();
+
+        let styled = Styled::new(button(), style);
+
+        let mut display = MockDisplay::new();
+        styled.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "           ",
+            "  WWWWWW   ",
+            " WWBBBBWR  ",
+            " WBBBBBBRG ",
+            " WBBBBBBRG ",
+            " WRBBBBRRG ",
+            "  RRRRRRGG ",
+            "   GGGGGG  ",
+        ]);
+
+        assert_eq!(
+            styled.bounding_box(),
+            Rectangle::new(Point::new(1, 1), Size::new(9, 7))
+        );
+        assert_eq!(display.affected_area(), styled.bounding_box());
+    }
+
+    #[test]
+    fn contains() {
+        let style = bevel_builder()
+            .stroke_width(2)
+            .drop_shadow(Point::new(-1, 2), Rgb888::GREEN)
+            .build();
+
+        let styled = Styled::new(button(), style);
+
+        let mut drawn = MockDisplay::new();
+        styled.draw(&mut drawn).unwrap();
+
+        let expected = MockDisplay::from_points(
+            styled
+                .bounding_box()
+                .points()
+                .filter(|p| styled.contains(*p)),
+        );
+
+        drawn.map(|_| BinaryColor::On).assert_eq(&expected);
+    }
+
+    #[test]
+    fn uniform_bevel_matches_stroke() {
+        let rectangle = RoundedRectangle::new(
+            Rectangle::new(Point::new(2, 3), Size::new(25, 18)),
+            CornerRadii {
+                top_left: Size::new(7, 5),
+                top_right: Size::new(3, 9),
+                bottom_right: Size::new(10, 8),
+                bottom_left: Size::new(4, 4),
+            },
+        );
+
+        for &stroke_width in &[1, 2, 3, 5] {
+            let bevel_style = BevelStyleBuilder::new()
+                .fill_color(Rgb888::BLUE)
+                .light_color(Rgb888::RED)
+                .dark_color(Rgb888::RED)
+                .stroke_width(stroke_width)
+                .build();
+
+            let primitive_style = PrimitiveStyleBuilder::new()
+                .fill_color(Rgb888::BLUE)
+                .stroke_color(Rgb888::RED)
+                .stroke_width(stroke_width)
+                .stroke_alignment(StrokeAlignment::Inside)
+                .build();
+
+            let mut display = MockDisplay::new();
+            Styled::new(rectangle, bevel_style)
+                .draw(&mut display)
+                .unwrap();
+
+            let mut expected = MockDisplay::new();
+            rectangle
+                .into_styled(primitive_style)
+                .draw(&mut expected)
+                .unwrap();
+
+            display.assert_eq(&expected);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! The rounded rectangle primitive.
 
+mod bevel;
 mod corner_radii;
 mod ellipse_quadrant;
 mod points;
@@ -10,6 +11,7 @@ use crate::{
     primitives::{rectangle::Rectangle, ContainsPoint, OffsetOutline, PointsIter, Primitive},
     transform::Transform,
 };
+pub use bevel::{BevelPixels, BevelStyle, BevelStyleBuilder, Shadow, ShadowKind, SideStroke};
 pub use corner_radii::{CornerRadii, CornerRadiiBuilder};
 use ellipse_quadrant::{EllipseQuadrant, Quadrant};
 pub use points::Points;
@@ -1,11 +1,11 @@
 use crate::{
     draw_target::DrawTarget,
-    geometry::Dimensions,
+    geometry::{Dimensions, Point},
     iterator::IntoPixels,
     pixelcolor::PixelColor,
     primitives::{
         rounded_rectangle::{Points, RoundedRectangle},
-        ContainsPoint, PrimitiveStyle, Rectangle, StyledPrimitiveAreas,
+        ContainsPoint, PrimitiveStyle, Rectangle, StyledContainsPoint, StyledPrimitiveAreas,
     },
     Drawable, Pixel, SaturatingCast, Styled,
 };
@@ -93,6 +93,15 @@ where
     }
 }
 
+impl<C> StyledContainsPoint for Styled<RoundedRectangle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn contains(&self, point: Point) -> bool {
+        self.areas_contain(point)
+    }
+}
+
 impl<C> Dimensions for Styled<RoundedRectangle, PrimitiveStyle<C>>
 where
     C: PixelColor,
@@ -113,7 +122,8 @@ mod tests {
         mock_display::MockDisplay,
         pixelcolor::{BinaryColor, Rgb888, RgbColor},
         primitives::{
-            rectangle::Rectangle, CornerRadii, Primitive, PrimitiveStyleBuilder, StrokeAlignment,
+            rectangle::Rectangle, CornerRadii, PointsIter, Primitive, PrimitiveStyleBuilder,
+            StrokeAlignment,
         },
         Drawable,
     };
@@ -342,4 +352,48 @@ mod tests {
 
         assert_eq!(transparent_rect.bounding_box(), filled_rect.bounding_box(),);
     }
+
+    #[test]
+    fn contains() {
+        let shape = RoundedRectangle::with_equal_corners(
+            Rectangle::new(Point::new(5, 8), Size::new(30, 20)),
+            Size::new(8, 6),
+        );
+
+        for &stroke_alignment in &[
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            let stroke = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(3)
+                .stroke_alignment(stroke_alignment)
+                .build();
+            let stroke_and_fill = PrimitiveStyleBuilder::from(&stroke)
+                .fill_color(BinaryColor::Off)
+                .build();
+
+            for &style in &[
+                stroke,
+                stroke_and_fill,
+                PrimitiveStyle::with_fill(BinaryColor::On),
+            ] {
+                let styled = shape.into_styled(style);
+
+                let mut display = MockDisplay::new();
+                styled.draw(&mut display).unwrap();
+
+                let expected = display.map(|_| BinaryColor::On);
+                let contained = MockDisplay::from_points(
+                    expected
+                        .bounding_box()
+                        .points()
+                        .filter(|p| styled.contains(*p)),
+                );
+
+                contained.assert_eq_with_message(&expected, |f| write!(f, "{:?}", style));
+            }
+        }
+    }
 }
@@ -1,14 +1,14 @@
 use crate::{
     draw_target::DrawTarget,
     geometry::angle_consts::ANGLE_90DEG,
-    geometry::{Angle, Dimensions},
+    geometry::{Angle, Dimensions, Point, PointExt},
     iterator::IntoPixels,
     pixelcolor::PixelColor,
     primitives::{
         common::{
             DistanceIterator, LineSide, LinearEquation, PlaneSector, PointType, NORMAL_VECTOR_SCALE,
         },
-        PrimitiveStyle, Rectangle, Sector, StyledPrimitiveAreas,
+        PrimitiveStyle, Rectangle, Sector, StyledContainsPoint, StyledPrimitiveAreas,
     },
     Drawable, Pixel, SaturatingCast, Styled,
 };
@@ -105,6 +105,49 @@ where
             fill_color: styled.style.fill_color,
         }
     }
+
+    /// Returns the point type for a point with the given delta and squared distance.
+    ///
+    /// `None` is returned if the point is outside the sector.
+    fn point_type(&self, delta: Point, distance: u32) -> Option<PointType> {
+        if distance >= self.outer_threshold {
+            return None;
+        }
+
+        // Check if point is inside the radial stroke lines or the fill.
+        let mut point_type = self.plane_sector.point_type(
+            delta,
+            self.stroke_threshold_inside,
+            self.stroke_threshold_outside,
+        )?;
+
+        // Bevel the line join.
+        if point_type == PointType::Stroke {
+            if let Some((kind, equation)) = self.bevel {
+                if equation.check_side(delta, LineSide::Left) {
+                    match kind {
+                        BevelKind::Interior => point_type = PointType::Fill,
+                        BevelKind::Exterior => return None,
+                    }
+                }
+            }
+        }
+
+        // Add the outer circular stroke.
+        if point_type == PointType::Fill && distance >= self.inner_threshold {
+            point_type = PointType::Stroke;
+        }
+
+        Some(point_type)
+    }
+
+    /// Returns the color for a point with the given delta and squared distance.
+    fn color(&self, delta: Point, distance: u32) -> Option<C> {
+        match self.point_type(delta, distance)? {
+            PointType::Stroke => self.stroke_color,
+            PointType::Fill => self.fill_color,
+        }
+    }
 }
 
 impl<C> Iterator for StyledPixels<C>
@@ -114,49 +157,13 @@ where
     type Item = Pixel<C>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let outer_threshold = self.outer_threshold;
-
-        loop {
-            let (point, delta, distance) = self
-                .iter
-                .find(|(_, _, distance)| *distance < outer_threshold)?;
-
-            // Check if point is inside the radial stroke lines or the fill.
-            let mut point_type = match self.plane_sector.point_type(
-                delta,
-                self.stroke_threshold_inside,
-                self.stroke_threshold_outside,
-            ) {
-                Some(point_type) => point_type,
-                None => continue,
-            };
-
-            // Bevel the line join.
-            if point_type == PointType::Stroke {
-                if let Some((kind, equation)) = self.bevel {
-                    if equation.check_side(delta, LineSide::Left) {
-                        match kind {
-                            BevelKind::Interior => point_type = PointType::Fill,
-                            BevelKind::Exterior => continue,
-                        }
-                    }
-                }
-            }
-
-            // Add the outer circular stroke.
-            if point_type == PointType::Fill && distance >= self.inner_threshold {
-                point_type = PointType::Stroke;
-            }
-
-            let color = match point_type {
-                PointType::Stroke => self.stroke_color,
-                PointType::Fill => self.fill_color,
-            };
-
-            if let Some(color) = color {
+        while let Some((point, delta, distance)) = self.iter.next() {
+            if let Some(color) = self.color(delta, distance) {
                 return Some(Pixel(point, color));
             }
         }
+
+        None
     }
 }
 
@@ -188,6 +195,18 @@ where
     }
 }
 
+impl<C> StyledContainsPoint for Styled<Sector, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn contains(&self, point: Point) -> bool {
+        let delta = point * 2 - self.stroke_area().to_circle().center_2x();
+        let distance = delta.length_squared() as u32;
+
+        StyledPixels::new(self).color(delta, distance).is_some()
+    }
+}
+
 impl<C> Dimensions for Styled<Sector, PrimitiveStyle<C>>
 where
     C: PixelColor,
@@ -213,7 +232,9 @@ mod tests {
         geometry::{AngleUnit, Point},
         mock_display::MockDisplay,
         pixelcolor::{BinaryColor, Rgb888, RgbColor},
-        primitives::{Circle, Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment},
+        primitives::{
+            Circle, PointsIter, Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment,
+        },
     };
 
     // Check the rendering of a simple sector
@@ -702,4 +723,45 @@ mod tests {
             .draw(&mut display)
             .unwrap();
     }
+
+    #[test]
+    fn contains() {
+        let shape = Sector::new(Point::new(5, 8), 30, 30.0.deg(), 100.0.deg());
+
+        for &stroke_alignment in &[
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            let stroke = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(3)
+                .stroke_alignment(stroke_alignment)
+                .build();
+            let stroke_and_fill = PrimitiveStyleBuilder::from(&stroke)
+                .fill_color(BinaryColor::Off)
+                .build();
+
+            for &style in &[
+                stroke,
+                stroke_and_fill,
+                PrimitiveStyle::with_fill(BinaryColor::On),
+            ] {
+                let styled = shape.into_styled(style);
+
+                let mut display = MockDisplay::new();
+                styled.draw(&mut display).unwrap();
+
+                let expected = display.map(|_| BinaryColor::On);
+                let contained = MockDisplay::from_points(
+                    expected
+                        .bounding_box()
+                        .points()
+                        .filter(|p| styled.contains(*p)),
+                );
+
+                contained.assert_eq_with_message(&expected, |f| write!(f, "{:?}", style));
+            }
+        }
+    }
 }
@@ -6,10 +6,10 @@ mod scanline_iterator;
 mod styled;
 
 use crate::{
-    geometry::{Dimensions, Point},
+    geometry::{Dimensions, Point, PointExt},
     primitives::{
         common::{LineJoin, LineSide, LinearEquation, Scanline, StrokeOffset},
-        ContainsPoint, Line, PointsIter, Primitive, Rectangle,
+        ContainsPoint, Line, OffsetOutline, PointsIter, Primitive, Rectangle,
     },
     transform::Transform,
 };
@@ -135,6 +135,54 @@ impl ContainsPoint for Triangle {
     }
 }
 
+impl OffsetOutline for Triangle {
+    /// Offsets the outline of the triangle.
+    ///
+    /// The edges are moved parallel to the original edges and the new vertices are placed at the
+    /// intersections of the moved edges, which results in mitered corners. If the triangle is
+    /// shrunk by more than its inradius, all vertices are placed at the center of the incircle.
+    ///
+    /// Colinear triangles don't have an outline that could be offset and are returned unchanged.
+    fn offset(&self, offset: i32) -> Self {
+        let area = self.area_doubled();
+
+        if offset == 0 || area == 0 {
+            return *self;
+        }
+
+        // The outside of a clockwise triangle is on the left side of the edges.
+        let offset = if area > 0 {
+            offset
+        } else {
+            offset.checked_neg().unwrap_or(core::i32::MAX)
+        };
+
+        let [p1, p2, p3] = self.vertices;
+
+        let edge1 = Line::new(p1, p2).parallel(offset);
+        let edge2 = Line::new(p2, p3).parallel(offset);
+        let edge3 = Line::new(p3, p1).parallel(offset);
+
+        let triangle = Self::new(
+            edge3.line_intersection(&edge1).unwrap_or(edge1.start),
+            edge1.line_intersection(&edge2).unwrap_or(edge2.start),
+            edge2.line_intersection(&edge3).unwrap_or(edge3.start),
+        );
+
+        // Shrinking the triangle by more than its inradius turns it upside down, which is
+        // detected by checking if an edge changed its direction.
+        let delta = p2 - p1;
+        let offset_delta = triangle.vertices[1] - triangle.vertices[0];
+        if delta.dot_product(offset_delta) <= 0 {
+            let center = self.homothetic_center(&triangle);
+
+            Self::new(center, center, center)
+        } else {
+            triangle
+        }
+    }
+}
+
 impl Dimensions for Triangle {
     fn bounding_box(&self) -> Rectangle {
         let [p1, p2, p3] = self.vertices;
@@ -228,6 +276,33 @@ impl Triangle {
         scanline
     }
 
+    /// Returns the center of a homothety that maps this triangle onto `other`.
+    ///
+    /// Both triangles must be similar with parallel edges and `other` must be a point reflected
+    /// copy of `self`. For triangles created by `offset` the center is the incenter.
+    fn homothetic_center(&self, other: &Self) -> Point {
+        let delta = self.vertices[1] - self.vertices[0];
+        let other_delta = other.vertices[1] - other.vertices[0];
+
+        // The scaling factor is `k = scale_numerator / scale_denominator`, which is less than or
+        // equal to 0. The center is calculated by solving `other_v = center + k * (v - center)`.
+        let scale_denominator = i64::from(delta.length_squared());
+        let scale_numerator = i64::from(delta.dot_product(other_delta));
+
+        let center = |v: i32, other_v: i32| {
+            let numerator = i64::from(other_v) * scale_denominator - i64::from(v) * scale_numerator;
+            let denominator = scale_denominator - scale_numerator;
+
+            // Round to the nearest integer.
+            (numerator + denominator / 2).div_euclid(denominator) as i32
+        };
+
+        Point::new(
+            center(self.vertices[0].x, other.vertices[0].x),
+            center(self.vertices[0].y, other.vertices[0].y),
+        )
+    }
+
     /// Generate a line join for each corner of the triangle.
     fn joins(&self, stroke_width: u32, stroke_offset: StrokeOffset) -> [LineJoin; 3] {
         let [p1, p2, p3] = self.vertices;
@@ -442,4 +517,59 @@ mod tests {
 
         assert_eq!(triangle.is_collapsed(20, StrokeOffset::None), true);
     }
+
+    #[test]
+    fn offset() {
+        let triangle = Triangle::new(Point::new(10, 10), Point::new(30, 10), Point::new(10, 30));
+
+        assert_eq!(triangle.offset(0), triangle);
+
+        assert_eq!(
+            triangle.offset(2),
+            Triangle::new(Point::new(8, 8), Point::new(35, 8), Point::new(8, 35))
+        );
+        assert_eq!(
+            triangle.offset(-2),
+            Triangle::new(Point::new(12, 12), Point::new(25, 12), Point::new(12, 25))
+        );
+    }
+
+    #[test]
+    fn offset_counterclockwise() {
+        let triangle = Triangle::new(Point::new(30, 10), Point::new(10, 10), Point::new(10, 30));
+
+        assert_eq!(
+            triangle.offset(2),
+            Triangle::new(Point::new(35, 8), Point::new(8, 8), Point::new(8, 35))
+        );
+        assert_eq!(
+            triangle.offset(-2),
+            Triangle::new(Point::new(25, 12), Point::new(12, 12), Point::new(12, 25))
+        );
+    }
+
+    #[test]
+    fn offset_collapses_to_incenter() {
+        let triangle = Triangle::new(Point::new(10, 10), Point::new(30, 10), Point::new(10, 30));
+
+        // The incenter of this triangle is at (15.86, 15.86).
+        let incenter = Point::new(16, 16);
+
+        for offset in 6..30 {
+            assert_eq!(
+                triangle.offset(-offset),
+                Triangle::new(incenter, incenter, incenter),
+                "offset: {}",
+                -offset
+            );
+        }
+    }
+
+    #[test]
+    fn offset_colinear() {
+        let triangle = Triangle::new(Point::new(10, 10), Point::new(20, 20), Point::new(30, 30));
+
+        assert_eq!(triangle.offset(5), triangle);
+        assert_eq!(triangle.offset(-5), triangle);
+    }
 }
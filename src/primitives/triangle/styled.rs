@@ -5,8 +5,11 @@ use crate::{
     pixelcolor::PixelColor,
     primitives::{
         common::{ClosedThickSegmentIter, PointType, Scanline, StrokeOffset},
-        triangle::{scanline_iterator::ScanlineIterator, Triangle},
-        PrimitiveStyle, Rectangle, StrokeAlignment,
+        triangle::{
+            scanline_intersections::ScanlineIntersections, scanline_iterator::ScanlineIterator,
+            Triangle,
+        },
+        PrimitiveStyle, Rectangle, StrokeAlignment, StyledContainsPoint,
     },
     Drawable, Pixel, Styled,
 };
@@ -133,6 +136,36 @@ where
     }
 }
 
+impl<C> StyledContainsPoint for Styled<Triangle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn contains(&self, point: Point) -> bool {
+        if self.style.is_transparent() || !self.bounding_box().contains(point) {
+            return false;
+        }
+
+        let stroke_color = self.style.effective_stroke_color();
+        let fill_color = self.style.fill_color;
+
+        ScanlineIntersections::new(
+            &self.primitive.sorted_clockwise(),
+            self.style.stroke_width,
+            StrokeOffset::from(self.style.stroke_alignment),
+            fill_color.is_some(),
+            point.y,
+        )
+        .any(|(line, kind)| {
+            let color_is_some = match kind {
+                PointType::Stroke => stroke_color.is_some(),
+                PointType::Fill => fill_color.is_some(),
+            };
+
+            color_is_some && line.x.contains(&point.x)
+        })
+    }
+}
+
 impl<C> Dimensions for Styled<Triangle, PrimitiveStyle<C>>
 where
     C: PixelColor,
@@ -176,7 +209,7 @@ mod tests {
         geometry::Point,
         mock_display::MockDisplay,
         pixelcolor::{BinaryColor, Rgb565, Rgb888, RgbColor},
-        primitives::{Line, Primitive, PrimitiveStyleBuilder, StrokeAlignment},
+        primitives::{Line, PointsIter, Primitive, PrimitiveStyleBuilder, StrokeAlignment},
         transform::Transform,
         Drawable,
     };
@@ -568,4 +601,45 @@ mod tests {
             "R            ",
         ]);
     }
+
+    #[test]
+    fn contains() {
+        let shape = Triangle::new(Point::new(5, 8), Point::new(30, 12), Point::new(12, 30));
+
+        for &stroke_alignment in &[
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            let stroke = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(3)
+                .stroke_alignment(stroke_alignment)
+                .build();
+            let stroke_and_fill = PrimitiveStyleBuilder::from(&stroke)
+                .fill_color(BinaryColor::Off)
+                .build();
+
+            for &style in &[
+                stroke,
+                stroke_and_fill,
+                PrimitiveStyle::with_fill(BinaryColor::On),
+            ] {
+                let styled = shape.into_styled(style);
+
+                let mut display = MockDisplay::new();
+                styled.draw(&mut display).unwrap();
+
+                let expected = display.map(|_| BinaryColor::On);
+                let contained = MockDisplay::from_points(
+                    expected
+                        .bounding_box()
+                        .points()
+                        .filter(|p| styled.contains(*p)),
+                );
+
+                contained.assert_eq_with_message(&expected, |f| write!(f, "{:?}", style));
+            }
+        }
+    }
 }
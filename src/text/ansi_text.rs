@@ -0,0 +1,729 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point},
+    pixelcolor::{PixelColor, Rgb888},
+    primitives::Rectangle,
+    text::{
+        layout::{draw_lines, measure_lines, Layout, StyledLine},
+        CharacterStyle, DecorationColor, Span, TextRenderer, TextStyle,
+    },
+    transform::Transform,
+    Drawable, Styled,
+};
+
+/// Text with ANSI escape sequences.
+///
+/// `AnsiText` works like [`Text`], but the string can contain ANSI SGR (Select Graphic
+/// Rendition) escape sequences, which change the style of the following characters. The
+/// character style attached to the text by using [`into_styled`] is used as the default style
+/// and all escape sequences are applied by calling the [`CharacterStyle`] setters before the
+/// following text is drawn by the [`TextRenderer`].
+///
+/// The following SGR parameters are supported:
+///
+/// | Parameter | Description |
+/// |-----------|-------------|
+/// | 0 | Reset to the default style |
+/// | 4 | Underline in text color |
+/// | 9 | Strikethrough in text color |
+/// | 24 | Disable underline |
+/// | 29 | Disable strikethrough |
+/// | 30 - 37 | Set text color |
+/// | 38;5;n / 38;2;r;g;b | Set text color from 256 color palette / RGB value |
+/// | 39 | Reset text color to default |
+/// | 40 - 47 | Set background color |
+/// | 48;5;n / 48;2;r;g;b | Set background color from 256 color palette / RGB value |
+/// | 49 | Reset background color to default |
+/// | 58;5;n / 58;2;r;g;b | Set underline color from 256 color palette / RGB value |
+/// | 59 | Reset underline color to text color |
+/// | 90 - 97 | Set bright text color |
+/// | 100 - 107 | Set bright background color |
+///
+/// Lines are laid out in the same way as in [`Text`], including the alignment, vertical
+/// alignment, line height and tab stops of a [`TextStyle`].
+///
+/// Other SGR parameters and other escape sequences are ignored. Settings which aren't supported
+/// by the used character style, like font changes, can't be expressed by a `CharacterStyle` and
+/// are therefore also ignored.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     mono_font::{ascii::Font6x9, MonoTextStyle},
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     text::AnsiText,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+/// # display.set_allow_out_of_bounds_drawing(true);
+///
+/// AnsiText::new(
+///     "Status: \x1b[32;4mOK\x1b[0m\nErrors: \x1b[91m3",
+///     Point::new(5, 10),
+/// )
+/// .into_styled(MonoTextStyle::new(Font6x9, Rgb565::WHITE))
+/// .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Text`]: struct.Text.html
+/// [`TextStyle`]: struct.TextStyle.html
+/// [`into_styled`]: #method.into_styled
+/// [`CharacterStyle`]: trait.CharacterStyle.html
+/// [`TextRenderer`]: trait.TextRenderer.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct AnsiText<'a> {
+    /// The string.
+    pub text: &'a str,
+
+    /// The position.
+    pub position: Point,
+}
+
+impl<'a> AnsiText<'a> {
+    /// Creates a text with ANSI escape sequences.
+    pub const fn new(text: &'a str, position: Point) -> Self {
+        Self { text, position }
+    }
+
+    /// Attaches a text style to the text object.
+    pub fn into_styled<S>(self, style: S) -> Styled<Self, S> {
+        Styled::new(self, style)
+    }
+
+    /// Returns an iterator over the lines of this text.
+    fn lines<C>(&self) -> Lines<'a, C>
+    where
+        C: PixelColor + From<Rgb888>,
+    {
+        Lines {
+            segments: self.segments(),
+        }
+    }
+
+    /// Returns an iterator over the segments of this text.
+    fn segments<C>(&self) -> Segments<'a, C>
+    where
+        C: PixelColor + From<Rgb888>,
+    {
+        Segments {
+            text: self.text,
+            state: Span::new(""),
+        }
+    }
+}
+
+impl Transform for AnsiText<'_> {
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            position: self.position + by,
+            ..*self
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.position += by;
+
+        self
+    }
+}
+
+/// Text segment.
+#[derive(Debug, PartialEq)]
+enum Segment<'a, C> {
+    /// Text with style overrides.
+    Span(Span<'a, C>),
+
+    /// Line break.
+    NewLine,
+}
+
+/// Iterator over the text segments in a string with ANSI escape sequences.
+#[derive(Clone, Debug)]
+struct Segments<'a, C> {
+    /// Remaining text.
+    text: &'a str,
+
+    /// Style overrides set by the previous escape sequences.
+    state: Span<'a, C>,
+}
+
+impl<'a, C> Segments<'a, C>
+where
+    C: PixelColor + From<Rgb888>,
+{
+    /// Parses an escape sequence at the start of the remaining text.
+    ///
+    /// The text must start with the escape character.
+    fn parse_escape_sequence(&mut self) {
+        let sequence = &self.text[1..];
+
+        if !sequence.starts_with('[') {
+            // Ignore the escape character if it isn't the start of a control sequence.
+            self.text = sequence;
+            return;
+        }
+
+        // A control sequence ends with a byte in the range from `@` to `~`.
+        let sequence = &sequence[1..];
+        let end = match sequence.find(|c| ('@'..='~').contains(&c)) {
+            Some(end) => end,
+            None => {
+                self.text = "";
+                return;
+            }
+        };
+
+        if sequence[end..].starts_with('m') {
+            self.apply_sgr(&sequence[..end]);
+        }
+
+        self.text = &sequence[end + 1..];
+    }
+
+    /// Applies the parameters of an SGR escape sequence.
+    fn apply_sgr(&mut self, parameters: &str) {
+        // An empty parameter is interpreted as 0 and invalid parameters are returned as `None`.
+        let mut parameters = parameters.split(';').map(|parameter| {
+            if parameter.is_empty() {
+                Some(0)
+            } else {
+                parameter.parse::<u8>().ok()
+            }
+        });
+
+        while let Some(parameter) = parameters.next() {
+            let parameter = match parameter {
+                Some(parameter) => parameter,
+                None => continue,
+            };
+
+            let state = &mut self.state;
+
+            match parameter {
+                0 => *state = Span::new(""),
+                4 => state.underline_color = Some(DecorationColor::TextColor),
+                9 => state.strikethrough_color = Some(DecorationColor::TextColor),
+                24 => state.underline_color = Some(DecorationColor::None),
+                29 => state.strikethrough_color = Some(DecorationColor::None),
+                30..=37 => state.text_color = Some(palette_color(parameter - 30).into()),
+                38 => {
+                    if let Some(color) = extended_color(&mut parameters) {
+                        state.text_color = Some(color.into());
+                    }
+                }
+                39 => state.text_color = None,
+                40..=47 => state.background_color = Some(palette_color(parameter - 40).into()),
+                48 => {
+                    if let Some(color) = extended_color(&mut parameters) {
+                        state.background_color = Some(color.into());
+                    }
+                }
+                49 => state.background_color = None,
+                58 => {
+                    if let Some(color) = extended_color(&mut parameters) {
+                        state.underline_color = Some(DecorationColor::Custom(color.into()));
+                    }
+                }
+                59 if state
+                    .underline_color
+                    .filter(DecorationColor::is_custom)
+                    .is_some() =>
+                {
+                    state.underline_color = Some(DecorationColor::TextColor);
+                }
+                90..=97 => state.text_color = Some(palette_color(parameter - 90 + 8).into()),
+                100..=107 => {
+                    state.background_color = Some(palette_color(parameter - 100 + 8).into())
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<'a, C> Iterator for Segments<'a, C>
+where
+    C: PixelColor + From<Rgb888>,
+{
+    type Item = Segment<'a, C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.text.is_empty() {
+                return None;
+            } else if self.text.starts_with('\x1b') {
+                self.parse_escape_sequence();
+            } else if self.text.starts_with('\n') {
+                self.text = &self.text[1..];
+                return Some(Segment::NewLine);
+            } else {
+                let end = self
+                    .text
+                    .find(&['\x1b', '\n'][..])
+                    .unwrap_or(self.text.len());
+
+                let span = Span {
+                    text: &self.text[..end],
+                    ..self.state
+                };
+                self.text = &self.text[end..];
+
+                return Some(Segment::Span(span));
+            }
+        }
+    }
+}
+
+/// Parses an extended color parameter.
+///
+/// Extended colors are either specified as an index into the 256 color palette (`5;n`) or as an
+/// RGB value (`2;r;g;b`). `None` is returned if the parameters are missing or invalid.
+fn extended_color<I>(parameters: &mut I) -> Option<Rgb888>
+where
+    I: Iterator<Item = Option<u8>>,
+{
+    match parameters.next()?? {
+        5 => parameters.next()?.map(palette_color),
+        2 => {
+            let r = parameters.next()??;
+            let g = parameters.next()??;
+            let b = parameters.next()??;
+
+            Some(Rgb888::new(r, g, b))
+        }
+        _ => None,
+    }
+}
+
+/// Returns a color from the 256 color palette.
+fn palette_color(index: u8) -> Rgb888 {
+    const BASIC_COLORS: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (170, 0, 0),
+        (0, 170, 0),
+        (170, 85, 0),
+        (0, 0, 170),
+        (170, 0, 170),
+        (0, 170, 170),
+        (170, 170, 170),
+        (85, 85, 85),
+        (255, 85, 85),
+        (85, 255, 85),
+        (255, 255, 85),
+        (85, 85, 255),
+        (255, 85, 255),
+        (85, 255, 255),
+        (255, 255, 255),
+    ];
+
+    match index {
+        0..=15 => {
+            let (r, g, b) = BASIC_COLORS[usize::from(index)];
+
+            Rgb888::new(r, g, b)
+        }
+        16..=231 => {
+            // 6x6x6 color cube
+            let index = index - 16;
+            let level = |value: u8| if value == 0 { 0 } else { value * 40 + 55 };
+
+            Rgb888::new(level(index / 36), level(index / 6 % 6), level(index % 6))
+        }
+        _ => {
+            // Grayscale ramp
+            let luma = (index - 232) * 10 + 8;
+
+            Rgb888::new(luma, luma, luma)
+        }
+    }
+}
+
+/// Iterator over the lines in a string with ANSI escape sequences.
+///
+/// Each line is returned as the segments iterator at the start of the line, which includes the
+/// style set by the escape sequences in the preceding lines.
+#[derive(Clone, Debug)]
+struct Lines<'a, C> {
+    segments: Segments<'a, C>,
+}
+
+impl<'a, C> Iterator for Lines<'a, C>
+where
+    C: PixelColor + From<Rgb888>,
+{
+    type Item = Line<'a, C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Like `str::lines`, no trailing empty line is returned.
+        if self.segments.text.is_empty() {
+            return None;
+        }
+
+        let line = Line {
+            segments: self.segments.clone(),
+        };
+
+        for segment in self.segments.by_ref() {
+            if let Segment::NewLine = segment {
+                break;
+            }
+        }
+
+        Some(line)
+    }
+}
+
+/// A single line of text with ANSI escape sequences.
+#[derive(Debug)]
+struct Line<'a, C> {
+    segments: Segments<'a, C>,
+}
+
+impl<C, S> StyledLine<S> for Line<'_, C>
+where
+    C: PixelColor + From<Rgb888>,
+    S: CharacterStyle<Color = C>,
+{
+    fn try_for_each_piece<F, E>(&self, style: &S, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&str, &S) -> Result<(), E>,
+    {
+        for segment in self.segments.clone() {
+            match segment {
+                Segment::Span(span) => {
+                    let mut span_style = style.clone();
+                    span.apply(&mut span_style);
+
+                    f(span.text, &span_style)?;
+                }
+                Segment::NewLine => break,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<C, S> Drawable for Styled<AnsiText<'_>, S>
+where
+    C: PixelColor + From<Rgb888>,
+    S: TextRenderer<Color = C> + CharacterStyle<Color = C>,
+{
+    type Color = C;
+    type Output = Point;
+
+    fn draw<D>(&self, target: &mut D) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        draw_lines(
+            self.primitive.lines(),
+            self.primitive.position,
+            &self.style,
+            Layout::default(),
+            target,
+        )
+    }
+}
+
+impl<C, S> Drawable for Styled<AnsiText<'_>, TextStyle<'_, S>>
+where
+    C: PixelColor + From<Rgb888>,
+    S: TextRenderer<Color = C> + CharacterStyle<Color = C>,
+{
+    type Color = C;
+    type Output = Point;
+
+    fn draw<D>(&self, target: &mut D) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        draw_lines(
+            self.primitive.lines(),
+            self.primitive.position,
+            &self.style.character_style,
+            Layout::new(&self.style),
+            target,
+        )
+    }
+}
+
+impl<C, S> Dimensions for Styled<AnsiText<'_>, S>
+where
+    C: PixelColor + From<Rgb888>,
+    S: TextRenderer<Color = C> + CharacterStyle<Color = C>,
+{
+    fn bounding_box(&self) -> Rectangle {
+        measure_lines(
+            self.primitive.lines(),
+            self.primitive.position,
+            &self.style,
+            Layout::default(),
+        )
+        .bounding_box
+    }
+}
+
+impl<C, S> Dimensions for Styled<AnsiText<'_>, TextStyle<'_, S>>
+where
+    C: PixelColor + From<Rgb888>,
+    S: TextRenderer<Color = C> + CharacterStyle<Color = C>,
+{
+    fn bounding_box(&self) -> Rectangle {
+        measure_lines(
+            self.primitive.lines(),
+            self.primitive.position,
+            &self.style.character_style,
+            Layout::new(&self.style),
+        )
+        .bounding_box
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock_display::MockDisplay,
+        mono_font::{ascii::Font6x9, MonoTextStyle, MonoTextStyleBuilder},
+        pixelcolor::{Rgb565, RgbColor},
+        text::{
+            Alignment, Baseline, LineHeight, RichText, TabStops, Text, TextStyleBuilder,
+            VerticalAlignment,
+        },
+    };
+
+    fn segments(text: &str) -> Segments<'_, Rgb888> {
+        AnsiText::new(text, Point::zero()).segments()
+    }
+
+    #[test]
+    fn plain_text() {
+        let mut segments = segments("ab\ncd");
+
+        assert_eq!(segments.next(), Some(Segment::Span(Span::new("ab"))));
+        assert_eq!(segments.next(), Some(Segment::NewLine));
+        assert_eq!(segments.next(), Some(Segment::Span(Span::new("cd"))));
+        assert_eq!(segments.next(), None);
+    }
+
+    #[test]
+    fn sgr_parameters() {
+        let mut segments = segments(
+            "\x1b[31;4ma\x1b[42;9mb\x1b[39;24;49mc\x1b[0md\x1b[38;2;1;2;3;48;5;196me\x1b[mf",
+        );
+
+        let red = palette_color(1);
+        let green = palette_color(2);
+
+        assert_eq!(
+            segments.next(),
+            Some(Segment::Span(Span::new("a").text_color(red).underline()))
+        );
+        assert_eq!(
+            segments.next(),
+            Some(Segment::Span(
+                Span::new("b")
+                    .text_color(red)
+                    .background_color(green)
+                    .underline()
+                    .strikethrough()
+            ))
+        );
+
+        let mut expected = Span::new("c").strikethrough();
+        expected.underline_color = Some(DecorationColor::None);
+        assert_eq!(segments.next(), Some(Segment::Span(expected)));
+
+        assert_eq!(segments.next(), Some(Segment::Span(Span::new("d"))));
+        assert_eq!(
+            segments.next(),
+            Some(Segment::Span(
+                Span::new("e")
+                    .text_color(Rgb888::new(1, 2, 3))
+                    .background_color(Rgb888::new(255, 0, 0))
+            ))
+        );
+        assert_eq!(segments.next(), Some(Segment::Span(Span::new("f"))));
+        assert_eq!(segments.next(), None);
+    }
+
+    #[test]
+    fn underline_color() {
+        let mut segments = segments("\x1b[4;58;5;21ma\x1b[59mb");
+
+        assert_eq!(
+            segments.next(),
+            Some(Segment::Span(
+                Span::new("a").underline_with_color(Rgb888::new(0, 0, 255))
+            ))
+        );
+        assert_eq!(
+            segments.next(),
+            Some(Segment::Span(Span::new("b").underline()))
+        );
+    }
+
+    #[test]
+    fn ignored_sequences() {
+        let mut segments = segments("\x1b[2Ja\x1bb\x1b[1;3mc\x1b[31");
+
+        assert_eq!(segments.next(), Some(Segment::Span(Span::new("a"))));
+        assert_eq!(segments.next(), Some(Segment::Span(Span::new("b"))));
+        assert_eq!(segments.next(), Some(Segment::Span(Span::new("c"))));
+        assert_eq!(segments.next(), None);
+    }
+
+    #[test]
+    fn invalid_parameters_are_ignored() {
+        let mut segments = segments("\x1b[31;4ma\x1b[300;1?;42mb");
+
+        let red = palette_color(1);
+        let green = palette_color(2);
+
+        assert_eq!(
+            segments.next(),
+            Some(Segment::Span(Span::new("a").text_color(red).underline()))
+        );
+        assert_eq!(
+            segments.next(),
+            Some(Segment::Span(
+                Span::new("b")
+                    .text_color(red)
+                    .background_color(green)
+                    .underline()
+            ))
+        );
+        assert_eq!(segments.next(), None);
+    }
+
+    #[test]
+    fn invalid_extended_colors_are_ignored() {
+        let mut segments =
+            segments("\x1b[31;42ma\x1b[38;5;256mb\x1b[48;2;1;2ma\x1b[38;7mc\x1b[38;2;1;?;3md");
+
+        let red = palette_color(1);
+        let green = palette_color(2);
+        let expected = |text| {
+            Some(Segment::Span(
+                Span::new(text).text_color(red).background_color(green),
+            ))
+        };
+
+        assert_eq!(segments.next(), expected("a"));
+        assert_eq!(segments.next(), expected("b"));
+        assert_eq!(segments.next(), expected("a"));
+        assert_eq!(segments.next(), expected("c"));
+        assert_eq!(segments.next(), expected("d"));
+        assert_eq!(segments.next(), None);
+    }
+
+    #[test]
+    fn palette() {
+        assert_eq!(palette_color(9), Rgb888::new(255, 85, 85));
+        assert_eq!(palette_color(16), Rgb888::new(0, 0, 0));
+        assert_eq!(palette_color(231), Rgb888::new(255, 255, 255));
+        assert_eq!(palette_color(232), Rgb888::new(8, 8, 8));
+        assert_eq!(palette_color(255), Rgb888::new(238, 238, 238));
+    }
+
+    #[test]
+    fn matches_rich_text() {
+        for &alignment in &[Alignment::Left, Alignment::Center, Alignment::Right] {
+            let character_style = MonoTextStyleBuilder::new()
+                .font(Font6x9)
+                .text_color(Rgb565::WHITE)
+                .build();
+
+            let text_style = TextStyleBuilder::new()
+                .character_style(character_style)
+                .alignment(alignment)
+                .baseline(Baseline::Top)
+                .build();
+
+            let text = AnsiText::new("A\x1b[91;44mB\nC\x1b[4mD", Point::new(20, 0))
+                .into_styled(text_style);
+
+            let red = Rgb565::from(palette_color(9));
+            let blue = Rgb565::from(palette_color(4));
+            let spans = [
+                Span::new("A"),
+                Span::new("B\nC").text_color(red).background_color(blue),
+                Span::new("D")
+                    .text_color(red)
+                    .background_color(blue)
+                    .underline(),
+            ];
+            let rich_text = RichText::new(&spans, Point::new(20, 0)).into_styled(text_style);
+
+            let mut expected = MockDisplay::new();
+            expected.set_allow_overdraw(true);
+            let expected_next = rich_text.draw(&mut expected).unwrap();
+
+            let mut display = MockDisplay::new();
+            display.set_allow_overdraw(true);
+            let next = text.draw(&mut display).unwrap();
+
+            display.assert_eq(&expected);
+            assert_eq!(next, expected_next, "{:?}", alignment);
+            assert_eq!(
+                text.bounding_box(),
+                rich_text.bounding_box(),
+                "{:?}",
+                alignment
+            );
+        }
+    }
+
+    #[test]
+    fn text_style_layout() {
+        let character_style = MonoTextStyle::new(Font6x9, Rgb565::WHITE);
+
+        let text_style = TextStyleBuilder::new()
+            .character_style(character_style)
+            .alignment(Alignment::Right)
+            .baseline(Baseline::Top)
+            .vertical_alignment(VerticalAlignment::Middle)
+            .line_height(LineHeight::Percent(150))
+            .tab_stops(TabStops::Pixels(10))
+            .build();
+
+        let ansi_text = AnsiText::new("A\tB\x1b[4m\x1b[0m\nC\x1b[mD", Point::new(30, 20))
+            .into_styled(text_style);
+
+        let mut display = MockDisplay::new();
+        let next = ansi_text.draw(&mut display).unwrap();
+
+        let text = Text::new("A\tB\nCD", Point::new(30, 20)).into_styled(text_style);
+        let mut expected = MockDisplay::new();
+        let expected_next = text.draw(&mut expected).unwrap();
+
+        display.assert_eq(&expected);
+        assert_eq!(next, expected_next);
+        assert_eq!(ansi_text.bounding_box(), text.bounding_box());
+    }
+
+    #[test]
+    fn without_text_style() {
+        let style = MonoTextStyle::new(Font6x9, Rgb565::WHITE);
+
+        let text = AnsiText::new("\x1b[32mA", Point::new(0, 6)).into_styled(style);
+
+        let mut display = MockDisplay::new();
+        text.draw(&mut display).unwrap();
+
+        assert_eq!(
+            text.bounding_box(),
+            Text::new("A", Point::new(0, 6))
+                .into_styled(style)
+                .bounding_box()
+        );
+        assert_eq!(
+            display.get_pixel(Point::new(2, 1)),
+            Some(Rgb565::from(palette_color(2)))
+        );
+    }
+}
@@ -0,0 +1,659 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point},
+    pixelcolor::PixelColor,
+    primitives::Rectangle,
+    text::{
+        layout::{draw_lines, measure_lines, Layout, StyledLine},
+        Alignment, TextRenderer, TextStyle,
+    },
+    transform::Transform,
+    Drawable, Styled,
+};
+use core::iter;
+
+/// Paragraph direction.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Direction {
+    /// The direction is determined by the first strong character in the text.
+    ///
+    /// Texts without strong characters are laid out from left to right.
+    Auto,
+    /// Left to right.
+    LeftToRight,
+    /// Right to left.
+    RightToLeft,
+}
+
+/// Simplified bidirectional character type.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+enum Class {
+    /// Strong left to right character.
+    Left,
+    /// Strong right to left character.
+    Right,
+    /// Digit.
+    Number,
+    /// Whitespace, punctuation and other neutral characters.
+    Neutral,
+}
+
+impl Class {
+    fn of(c: char) -> Self {
+        match c as u32 {
+            0x30..=0x39 | 0x660..=0x669 | 0x6F0..=0x6F9 => Class::Number,
+            0x590..=0x8FF | 0xFB1D..=0xFDFF | 0xFE70..=0xFEFF | 0x10800..=0x10FFF => Class::Right,
+            _ if c.is_alphabetic() => Class::Left,
+            _ => Class::Neutral,
+        }
+    }
+
+    /// Returns the direction which is used to resolve adjacent neutral characters.
+    fn neutral_direction(self) -> Self {
+        if self == Class::Number {
+            Class::Right
+        } else {
+            self
+        }
+    }
+}
+
+/// Run of characters with the same embedding level.
+///
+/// `BidiRun`s are used as the caller provided buffer for the layout of a [`BidiText`]. The
+/// required buffer size depends on the number of direction changes in the text.
+///
+/// [`BidiText`]: struct.BidiText.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BidiRun {
+    start: usize,
+    end: usize,
+    class: Class,
+    level: u8,
+}
+
+impl BidiRun {
+    /// Creates an empty run.
+    pub const fn new() -> Self {
+        Self {
+            start: 0,
+            end: 0,
+            class: Class::Neutral,
+            level: 0,
+        }
+    }
+
+    /// Returns `true` if the run is drawn from right to left.
+    fn is_right_to_left(&self) -> bool {
+        self.level % 2 == 1
+    }
+}
+
+impl Default for BidiRun {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A single line of bidirectional text.
+///
+/// `BidiText` implements a subset of the Unicode Bidirectional Algorithm to draw text which
+/// contains a mixture of left to right scripts, like Latin, and right to left scripts, like Hebrew
+/// and Arabic. The text is split into runs of the same direction, which are reordered into
+/// display order before they are passed to [`TextRenderer::draw_string`]. Characters in right to
+/// left runs are drawn in reverse order and paired brackets are mirrored.
+///
+/// Explicit directional formatting characters, character shaping and line breaks aren't
+/// supported.
+///
+/// The layout is stored in a caller provided buffer of [`BidiRun`]s, which must contain at least
+/// one entry for each change of the character type in the text.
+///
+/// The horizontal alignment of the text is relative to the paragraph direction. `Alignment::Left`
+/// aligns the start of the paragraph to the text position, which is the right edge for right to
+/// left paragraphs. If no [`TextStyle`] is used right to left paragraphs are therefore drawn to the
+/// left of the text position. Tabs, soft hyphens and non-breaking spaces are interpreted in the
+/// same way as in [`Text`], with tab stops measured from the left edge of the line in display
+/// order.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     mono_font::{ascii::Font6x9, MonoTextStyle},
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     text::{BidiRun, BidiText, Direction},
+/// };
+/// # let mut display = embedded_graphics::mock_display::MockDisplay::new();
+///
+/// let style = MonoTextStyle::new(Font6x9, BinaryColor::On);
+///
+/// let mut runs = [BidiRun::new(); 8];
+/// let text = BidiText::new("abc (123)", Point::new(60, 6), Direction::RightToLeft, &mut runs)
+///     .expect("buffer too small");
+///
+/// text.into_styled(style).draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`TextRenderer::draw_string`]: trait.TextRenderer.html#tymethod.draw_string
+/// [`BidiRun`]: struct.BidiRun.html
+/// [`Text`]: struct.Text.html
+/// [`TextStyle`]: struct.TextStyle.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BidiText<'a, 'b> {
+    text: &'a str,
+    runs: &'b [BidiRun],
+    right_to_left: bool,
+
+    /// The position.
+    pub position: Point,
+}
+
+impl<'a, 'b> BidiText<'a, 'b> {
+    /// Creates a bidirectional text.
+    ///
+    /// Returns `None` if `buffer` is too small to store the layout.
+    pub fn new(
+        text: &'a str,
+        position: Point,
+        direction: Direction,
+        buffer: &'b mut [BidiRun],
+    ) -> Option<Self> {
+        let right_to_left = match direction {
+            Direction::LeftToRight => false,
+            Direction::RightToLeft => true,
+            Direction::Auto => {
+                text.chars()
+                    .map(Class::of)
+                    .find(|&class| class == Class::Left || class == Class::Right)
+                    == Some(Class::Right)
+            }
+        };
+
+        let len = split_runs(text, buffer)?;
+        let runs = &mut buffer[..len];
+
+        resolve_classes(runs, right_to_left);
+        let len = resolve_levels(runs, right_to_left);
+        let runs = &mut runs[..len];
+        reorder(runs);
+
+        Some(Self {
+            text,
+            runs,
+            right_to_left,
+            position,
+        })
+    }
+
+    /// Returns `true` if the paragraph direction is right to left.
+    pub fn is_right_to_left(&self) -> bool {
+        self.right_to_left
+    }
+
+    /// Attaches a text style to the bidirectional text object.
+    pub fn into_styled<S>(self, style: S) -> Styled<Self, S> {
+        Styled::new(self, style)
+    }
+
+    /// Returns an iterator over the parts of the text in display order.
+    fn pieces(&self) -> impl Iterator<Item = Piece<'a>> + '_ {
+        let text = self.text;
+
+        self.runs.iter().flat_map(move |run| {
+            let run_text = &text[run.start..run.end];
+            let (whole, reversed) = if run.is_right_to_left() {
+                (
+                    None,
+                    Some(run_text.chars().rev().map(|c| Piece::Char(mirror(c)))),
+                )
+            } else {
+                (Some(Piece::Str(run_text)), None)
+            };
+
+            whole.into_iter().chain(reversed.into_iter().flatten())
+        })
+    }
+}
+
+impl Transform for BidiText<'_, '_> {
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            position: self.position + by,
+            ..*self
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.position += by;
+
+        self
+    }
+}
+
+/// Splits the text into runs of characters with the same class.
+///
+/// Returns the number of runs or `None` if the buffer is too small.
+fn split_runs(text: &str, buffer: &mut [BidiRun]) -> Option<usize> {
+    let mut len = 0;
+
+    for (index, c) in text.char_indices() {
+        let class = Class::of(c);
+        let end = index + c.len_utf8();
+
+        if len > 0 && buffer[len - 1].class == class {
+            buffer[len - 1].end = end;
+        } else {
+            *buffer.get_mut(len)? = BidiRun {
+                start: index,
+                end,
+                class,
+                level: 0,
+            };
+            len += 1;
+        }
+    }
+
+    Some(len)
+}
+
+/// Resolves numbers and neutral characters to the direction of the surrounding text.
+fn resolve_classes(runs: &mut [BidiRun], right_to_left: bool) {
+    let paragraph_class = if right_to_left {
+        Class::Right
+    } else {
+        Class::Left
+    };
+
+    // Numbers after left to right text are treated as left to right text.
+    let mut previous_strong = paragraph_class;
+    for run in runs.iter_mut() {
+        match run.class {
+            Class::Left | Class::Right => previous_strong = run.class,
+            Class::Number if previous_strong == Class::Left => run.class = Class::Left,
+            _ => {}
+        }
+    }
+
+    // Neutral characters between text of the same direction are resolved to this direction and
+    // all other neutral characters are resolved to the paragraph direction.
+    for index in 0..runs.len() {
+        if runs[index].class != Class::Neutral {
+            continue;
+        }
+
+        let previous = index
+            .checked_sub(1)
+            .map_or(paragraph_class, |i| runs[i].class.neutral_direction());
+        let next = runs[index + 1..]
+            .iter()
+            .find(|run| run.class != Class::Neutral)
+            .map_or(paragraph_class, |run| run.class.neutral_direction());
+
+        runs[index].class = if previous == next {
+            previous
+        } else {
+            paragraph_class
+        };
+    }
+}
+
+/// Assigns the embedding levels and merges adjacent runs with the same level.
+///
+/// Returns the number of runs after merging.
+fn resolve_levels(runs: &mut [BidiRun], right_to_left: bool) -> usize {
+    let mut len = 0;
+
+    for index in 0..runs.len() {
+        let mut run = runs[index];
+        run.level = match (run.class, right_to_left) {
+            (Class::Left, false) => 0,
+            (Class::Right, _) => 1,
+            _ => 2,
+        };
+
+        if len > 0 && runs[len - 1].level == run.level {
+            runs[len - 1].end = run.end;
+        } else {
+            runs[len] = run;
+            len += 1;
+        }
+    }
+
+    len
+}
+
+/// Reorders the runs from logical into display order.
+fn reorder(runs: &mut [BidiRun]) {
+    let max_level = runs.iter().map(|run| run.level).max().unwrap_or(0);
+
+    // Every sequence of runs at a level greater than or equal to each odd level is reversed.
+    for level in (1..=max_level).rev() {
+        let mut start = 0;
+        while start < runs.len() {
+            if runs[start].level < level {
+                start += 1;
+                continue;
+            }
+
+            let end = runs[start..]
+                .iter()
+                .position(|run| run.level < level)
+                .map_or(runs.len(), |length| start + length);
+
+            runs[start..end].reverse();
+            start = end;
+        }
+    }
+}
+
+/// Returns the mirrored glyph for characters in right to left runs.
+fn mirror(c: char) -> char {
+    match c {
+        '(' => ')',
+        ')' => '(',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '<' => '>',
+        '>' => '<',
+        '«' => '»',
+        '»' => '«',
+        _ => c,
+    }
+}
+
+/// Part of a bidirectional text in display order.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum Piece<'a> {
+    /// String which is drawn left to right.
+    Str(&'a str),
+    /// Single character of a right to left run.
+    Char(char),
+}
+
+impl Piece<'_> {
+    /// Calls `f` with the string representation of this piece.
+    fn with_str<F, T>(self, f: F) -> T
+    where
+        F: FnOnce(&str) -> T,
+    {
+        match self {
+            Piece::Str(text) => f(text),
+            Piece::Char(c) => f(c.encode_utf8(&mut [0; 4])),
+        }
+    }
+}
+
+impl<S> StyledLine<S> for &BidiText<'_, '_> {
+    fn try_for_each_piece<F, E>(&self, style: &S, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&str, &S) -> Result<(), E>,
+    {
+        for piece in self.pieces() {
+            piece.with_str(|text| f(text, style))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl BidiText<'_, '_> {
+    /// Returns the layout with the horizontal alignment relative to the paragraph direction.
+    fn layout<'a>(&self, mut layout: Layout<'a>) -> Layout<'a> {
+        layout.alignment = match (layout.alignment, self.right_to_left) {
+            (Alignment::Left, true) => Alignment::Right,
+            (Alignment::Right, true) => Alignment::Left,
+            (alignment, _) => alignment,
+        };
+
+        layout
+    }
+}
+
+impl<C, S> Drawable for Styled<BidiText<'_, '_>, S>
+where
+    C: PixelColor,
+    S: TextRenderer<Color = C>,
+{
+    type Color = C;
+    type Output = Point;
+
+    fn draw<D>(&self, target: &mut D) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        draw_lines(
+            iter::once(&self.primitive),
+            self.primitive.position,
+            &self.style,
+            self.primitive.layout(Layout::default()),
+            target,
+        )
+    }
+}
+
+impl<C, S> Drawable for Styled<BidiText<'_, '_>, TextStyle<'_, S>>
+where
+    C: PixelColor,
+    S: TextRenderer<Color = C>,
+{
+    type Color = C;
+    type Output = Point;
+
+    fn draw<D>(&self, target: &mut D) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        draw_lines(
+            iter::once(&self.primitive),
+            self.primitive.position,
+            &self.style.character_style,
+            self.primitive.layout(Layout::new(&self.style)),
+            target,
+        )
+    }
+}
+
+impl<C, S> Dimensions for Styled<BidiText<'_, '_>, S>
+where
+    C: PixelColor,
+    S: TextRenderer<Color = C>,
+{
+    fn bounding_box(&self) -> Rectangle {
+        measure_lines(
+            iter::once(&self.primitive),
+            self.primitive.position,
+            &self.style,
+            self.primitive.layout(Layout::default()),
+        )
+        .bounding_box
+    }
+}
+
+impl<C, S> Dimensions for Styled<BidiText<'_, '_>, TextStyle<'_, S>>
+where
+    C: PixelColor,
+    S: TextRenderer<Color = C>,
+{
+    fn bounding_box(&self) -> Rectangle {
+        measure_lines(
+            iter::once(&self.primitive),
+            self.primitive.position,
+            &self.style.character_style,
+            self.primitive.layout(Layout::new(&self.style)),
+        )
+        .bounding_box
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Size,
+        mock_display::MockDisplay,
+        mono_font::{ascii::Font6x9, MonoTextStyle, MonoTextStyleBuilder},
+        pixelcolor::BinaryColor,
+        text::{Baseline, Text, TextStyleBuilder},
+    };
+    use arrayvec::ArrayString;
+
+    /// Returns the text in display order.
+    fn visual(text: &str, direction: Direction) -> ArrayString<[u8; 64]> {
+        let mut runs = [BidiRun::new(); 16];
+        let bidi_text = BidiText::new(text, Point::zero(), direction, &mut runs).unwrap();
+
+        let mut output = ArrayString::new();
+        for piece in bidi_text.pieces() {
+            piece.with_str(|text| output.push_str(text));
+        }
+
+        output
+    }
+
+    #[test]
+    fn left_to_right() {
+        assert_eq!(visual("abc 123", Direction::Auto).as_str(), "abc 123");
+        assert_eq!(
+            visual("abc אבג def", Direction::Auto).as_str(),
+            "abc גבא def"
+        );
+        assert_eq!(
+            visual("abc אבג 123", Direction::Auto).as_str(),
+            "abc 123 גבא"
+        );
+    }
+
+    #[test]
+    fn right_to_left() {
+        assert_eq!(visual("אבג", Direction::Auto).as_str(), "גבא");
+        assert_eq!(visual("אבג 123", Direction::Auto).as_str(), "123 גבא");
+        assert_eq!(
+            visual("אבג abc 123", Direction::Auto).as_str(),
+            "abc 123 גבא"
+        );
+        assert_eq!(
+            visual("abc def", Direction::RightToLeft).as_str(),
+            "abc def"
+        );
+        assert_eq!(visual("abc!", Direction::RightToLeft).as_str(), "!abc");
+    }
+
+    #[test]
+    fn mirrored_brackets() {
+        assert_eq!(visual("א(ב)", Direction::Auto).as_str(), "(ב)א");
+        assert_eq!(visual("a(b)", Direction::Auto).as_str(), "a(b)");
+    }
+
+    #[test]
+    fn paragraph_direction() {
+        let mut runs = [BidiRun::new(); 4];
+
+        assert!(
+            !BidiText::new("12 abc", Point::zero(), Direction::Auto, &mut runs)
+                .unwrap()
+                .is_right_to_left()
+        );
+        assert!(
+            BidiText::new("12 אבג", Point::zero(), Direction::Auto, &mut runs)
+                .unwrap()
+                .is_right_to_left()
+        );
+        assert!(
+            !BidiText::new("!", Point::zero(), Direction::Auto, &mut runs)
+                .unwrap()
+                .is_right_to_left()
+        );
+        assert!(
+            BidiText::new("abc", Point::zero(), Direction::RightToLeft, &mut runs)
+                .unwrap()
+                .is_right_to_left()
+        );
+    }
+
+    #[test]
+    fn buffer_too_small() {
+        let mut runs = [BidiRun::new(); 2];
+
+        assert!(BidiText::new("abc def", Point::zero(), Direction::Auto, &mut runs).is_none());
+        assert!(BidiText::new("abc", Point::zero(), Direction::Auto, &mut runs).is_some());
+        assert!(BidiText::new("", Point::zero(), Direction::Auto, &mut []).is_some());
+    }
+
+    #[test]
+    fn draw_left_to_right() {
+        let style = MonoTextStyleBuilder::new()
+            .font(Font6x9)
+            .text_color(BinaryColor::On)
+            .build();
+
+        let mut runs = [BidiRun::new(); 4];
+        let text = BidiText::new("a(b)", Point::new(1, 6), Direction::Auto, &mut runs)
+            .unwrap()
+            .into_styled(style);
+
+        let mut display = MockDisplay::new();
+        let next = text.draw(&mut display).unwrap();
+
+        let mut expected = MockDisplay::new();
+        let expected_text = Text::new("a(b)", Point::new(1, 6)).into_styled(style);
+        let expected_next = expected_text.draw(&mut expected).unwrap();
+
+        display.assert_eq(&expected);
+        assert_eq!(next, expected_next);
+        assert_eq!(text.bounding_box(), expected_text.bounding_box());
+    }
+
+    #[test]
+    fn draw_right_to_left() {
+        let style = MonoTextStyle::new(Font6x9, BinaryColor::On);
+
+        let mut runs = [BidiRun::new(); 4];
+        let text = BidiText::new("ab(", Point::new(30, 6), Direction::RightToLeft, &mut runs)
+            .unwrap()
+            .into_styled(style);
+
+        let mut display = MockDisplay::new();
+        text.draw(&mut display).unwrap();
+
+        // Right to left paragraphs are drawn to the left of the position.
+        let mut expected = MockDisplay::new();
+        let expected_text = Text::new(")ab", Point::new(30 - 17, 6)).into_styled(style);
+        expected_text.draw(&mut expected).unwrap();
+
+        display.assert_eq(&expected);
+        assert_eq!(text.bounding_box(), expected_text.bounding_box());
+    }
+
+    #[test]
+    fn alignment_is_relative_to_paragraph_direction() {
+        for &(alignment, offset) in &[
+            (Alignment::Left, -23),
+            (Alignment::Center, -11),
+            (Alignment::Right, 0),
+        ] {
+            let character_style = MonoTextStyle::new(Font6x9, BinaryColor::On);
+            let text_style = TextStyleBuilder::new()
+                .character_style(character_style)
+                .alignment(alignment)
+                .baseline(Baseline::Top)
+                .build();
+
+            let mut runs = [BidiRun::new(); 4];
+            let text = BidiText::new("abcd", Point::new(30, 0), Direction::RightToLeft, &mut runs)
+                .unwrap()
+                .into_styled(text_style);
+
+            assert_eq!(
+                text.bounding_box(),
+                Rectangle::new(Point::new(30 + offset, 0), Size::new(24, 9)),
+                "{:?}",
+                alignment
+            );
+        }
+    }
+}
@@ -0,0 +1,522 @@
+//! Shared layout of text drawables.
+//!
+//! All text drawables in this module position their lines and interpret whitespace characters in
+//! the same way. The drawables only provide the pieces of each line by implementing
+//! [`StyledLine`] and the functions in this module apply the settings of the [`TextStyle`].
+
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    primitives::Rectangle,
+    text::{
+        Alignment, Baseline, LineHeight, TabStops, TextMetrics, TextRenderer, TextStyle,
+        VerticalAlignment,
+    },
+    SaturatingCast,
+};
+use core::convert::Infallible;
+
+/// Layout settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Layout<'a> {
+    pub alignment: Alignment,
+    pub baseline: Baseline,
+    pub vertical_alignment: VerticalAlignment,
+    pub line_height: LineHeight,
+    pub tab_stops: TabStops<'a>,
+}
+
+impl<'a> Layout<'a> {
+    /// Returns the layout settings of a text style.
+    pub fn new<S>(style: &TextStyle<'a, S>) -> Self {
+        Self {
+            alignment: style.alignment,
+            baseline: style.baseline,
+            vertical_alignment: style.vertical_alignment,
+            line_height: style.line_height,
+            tab_stops: style.tab_stops,
+        }
+    }
+}
+
+impl Default for Layout<'_> {
+    /// Returns the layout settings which are used if no `TextStyle` is attached to a text.
+    fn default() -> Self {
+        Self {
+            alignment: Alignment::Left,
+            baseline: Baseline::Alphabetic,
+            vertical_alignment: VerticalAlignment::Top,
+            line_height: LineHeight::default(),
+            tab_stops: TabStops::default(),
+        }
+    }
+}
+
+/// A single line of text.
+///
+/// A line consists of pieces of text which can use different character styles.
+pub(super) trait StyledLine<S> {
+    /// Calls `f` with the string and character style of each piece in this line.
+    ///
+    /// `style` is the character style attached to the text.
+    fn try_for_each_piece<F, E>(&self, style: &S, f: F) -> Result<(), E>
+    where
+        F: FnMut(&str, &S) -> Result<(), E>;
+}
+
+impl<S> StyledLine<S> for &str {
+    fn try_for_each_piece<F, E>(&self, style: &S, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&str, &S) -> Result<(), E>,
+    {
+        f(self, style)
+    }
+}
+
+impl<S> StyledLine<S> for &[&str] {
+    fn try_for_each_piece<F, E>(&self, style: &S, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&str, &S) -> Result<(), E>,
+    {
+        self.iter().try_for_each(|piece| f(piece, style))
+    }
+}
+
+/// Draws lines of text.
+///
+/// Returns the position of the next character after the last line.
+pub(super) fn draw_lines<S, I, D>(
+    lines: I,
+    position: Point,
+    renderer: &S,
+    layout: Layout<'_>,
+    target: &mut D,
+) -> Result<Point, D::Error>
+where
+    S: TextRenderer,
+    I: IntoIterator,
+    I::IntoIter: Clone,
+    I::Item: StyledLine<S>,
+    D: DrawTarget<Color = S::Color>,
+{
+    let mut next_position = position;
+
+    for (line, line_start) in line_positions(lines, position, renderer, layout) {
+        next_position = line_start;
+        let mut is_line_empty = true;
+
+        line.try_for_each_piece(renderer, |text, style| {
+            next_position = draw_tokens(
+                style,
+                text,
+                line_start,
+                next_position,
+                &mut is_line_empty,
+                layout.baseline,
+                layout.tab_stops,
+                target,
+            )?;
+
+            Ok(())
+        })?;
+    }
+
+    Ok(next_position)
+}
+
+/// Returns the text metrics of lines of text.
+pub(super) fn measure_lines<S, I>(
+    lines: I,
+    position: Point,
+    renderer: &S,
+    layout: Layout<'_>,
+) -> TextMetrics
+where
+    S: TextRenderer,
+    I: IntoIterator,
+    I::IntoIter: Clone,
+    I::Item: StyledLine<S>,
+{
+    let mut min_max: Option<(Point, Point)> = None;
+    let mut next_position = position;
+
+    for (line, line_start) in line_positions(lines, position, renderer, layout) {
+        let metrics = measure_line(&line, renderer, line_start, layout);
+        update_min_max(&mut min_max, &metrics);
+
+        next_position = metrics.next_position;
+    }
+
+    TextMetrics {
+        bounding_box: bounding_box(min_max, position),
+        next_position,
+    }
+}
+
+/// Returns an iterator over the lines and the start position of each line.
+///
+/// The start positions include the offsets for the horizontal and vertical alignment.
+fn line_positions<'a, S, I>(
+    lines: I,
+    position: Point,
+    renderer: &'a S,
+    layout: Layout<'a>,
+) -> impl Iterator<Item = (I::Item, Point)> + 'a
+where
+    S: TextRenderer,
+    I: IntoIterator,
+    I::IntoIter: Clone + 'a,
+    I::Item: StyledLine<S>,
+{
+    let lines = lines.into_iter();
+
+    let line_height: i32 = layout
+        .line_height
+        .to_absolute(renderer.line_height())
+        .saturating_cast();
+
+    let line_count = lines.clone().count() as i32;
+    let block_height = line_count.saturating_sub(1).saturating_mul(line_height);
+
+    let mut position = position;
+    position.y -= match layout.vertical_alignment {
+        VerticalAlignment::Top => 0,
+        VerticalAlignment::Middle => block_height / 2,
+        VerticalAlignment::Bottom => block_height,
+    };
+
+    lines.map(move |line| {
+        let line_start = match layout.alignment {
+            Alignment::Left => position,
+            Alignment::Center | Alignment::Right => {
+                let advance = measure_line(&line, renderer, Point::zero(), layout).next_position;
+                let offset = last_pixel_offset(advance);
+
+                if layout.alignment == Alignment::Center {
+                    position - offset / 2
+                } else {
+                    position - offset
+                }
+            }
+        };
+
+        position.y += line_height;
+
+        (line, line_start)
+    })
+}
+
+/// Returns the text metrics of a single line.
+fn measure_line<S, L>(line: &L, renderer: &S, line_start: Point, layout: Layout<'_>) -> TextMetrics
+where
+    S: TextRenderer,
+    L: StyledLine<S>,
+{
+    let mut min_max: Option<(Point, Point)> = None;
+    let mut next_position = line_start;
+    let mut is_line_empty = true;
+
+    line.try_for_each_piece(renderer, |text, style| {
+        let metrics = measure_tokens(
+            style,
+            text,
+            line_start,
+            next_position,
+            &mut is_line_empty,
+            layout.baseline,
+            layout.tab_stops,
+        );
+        update_min_max(&mut min_max, &metrics);
+
+        next_position = metrics.next_position;
+
+        Ok::<_, Infallible>(())
+    })
+    .unwrap_or_else(|error| match error {});
+
+    TextMetrics {
+        bounding_box: bounding_box(min_max, line_start),
+        next_position,
+    }
+}
+
+/// Returns the offset of the last pixel in a line from the line start.
+///
+/// `advance` is the next position of the line measured at the origin. The advance is vertical
+/// for rotated text.
+fn last_pixel_offset(advance: Point) -> Point {
+    advance - Point::new(advance.x.signum(), advance.y.signum())
+}
+
+/// Returns the distance between two points on the same line.
+pub(crate) fn distance(start: Point, end: Point) -> i32 {
+    let delta = end - start;
+
+    delta.x.abs() + delta.y.abs()
+}
+
+/// Soft hyphen.
+const SOFT_HYPHEN: char = '\u{ad}';
+
+/// Non-breaking space.
+const NO_BREAK_SPACE: char = '\u{a0}';
+
+/// Part of a line of text.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Token<'a> {
+    /// String without control characters.
+    Text(&'a str),
+    /// Tab.
+    Tab,
+    /// Non-breaking space.
+    NoBreakSpace,
+}
+
+/// Iterator over the tokens in a line of text.
+///
+/// Soft hyphens are removed, because text drawables don't break lines and a soft hyphen is only
+/// visible at a line break.
+#[derive(Debug)]
+struct Tokens<'a> {
+    text: Option<&'a str>,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(line: &'a str) -> Self {
+        Self { text: Some(line) }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let text = self.text?;
+
+            let index = match text.find(&['\t', SOFT_HYPHEN, NO_BREAK_SPACE][..]) {
+                Some(index) => index,
+                None if text.is_empty() => return None,
+                None => {
+                    self.text = None;
+                    return Some(Token::Text(text));
+                }
+            };
+
+            if index > 0 {
+                self.text = Some(&text[index..]);
+                return Some(Token::Text(&text[..index]));
+            }
+
+            let c = text.chars().next().unwrap();
+            self.text = Some(&text[c.len_utf8()..]);
+
+            match c {
+                '\t' => return Some(Token::Tab),
+                NO_BREAK_SPACE => return Some(Token::NoBreakSpace),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Returns the direction in which the renderer advances the position.
+///
+/// The direction is a unit vector along one of the axes, which is `+x` for horizontal text.
+fn direction<S: TextRenderer>(renderer: &S) -> Point {
+    let advance = renderer
+        .measure_string(" ", Point::zero(), Baseline::Top)
+        .next_position;
+
+    if advance.y != 0 && advance.x == 0 {
+        Point::new(0, advance.y.signum())
+    } else if advance.x < 0 {
+        Point::new(-1, 0)
+    } else {
+        Point::new(1, 0)
+    }
+}
+
+/// Returns the length of the advance of a string along the text direction.
+fn advance<S: TextRenderer>(renderer: &S, text: &str) -> i32 {
+    let metrics = renderer.measure_string(text, Point::zero(), Baseline::Top);
+
+    distance(Point::zero(), metrics.next_position)
+}
+
+/// Returns the width of a space character.
+fn space_width<S: TextRenderer>(renderer: &S) -> u32 {
+    advance(renderer, " ").max(0) as u32
+}
+
+/// Returns the spacing between two adjacent characters.
+///
+/// The spacing isn't part of the result of `measure_string` for a single string and must be
+/// added between tokens which are drawn separately.
+fn character_spacing<S: TextRenderer>(renderer: &S) -> u32 {
+    (advance(renderer, "  ") - 2 * advance(renderer, " ")).max(0) as u32
+}
+
+/// Returns the bounding box of a whitespace token.
+///
+/// The extent perpendicular to the text direction is the same as for a space character.
+fn whitespace_bounding_box(space: Rectangle, direction: Point, width: u32) -> Rectangle {
+    let mut bounding_box = space;
+
+    if direction.x != 0 {
+        bounding_box.size.width = width;
+        if direction.x < 0 {
+            bounding_box.top_left.x += space.size.width.saturating_cast() - width.saturating_cast();
+        }
+    } else {
+        bounding_box.size.height = width;
+        if direction.y < 0 {
+            bounding_box.top_left.y +=
+                space.size.height.saturating_cast() - width.saturating_cast();
+        }
+    }
+
+    bounding_box
+}
+
+/// Returns the width of a whitespace token at the given offset from the start of the line.
+fn whitespace_width(
+    token: Token<'_>,
+    offset: i32,
+    space_width: u32,
+    tab_stops: TabStops<'_>,
+) -> u32 {
+    match token {
+        Token::Tab => tab_stops.distance_to_next(offset.max(0) as u32, space_width),
+        _ => space_width,
+    }
+}
+
+/// Draws a string and interprets tabs, soft hyphens and non-breaking spaces.
+///
+/// Tab stops are relative to `line_start`. The character spacing is inserted before each token
+/// unless `is_line_empty` is `true`, which is reset after the first token was drawn.
+#[allow(clippy::too_many_arguments)]
+fn draw_tokens<S, D>(
+    renderer: &S,
+    text: &str,
+    line_start: Point,
+    position: Point,
+    is_line_empty: &mut bool,
+    baseline: Baseline,
+    tab_stops: TabStops<'_>,
+    target: &mut D,
+) -> Result<Point, D::Error>
+where
+    S: TextRenderer,
+    D: DrawTarget<Color = S::Color>,
+{
+    let space_width = space_width(renderer);
+    let character_spacing = character_spacing(renderer);
+    let mut next_position = position;
+
+    for token in Tokens::new(text) {
+        if !*is_line_empty && character_spacing > 0 {
+            next_position =
+                renderer.draw_whitespace(character_spacing, next_position, baseline, target)?;
+        }
+        *is_line_empty = false;
+
+        next_position = match token {
+            Token::Text(text) => renderer.draw_string(text, next_position, baseline, target)?,
+            _ => {
+                let width = whitespace_width(
+                    token,
+                    distance(line_start, next_position),
+                    space_width,
+                    tab_stops,
+                );
+
+                renderer.draw_whitespace(width, next_position, baseline, target)?
+            }
+        };
+    }
+
+    Ok(next_position)
+}
+
+/// Returns the text metrics for a string.
+///
+/// Tabs, soft hyphens and non-breaking spaces are interpreted in the same way as in
+/// `draw_tokens`.
+fn measure_tokens<S>(
+    renderer: &S,
+    text: &str,
+    line_start: Point,
+    position: Point,
+    is_line_empty: &mut bool,
+    baseline: Baseline,
+    tab_stops: TabStops<'_>,
+) -> TextMetrics
+where
+    S: TextRenderer,
+{
+    let space_width = space_width(renderer);
+    let character_spacing = character_spacing(renderer);
+    let direction = direction(renderer);
+    let mut next_position = position;
+    let mut min_max: Option<(Point, Point)> = None;
+
+    for token in Tokens::new(text) {
+        if !*is_line_empty {
+            next_position += direction * character_spacing.saturating_cast();
+        }
+        *is_line_empty = false;
+
+        let metrics = match token {
+            Token::Text(text) => renderer.measure_string(text, next_position, baseline),
+            _ => {
+                let width = whitespace_width(
+                    token,
+                    distance(line_start, next_position),
+                    space_width,
+                    tab_stops,
+                );
+
+                let space = renderer.measure_string(" ", next_position, baseline);
+
+                TextMetrics {
+                    bounding_box: whitespace_bounding_box(space.bounding_box, direction, width),
+                    next_position: next_position + direction * width.saturating_cast(),
+                }
+            }
+        };
+
+        update_min_max(&mut min_max, &metrics);
+        next_position = metrics.next_position;
+    }
+
+    TextMetrics {
+        bounding_box: bounding_box(min_max, position),
+        next_position,
+    }
+}
+
+fn update_min_max(min_max: &mut Option<(Point, Point)>, metrics: &TextMetrics) {
+    if let Some(bottom_right) = metrics.bounding_box.bottom_right() {
+        if let Some((min, max)) = min_max {
+            min.x = min.x.min(metrics.bounding_box.top_left.x);
+            min.y = min.y.min(metrics.bounding_box.top_left.y);
+            max.x = max.x.max(bottom_right.x);
+            max.y = max.y.max(bottom_right.y);
+        } else {
+            *min_max = Some((metrics.bounding_box.top_left, bottom_right));
+        }
+    }
+}
+
+/// Returns the bounding box for the result of `update_min_max`.
+///
+/// A zero sized bounding box at `position` is returned if nothing was measured.
+fn bounding_box(min_max: Option<(Point, Point)>, position: Point) -> Rectangle {
+    if let Some((min, max)) = min_max {
+        Rectangle::with_corners(min, max)
+    } else {
+        Rectangle::new(position, Size::zero())
+    }
+}
@@ -12,11 +12,23 @@
 //!         A text renderer example might be too complicated for the docs and the target audience
 //!         is relatively small, so that a link to an external example could be a better idea.
 
+mod ansi_text;
+mod bidi_text;
+mod layout;
+mod rich_text;
+mod tab_stops;
 mod text;
 mod text_style;
+mod truncated_text;
 
+pub use ansi_text::AnsiText;
+pub use bidi_text::{BidiRun, BidiText, Direction};
 pub use embedded_graphics_core::text::{
-    Alignment, Baseline, CharacterStyle, DecorationColor, TextMetrics, TextRenderer,
+    Alignment, Baseline, CharacterStyle, DecorationColor, LineHeight, TextMetrics, TextRenderer,
+    VerticalAlignment,
 };
+pub use rich_text::{RichText, Span};
+pub use tab_stops::TabStops;
 pub use text::Text;
 pub use text_style::{TextStyle, TextStyleBuilder};
+pub use truncated_text::{TruncatedText, Truncation};
@@ -0,0 +1,619 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point},
+    pixelcolor::PixelColor,
+    primitives::Rectangle,
+    text::{
+        layout::{draw_lines, measure_lines, Layout, StyledLine},
+        CharacterStyle, DecorationColor, TextRenderer, TextStyle,
+    },
+    transform::Transform,
+    Drawable, Styled,
+};
+
+/// A span of rich text.
+///
+/// A span contains a string and optional overrides for the character style properties. All
+/// properties which aren't overridden are taken from the character style of the [`RichText`] the
+/// span is drawn as part of.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{pixelcolor::Rgb565, prelude::*, text::Span};
+///
+/// let normal: Span<Rgb565> = Span::new("normal ");
+/// let highlighted = Span::new("highlighted")
+///     .text_color(Rgb565::RED)
+///     .underline();
+/// ```
+///
+/// [`RichText`]: struct.RichText.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Span<'a, C> {
+    /// The string.
+    pub text: &'a str,
+
+    /// Text color override.
+    pub text_color: Option<C>,
+
+    /// Background color override.
+    pub background_color: Option<C>,
+
+    /// Underline color override.
+    pub underline_color: Option<DecorationColor<C>>,
+
+    /// Strikethrough color override.
+    pub strikethrough_color: Option<DecorationColor<C>>,
+}
+
+impl<'a, C> Span<'a, C>
+where
+    C: PixelColor,
+{
+    /// Creates a span without any style overrides.
+    pub const fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            text_color: None,
+            background_color: None,
+            underline_color: None,
+            strikethrough_color: None,
+        }
+    }
+
+    /// Overrides the text color.
+    pub fn text_color(mut self, text_color: C) -> Self {
+        self.text_color = Some(text_color);
+
+        self
+    }
+
+    /// Overrides the background color.
+    pub fn background_color(mut self, background_color: C) -> Self {
+        self.background_color = Some(background_color);
+
+        self
+    }
+
+    /// Enables underline using the text color.
+    pub fn underline(mut self) -> Self {
+        self.underline_color = Some(DecorationColor::TextColor);
+
+        self
+    }
+
+    /// Enables underline with a custom color.
+    pub fn underline_with_color(mut self, underline_color: C) -> Self {
+        self.underline_color = Some(DecorationColor::Custom(underline_color));
+
+        self
+    }
+
+    /// Enables strikethrough using the text color.
+    pub fn strikethrough(mut self) -> Self {
+        self.strikethrough_color = Some(DecorationColor::TextColor);
+
+        self
+    }
+
+    /// Enables strikethrough with a custom color.
+    pub fn strikethrough_with_color(mut self, strikethrough_color: C) -> Self {
+        self.strikethrough_color = Some(DecorationColor::Custom(strikethrough_color));
+
+        self
+    }
+
+    /// Applies the overrides in this span to a character style.
+    pub(super) fn apply<S>(&self, style: &mut S)
+    where
+        S: CharacterStyle<Color = C>,
+    {
+        if let Some(text_color) = self.text_color {
+            style.set_text_color(Some(text_color));
+        }
+
+        if let Some(background_color) = self.background_color {
+            style.set_background_color(Some(background_color));
+        }
+
+        if let Some(underline_color) = self.underline_color {
+            style.set_underline_color(underline_color);
+        }
+
+        if let Some(strikethrough_color) = self.strikethrough_color {
+            style.set_strikethrough_color(strikethrough_color);
+        }
+    }
+}
+
+/// Text with multiple character styles.
+///
+/// A `RichText` object consists of a sequence of [`Span`]s, which are drawn after each other on
+/// the same baseline. The character style attached to the rich text by using [`into_styled`] is
+/// used as the base style for all spans.
+///
+/// Spans can contain newline characters to draw multiple lines of text. The lines are laid out in
+/// the same way as the lines of a [`Text`]: if a [`TextStyle`] is used, each line is aligned
+/// separately and the vertical alignment, line height and tab stops of the text style are
+/// applied. The line height is relative to the line height of the base character style.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     mono_font::{ascii::Font6x9, MonoTextStyle},
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     text::{RichText, Span},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+/// # display.set_allow_out_of_bounds_drawing(true);
+///
+/// let spans = [
+///     Span::new("Temperature: "),
+///     Span::new("42 °C").text_color(Rgb565::RED).underline(),
+/// ];
+///
+/// RichText::new(&spans, Point::new(5, 10))
+///     .into_styled(MonoTextStyle::new(Font6x9, Rgb565::WHITE))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Span`]: struct.Span.html
+/// [`Text`]: struct.Text.html
+/// [`TextStyle`]: struct.TextStyle.html
+/// [`into_styled`]: #method.into_styled
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct RichText<'a, 'b, C> {
+    /// The spans.
+    pub spans: &'b [Span<'a, C>],
+
+    /// The position.
+    pub position: Point,
+}
+
+impl<'a, 'b, C> RichText<'a, 'b, C> {
+    /// Creates a rich text.
+    pub const fn new(spans: &'b [Span<'a, C>], position: Point) -> Self {
+        Self { spans, position }
+    }
+
+    /// Attaches a text style to the rich text object.
+    pub fn into_styled<S>(self, style: S) -> Styled<Self, S> {
+        Styled::new(self, style)
+    }
+
+    /// Returns an iterator over the lines.
+    fn lines(&self) -> Lines<'a, 'b, C> {
+        Lines {
+            spans: self.spans,
+            offset: 0,
+        }
+    }
+}
+
+impl<C> Transform for RichText<'_, '_, C>
+where
+    C: Copy,
+{
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            position: self.position + by,
+            ..*self
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.position += by;
+
+        self
+    }
+}
+
+/// A single line of rich text.
+#[derive(Debug)]
+struct Line<'a, 'b, C> {
+    /// Spans which are part of this line.
+    spans: &'b [Span<'a, C>],
+
+    /// Start offset in the first span.
+    start: usize,
+
+    /// End offset in the last span.
+    end: usize,
+}
+
+impl<'a, 'b, C> Line<'a, 'b, C> {
+    /// Returns the parts of all spans in this line.
+    fn segments(&self) -> impl Iterator<Item = (&'a str, &'b Span<'a, C>)> + '_ {
+        let last = self.spans.len().saturating_sub(1);
+
+        self.spans.iter().enumerate().map(move |(index, span)| {
+            let start = if index == 0 { self.start } else { 0 };
+            let end = if index == last {
+                self.end
+            } else {
+                span.text.len()
+            };
+
+            (&span.text[start..end], span)
+        })
+    }
+}
+
+/// Iterator over the lines in a sequence of spans.
+#[derive(Debug, Clone)]
+struct Lines<'a, 'b, C> {
+    /// Remaining spans.
+    spans: &'b [Span<'a, C>],
+
+    /// Offset of the remaining text in the first span.
+    offset: usize,
+}
+
+impl<'a, 'b, C> Iterator for Lines<'a, 'b, C> {
+    type Item = Line<'a, 'b, C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Like `str::lines`, no trailing empty line is returned.
+        let mut remaining = self.spans.iter().enumerate().map(|(index, span)| {
+            if index == 0 {
+                span.text.len().saturating_sub(self.offset)
+            } else {
+                span.text.len()
+            }
+        });
+        if remaining.all(|length| length == 0) {
+            return None;
+        }
+
+        let start = self.offset;
+
+        for (index, span) in self.spans.iter().enumerate() {
+            let offset = if index == 0 { start } else { 0 };
+
+            if let Some(newline) = span.text[offset..].find('\n') {
+                let end = offset + newline;
+                let line = Line {
+                    spans: &self.spans[..=index],
+                    start,
+                    end,
+                };
+
+                self.spans = &self.spans[index..];
+                self.offset = end + 1;
+
+                return Some(line);
+            }
+        }
+
+        let line = Line {
+            spans: self.spans,
+            start,
+            end: self.spans.last().map_or(0, |span| span.text.len()),
+        };
+
+        self.spans = &[];
+        self.offset = 0;
+
+        Some(line)
+    }
+}
+
+impl<C, S> StyledLine<S> for Line<'_, '_, C>
+where
+    C: PixelColor,
+    S: CharacterStyle<Color = C>,
+{
+    fn try_for_each_piece<F, E>(&self, style: &S, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&str, &S) -> Result<(), E>,
+    {
+        for (text, span) in self.segments() {
+            let mut span_style = style.clone();
+            span.apply(&mut span_style);
+
+            f(text, &span_style)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C, S> Drawable for Styled<RichText<'_, '_, C>, S>
+where
+    C: PixelColor,
+    S: TextRenderer<Color = C> + CharacterStyle<Color = C>,
+{
+    type Color = C;
+    type Output = Point;
+
+    fn draw<D>(&self, target: &mut D) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        draw_lines(
+            self.primitive.lines(),
+            self.primitive.position,
+            &self.style,
+            Layout::default(),
+            target,
+        )
+    }
+}
+
+impl<C, S> Drawable for Styled<RichText<'_, '_, C>, TextStyle<'_, S>>
+where
+    C: PixelColor,
+    S: TextRenderer<Color = C> + CharacterStyle<Color = C>,
+{
+    type Color = C;
+    type Output = Point;
+
+    fn draw<D>(&self, target: &mut D) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        draw_lines(
+            self.primitive.lines(),
+            self.primitive.position,
+            &self.style.character_style,
+            Layout::new(&self.style),
+            target,
+        )
+    }
+}
+
+impl<C, S> Dimensions for Styled<RichText<'_, '_, C>, S>
+where
+    C: PixelColor,
+    S: TextRenderer<Color = C> + CharacterStyle<Color = C>,
+{
+    fn bounding_box(&self) -> Rectangle {
+        measure_lines(
+            self.primitive.lines(),
+            self.primitive.position,
+            &self.style,
+            Layout::default(),
+        )
+        .bounding_box
+    }
+}
+
+impl<C, S> Dimensions for Styled<RichText<'_, '_, C>, TextStyle<'_, S>>
+where
+    C: PixelColor,
+    S: TextRenderer<Color = C> + CharacterStyle<Color = C>,
+{
+    fn bounding_box(&self) -> Rectangle {
+        measure_lines(
+            self.primitive.lines(),
+            self.primitive.position,
+            &self.style.character_style,
+            Layout::new(&self.style),
+        )
+        .bounding_box
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock_display::MockDisplay,
+        mono_font::{ascii::Font6x9, MonoTextStyle, MonoTextStyleBuilder},
+        pixelcolor::{BinaryColor, Rgb888, RgbColor},
+        text::{
+            Alignment, Baseline, LineHeight, TabStops, Text, TextStyleBuilder, VerticalAlignment,
+        },
+    };
+    use arrayvec::ArrayVec;
+
+    /// Returns the line index and text of all line segments.
+    fn segments<'a>(spans: &[Span<'a, BinaryColor>]) -> ArrayVec<[(usize, &'a str); 16]> {
+        RichText::new(spans, Point::zero())
+            .lines()
+            .enumerate()
+            .flat_map(|(index, line)| {
+                line.segments()
+                    .map(|(text, _)| (index, text))
+                    .collect::<ArrayVec<[_; 16]>>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn lines() {
+        let spans = [
+            Span::new("ab"),
+            Span::new("c\nd"),
+            Span::new("\n"),
+            Span::new("\ne"),
+        ];
+
+        assert_eq!(
+            segments(&spans).as_slice(),
+            &[
+                (0, "ab"),
+                (0, "c"),
+                (1, "d"),
+                (1, ""),
+                (2, ""),
+                (2, ""),
+                (3, "e")
+            ]
+        );
+    }
+
+    #[test]
+    fn lines_trailing_newline() {
+        assert_eq!(segments(&[Span::new("a\n")]).as_slice(), &[(0, "a")]);
+        assert_eq!(
+            segments(&[Span::new("a\n"), Span::new("")]).as_slice(),
+            &[(0, "a")]
+        );
+        assert!(segments(&[]).is_empty());
+        assert!(segments(&[Span::new("")]).is_empty());
+    }
+
+    #[test]
+    fn matches_chained_text() {
+        let style = MonoTextStyle::new(Font6x9, Rgb888::WHITE);
+        let red = MonoTextStyleBuilder::from(&style)
+            .text_color(Rgb888::RED)
+            .underline()
+            .build();
+        let green = MonoTextStyleBuilder::from(&style)
+            .text_color(Rgb888::GREEN)
+            .background_color(Rgb888::BLUE)
+            .build();
+
+        let mut expected = MockDisplay::new();
+        let next = Text::new("A", Point::new(0, 6))
+            .into_styled(style)
+            .draw(&mut expected)
+            .unwrap();
+        let next = Text::new("BC", next)
+            .into_styled(red)
+            .draw(&mut expected)
+            .unwrap();
+        let expected_next = Text::new("D", next)
+            .into_styled(green)
+            .draw(&mut expected)
+            .unwrap();
+
+        let spans = [
+            Span::new("A"),
+            Span::new("BC").text_color(Rgb888::RED).underline(),
+            Span::new("D")
+                .text_color(Rgb888::GREEN)
+                .background_color(Rgb888::BLUE),
+        ];
+        let rich_text = RichText::new(&spans, Point::new(0, 6)).into_styled(style);
+
+        let mut display = MockDisplay::new();
+        let next = rich_text.draw(&mut display).unwrap();
+
+        display.assert_eq(&expected);
+        assert_eq!(next, expected_next);
+        assert_eq!(rich_text.bounding_box(), expected.affected_area());
+    }
+
+    #[test]
+    fn multiline_alignment() {
+        for &alignment in &[Alignment::Left, Alignment::Center, Alignment::Right] {
+            let character_style = MonoTextStyleBuilder::new()
+                .font(Font6x9)
+                .text_color(BinaryColor::On)
+                .background_color(BinaryColor::Off)
+                .build();
+
+            let text_style = TextStyleBuilder::new()
+                .character_style(character_style)
+                .alignment(alignment)
+                .baseline(Baseline::Top)
+                .build();
+
+            let spans = [
+                Span::new("A\nB"),
+                Span::new("C").text_color(BinaryColor::Off),
+            ];
+            let rich_text = RichText::new(&spans, Point::new(20, 0)).into_styled(text_style);
+
+            let mut display = MockDisplay::new();
+            rich_text.draw(&mut display).unwrap();
+
+            // Drawing the same text without the color override must cover the same area.
+            let mut expected = MockDisplay::new();
+            Text::new("A\nBC", Point::new(20, 0))
+                .into_styled(text_style)
+                .draw(&mut expected)
+                .unwrap();
+
+            assert_eq!(
+                display.affected_area(),
+                expected.affected_area(),
+                "{:?}",
+                alignment
+            );
+            assert_eq!(
+                rich_text.bounding_box(),
+                display.affected_area(),
+                "{:?}",
+                alignment
+            );
+        }
+    }
+
+    #[test]
+    fn text_style_layout() {
+        let character_style = MonoTextStyle::new(Font6x9, BinaryColor::On);
+
+        let text_style = TextStyleBuilder::new()
+            .character_style(character_style)
+            .alignment(Alignment::Center)
+            .baseline(Baseline::Top)
+            .vertical_alignment(VerticalAlignment::Bottom)
+            .line_height(LineHeight::Pixels(12))
+            .tab_stops(TabStops::Pixels(10))
+            .build();
+
+        let spans = [Span::new("A\tB"), Span::new("\nC"), Span::new("D")];
+        let rich_text = RichText::new(&spans, Point::new(20, 20)).into_styled(text_style);
+
+        let mut display = MockDisplay::new();
+        let next = rich_text.draw(&mut display).unwrap();
+
+        let text = Text::new("A\tB\nCD", Point::new(20, 20)).into_styled(text_style);
+        let mut expected = MockDisplay::new();
+        let expected_next = text.draw(&mut expected).unwrap();
+
+        display.assert_eq(&expected);
+        assert_eq!(next, expected_next);
+        assert_eq!(rich_text.bounding_box(), text.bounding_box());
+    }
+
+    #[test]
+    fn span_overrides() {
+        let style = MonoTextStyleBuilder::new()
+            .font(Font6x9)
+            .text_color(BinaryColor::On)
+            .build();
+
+        let spans = [
+            Span::new("A").strikethrough_with_color(BinaryColor::Off),
+            Span::new(" "),
+        ];
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        RichText::new(&spans, Point::new(0, 6))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "      ", //
+            "  #   ", //
+            " # #  ", //
+            "#   # ", //
+            "......", //
+            "#   # ", //
+            "#   # ", //
+        ]);
+    }
+
+    #[test]
+    fn translate() {
+        let spans = [Span::<BinaryColor>::new("A")];
+        let rich_text = RichText::new(&spans, Point::new(1, 2));
+
+        assert_eq!(
+            rich_text.translate(Point::new(3, 4)).position,
+            Point::new(4, 6)
+        );
+    }
+}
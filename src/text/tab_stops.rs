@@ -0,0 +1,84 @@
+/// Tab stops.
+///
+/// Tab stops define the positions to which a tab character (`\t`) advances the text. All
+/// positions are measured in pixels relative to the start of the line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TabStops<'a> {
+    /// Tab stops at every multiple of the width of the given number of space characters.
+    Spaces(u32),
+
+    /// Tab stops at every multiple of the given width in pixels.
+    Pixels(u32),
+
+    /// Tab stops at explicit positions in pixels.
+    ///
+    /// The positions must be sorted in ascending order. Tab characters after the last stop
+    /// position advance the text by the width of a single space character.
+    Positions(&'a [u32]),
+}
+
+impl TabStops<'_> {
+    /// Returns the distance from `offset` to the next tab stop.
+    ///
+    /// `offset` is the current position relative to the start of the line and `space_width` is
+    /// the width of a space character in the used character style.
+    pub fn distance_to_next(self, offset: u32, space_width: u32) -> u32 {
+        let width = match self {
+            TabStops::Spaces(spaces) => spaces.saturating_mul(space_width),
+            TabStops::Pixels(width) => width,
+            TabStops::Positions(positions) => {
+                return positions
+                    .iter()
+                    .find(|&&stop| stop > offset)
+                    .map_or(space_width, |stop| stop - offset);
+            }
+        };
+
+        if width == 0 {
+            return 0;
+        }
+
+        width - offset % width
+    }
+}
+
+impl Default for TabStops<'_> {
+    fn default() -> Self {
+        TabStops::Spaces(4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spaces() {
+        let tab_stops = TabStops::Spaces(4);
+
+        assert_eq!(tab_stops.distance_to_next(0, 6), 24);
+        assert_eq!(tab_stops.distance_to_next(6, 6), 18);
+        assert_eq!(tab_stops.distance_to_next(23, 6), 1);
+        assert_eq!(tab_stops.distance_to_next(24, 6), 24);
+    }
+
+    #[test]
+    fn pixels() {
+        let tab_stops = TabStops::Pixels(10);
+
+        assert_eq!(tab_stops.distance_to_next(0, 6), 10);
+        assert_eq!(tab_stops.distance_to_next(15, 6), 5);
+        assert_eq!(TabStops::Pixels(0).distance_to_next(15, 6), 0);
+    }
+
+    #[test]
+    fn positions() {
+        let tab_stops = TabStops::Positions(&[10, 30]);
+
+        assert_eq!(tab_stops.distance_to_next(0, 6), 10);
+        assert_eq!(tab_stops.distance_to_next(10, 6), 20);
+        assert_eq!(tab_stops.distance_to_next(29, 6), 1);
+        assert_eq!(tab_stops.distance_to_next(30, 6), 6);
+        assert_eq!(TabStops::Positions(&[]).distance_to_next(0, 6), 6);
+    }
+}
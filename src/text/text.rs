@@ -1,11 +1,14 @@
 use crate::{
     draw_target::DrawTarget,
-    geometry::{Dimensions, Point, Size},
+    geometry::{Dimensions, Point},
     pixelcolor::PixelColor,
     primitives::Rectangle,
-    text::{Alignment, Baseline, TextMetrics, TextRenderer, TextStyle},
+    text::{
+        layout::{draw_lines, measure_lines, Layout},
+        TextRenderer, TextStyle,
+    },
     transform::Transform,
-    Drawable, SaturatingCast, Styled,
+    Drawable, Styled,
 };
 
 /// A text object.
@@ -16,9 +19,15 @@ use crate::{
 /// like the font or color. To draw a text object it is necessary to attach a style to it by using
 /// the [`into_styled`] method to create a [`Styled`] object.
 ///
+/// Line breaks (`\n`) start a new line and tab characters (`\t`) advance the text to the next
+/// tab stop, which is set by the [`tab_stops`] field of a [`TextStyle`]. Soft hyphens
+/// (`U+00AD`) aren't drawn and non-breaking spaces (`U+00A0`) are drawn as regular spaces.
+///
 /// See the [module-level documentation] for examples how to use text objects.
 ///
 /// [`into_styled`]: #method.into_styled
+/// [`tab_stops`]: struct.TextStyle.html#structfield.tab_stops
+/// [`TextStyle`]: struct.TextStyle.html
 /// [`Styled`]: ../struct.Styled.html
 /// [module-level documentation]: index.html
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
@@ -59,60 +68,6 @@ impl Transform for Text<'_> {
     }
 }
 
-impl<C, S> Styled<Text<'_>, S>
-where
-    C: PixelColor,
-    S: TextRenderer<Color = C>,
-{
-    fn lines(&self) -> impl Iterator<Item = (&str, Point)> {
-        let mut position = self.primitive.position;
-
-        self.primitive.text.lines().map(move |line| {
-            let p = position;
-
-            position.y += self.style.line_height().saturating_cast();
-
-            (line, p)
-        })
-    }
-}
-
-impl<C, S> Styled<Text<'_>, TextStyle<S>>
-where
-    C: PixelColor,
-    S: TextRenderer<Color = C>,
-{
-    fn lines(&self) -> impl Iterator<Item = (&str, Point)> {
-        let mut position = self.primitive.position;
-
-        self.primitive.text.lines().map(move |line| {
-            let p = match self.style.alignment {
-                Alignment::Left => position,
-                Alignment::Right => {
-                    let metrics = self.style.character_style.measure_string(
-                        line,
-                        Point::zero(),
-                        self.style.baseline,
-                    );
-                    position - (metrics.next_position - Point::new(1, 0))
-                }
-                Alignment::Center => {
-                    let metrics = self.style.character_style.measure_string(
-                        line,
-                        Point::zero(),
-                        self.style.baseline,
-                    );
-                    position - (metrics.next_position - Point::new(1, 0)) / 2
-                }
-            };
-
-            position.y += self.style.character_style.line_height().saturating_cast();
-
-            (line, p)
-        })
-    }
-}
-
 impl<C, S> Drawable for Styled<Text<'_>, S>
 where
     C: PixelColor,
@@ -125,19 +80,17 @@ where
     where
         D: DrawTarget<Color = C>,
     {
-        let mut next_position = self.primitive.position;
-
-        for (line, position) in self.lines() {
-            next_position = self
-                .style
-                .draw_string(line, position, Baseline::Alphabetic, target)?;
-        }
-
-        Ok(next_position)
+        draw_lines(
+            self.primitive.text.lines(),
+            self.primitive.position,
+            &self.style,
+            Layout::default(),
+            target,
+        )
     }
 }
 
-impl<C, S> Drawable for Styled<Text<'_>, TextStyle<S>>
+impl<C, S> Drawable for Styled<Text<'_>, TextStyle<'_, S>>
 where
     C: PixelColor,
     S: TextRenderer<Color = C>,
@@ -149,31 +102,13 @@ where
     where
         D: DrawTarget<Color = C>,
     {
-        let mut next_position = self.primitive.position;
-
-        for (line, position) in self.lines() {
-            next_position = self.style.character_style.draw_string(
-                line,
-                position,
-                self.style.baseline,
-                target,
-            )?;
-        }
-
-        Ok(next_position)
-    }
-}
-
-fn update_min_max(min_max: &mut Option<(Point, Point)>, metrics: &TextMetrics) {
-    if let Some(bottom_right) = metrics.bounding_box.bottom_right() {
-        if let Some((min, max)) = min_max {
-            min.x = min.x.min(metrics.bounding_box.top_left.x);
-            min.y = min.y.min(metrics.bounding_box.top_left.y);
-            max.x = max.x.max(bottom_right.x);
-            max.y = max.y.max(bottom_right.y);
-        } else {
-            *min_max = Some((metrics.bounding_box.top_left, bottom_right));
-        }
+        draw_lines(
+            self.primitive.text.lines(),
+            self.primitive.position,
+            &self.style.character_style,
+            Layout::new(&self.style),
+            target,
+        )
     }
 }
 
@@ -183,44 +118,29 @@ where
     S: TextRenderer<Color = C>,
 {
     fn bounding_box(&self) -> Rectangle {
-        let mut min_max: Option<(Point, Point)> = None;
-
-        for (line, position) in self.lines() {
-            let metrics = self
-                .style
-                .measure_string(line, position, Baseline::Alphabetic);
-            update_min_max(&mut min_max, &metrics);
-        }
-
-        if let Some((min, max)) = min_max {
-            Rectangle::with_corners(min, max)
-        } else {
-            Rectangle::new(self.primitive.position, Size::zero())
-        }
+        measure_lines(
+            self.primitive.text.lines(),
+            self.primitive.position,
+            &self.style,
+            Layout::default(),
+        )
+        .bounding_box
     }
 }
 
-impl<C, S> Dimensions for Styled<Text<'_>, TextStyle<S>>
+impl<C, S> Dimensions for Styled<Text<'_>, TextStyle<'_, S>>
 where
     C: PixelColor,
     S: TextRenderer<Color = C>,
 {
     fn bounding_box(&self) -> Rectangle {
-        let mut min_max: Option<(Point, Point)> = None;
-
-        for (line, position) in self.lines() {
-            let metrics =
-                self.style
-                    .character_style
-                    .measure_string(line, position, self.style.baseline);
-            update_min_max(&mut min_max, &metrics);
-        }
-
-        if let Some((min, max)) = min_max {
-            Rectangle::with_corners(min, max)
-        } else {
-            Rectangle::new(self.primitive.position, Size::zero())
-        }
+        measure_lines(
+            self.primitive.text.lines(),
+            self.primitive.position,
+            &self.style.character_style,
+            Layout::new(&self.style),
+        )
+        .bounding_box
     }
 }
 
@@ -233,11 +153,11 @@ mod tests {
         mono_font::{
             ascii::{Font6x13, Font6x9},
             tests::assert_text_from_pattern,
-            MonoTextStyle, MonoTextStyleBuilder,
+            MonoTextStyle, MonoTextStyleBuilder, TextOrientation,
         },
         pixelcolor::BinaryColor,
         primitives::{Primitive, PrimitiveStyle},
-        text::{Alignment, Baseline, TextStyleBuilder},
+        text::{Alignment, Baseline, LineHeight, TabStops, TextStyleBuilder, VerticalAlignment},
     };
 
     const HELLO_WORLD: &'static str = "Hello World!";
@@ -702,4 +622,309 @@ mod tests {
             "#   # ####   ...  ",
         ]);
     }
+
+    fn vertical_alignment_text_style(
+        vertical_alignment: VerticalAlignment,
+        line_height: LineHeight,
+    ) -> TextStyle<'static, MonoTextStyle<BinaryColor, Font6x9>> {
+        let character_style = MonoTextStyleBuilder::new()
+            .font(Font6x9)
+            .text_color(BinaryColor::On)
+            .build();
+
+        TextStyleBuilder::new()
+            .character_style(character_style)
+            .baseline(Baseline::Top)
+            .vertical_alignment(vertical_alignment)
+            .line_height(line_height)
+            .build()
+    }
+
+    #[test]
+    fn vertical_alignment() {
+        let expected = Text::new("A\nBC", Point::zero())
+            .into_styled(vertical_alignment_text_style(
+                VerticalAlignment::Top,
+                LineHeight::default(),
+            ))
+            .bounding_box();
+
+        for &(vertical_alignment, y) in &[
+            (VerticalAlignment::Top, 0),
+            (VerticalAlignment::Middle, 4),
+            (VerticalAlignment::Bottom, 9),
+        ] {
+            let text_style =
+                vertical_alignment_text_style(vertical_alignment, LineHeight::default());
+
+            let mut expected_display = MockDisplay::new();
+            Text::new("A\nBC", Point::zero())
+                .into_styled(vertical_alignment_text_style(
+                    VerticalAlignment::Top,
+                    LineHeight::default(),
+                ))
+                .draw(&mut expected_display)
+                .unwrap();
+
+            let text = Text::new("A\nBC", Point::new(0, y)).into_styled(text_style);
+
+            let mut display = MockDisplay::new();
+            text.draw(&mut display).unwrap();
+
+            display.assert_eq(&expected_display);
+            assert_eq!(text.bounding_box(), expected, "{:?}", vertical_alignment);
+        }
+    }
+
+    #[test]
+    fn vertical_alignment_single_line() {
+        for &vertical_alignment in &[
+            VerticalAlignment::Top,
+            VerticalAlignment::Middle,
+            VerticalAlignment::Bottom,
+        ] {
+            let text_style =
+                vertical_alignment_text_style(vertical_alignment, LineHeight::default());
+
+            assert_eq!(
+                Text::new("AB", Point::new(1, 2))
+                    .into_styled(text_style)
+                    .bounding_box(),
+                Rectangle::new(Point::new(1, 2), Size::new(12, 9))
+            );
+        }
+    }
+
+    #[test]
+    fn line_height() {
+        let text_style =
+            vertical_alignment_text_style(VerticalAlignment::Top, LineHeight::Pixels(11));
+
+        let mut display = MockDisplay::new();
+        Text::new("A\nBC", Point::zero())
+            .into_styled(text_style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "            ",
+            "  #         ",
+            " # #        ",
+            "#   #       ",
+            "#####       ",
+            "#   #       ",
+            "#   #       ",
+            "            ",
+            "            ",
+            "            ",
+            "            ",
+            "            ",
+            "####    ##  ",
+            "#   #  #  # ",
+            "####   #    ",
+            "#   #  #    ",
+            "#   #  #  # ",
+            "####    ##  ",
+            "            ",
+        ]);
+    }
+
+    #[test]
+    fn line_height_percent_with_vertical_alignment() {
+        let text_style =
+            vertical_alignment_text_style(VerticalAlignment::Bottom, LineHeight::Percent(200));
+
+        let text = Text::new("A\nB\nC", Point::new(0, 40)).into_styled(text_style);
+
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::new(0, 4), Size::new(6, 2 * 18 + 9))
+        );
+    }
+
+    fn tab_stops_text_style(
+        tab_stops: TabStops<'_>,
+        alignment: Alignment,
+    ) -> TextStyle<'_, MonoTextStyle<BinaryColor, Font6x9>> {
+        let character_style = MonoTextStyleBuilder::new()
+            .font(Font6x9)
+            .text_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .build();
+
+        TextStyleBuilder::new()
+            .character_style(character_style)
+            .baseline(Baseline::Top)
+            .alignment(alignment)
+            .tab_stops(tab_stops)
+            .build()
+    }
+
+    /// Draws the given parts of text at the given x coordinates.
+    fn draw_parts(parts: &[(&str, i32)]) -> MockDisplay<BinaryColor> {
+        let style = tab_stops_text_style(TabStops::default(), Alignment::Left);
+
+        let mut display = MockDisplay::new();
+        for (text, x) in parts {
+            Text::new(text, Point::new(*x, 0))
+                .into_styled(style)
+                .draw(&mut display)
+                .unwrap();
+        }
+
+        display
+    }
+
+    #[test]
+    fn tab_stops_pixels() {
+        let style = tab_stops_text_style(TabStops::Pixels(10), Alignment::Left);
+
+        let mut display = MockDisplay::new();
+        let next = Text::new("A\tB\tC", Point::zero())
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        let mut expected = draw_parts(&[("A", 0), ("B", 10), ("C", 20)]);
+        Rectangle::new(Point::new(6, 0), Size::new(4, 9))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::Off))
+            .draw(&mut expected)
+            .unwrap();
+        Rectangle::new(Point::new(16, 0), Size::new(4, 9))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::Off))
+            .draw(&mut expected)
+            .unwrap();
+
+        display.assert_eq(&expected);
+        assert_eq!(next, Point::new(26, 0));
+    }
+
+    #[test]
+    fn tab_stops_positions() {
+        let style = tab_stops_text_style(TabStops::Positions(&[8, 30]), Alignment::Left);
+
+        let text = Text::new("A\tB\tC\tD", Point::zero()).into_styled(style);
+
+        // The last tab is after the last stop and is replaced by a single space.
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::zero(), Size::new(30 + 6 + 6 + 6, 9))
+        );
+    }
+
+    #[test]
+    fn tab_stops_runtime_positions() {
+        let mut positions = [0; 2];
+        for (stop, position) in (1..).zip(positions.iter_mut()) {
+            *position = stop * 15;
+        }
+
+        let style = tab_stops_text_style(TabStops::Positions(&positions), Alignment::Left);
+
+        let text = Text::new("A\tB\tC", Point::zero()).into_styled(style);
+
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::zero(), Size::new(30 + 6, 9))
+        );
+    }
+
+    #[test]
+    fn tab_stops_spaces() {
+        let style = tab_stops_text_style(TabStops::Spaces(2), Alignment::Left);
+
+        let text = Text::new("ABC\tD", Point::zero()).into_styled(style);
+
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::zero(), Size::new(4 * 6 + 6, 9))
+        );
+    }
+
+    #[test]
+    fn tab_stops_right_aligned() {
+        let style = tab_stops_text_style(TabStops::Pixels(10), Alignment::Right);
+
+        let text = Text::new("A\tB\nCD", Point::new(15, 0)).into_styled(style);
+
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::new(0, 0), Size::new(16, 18))
+        );
+    }
+
+    #[test]
+    fn tab_without_text_style() {
+        let style = MonoTextStyleBuilder::new()
+            .font(Font6x9)
+            .text_color(BinaryColor::On)
+            .build();
+
+        let text = Text::new("A\tB", Point::new(0, 6)).into_styled(style);
+
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::zero(), Size::new(4 * 6 + 6, 9))
+        );
+    }
+
+    #[test]
+    fn soft_hyphen_is_removed() {
+        let style = tab_stops_text_style(TabStops::default(), Alignment::Left);
+
+        let mut display = MockDisplay::new();
+        Text::new("A\u{ad}B", Point::zero())
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&draw_parts(&[("AB", 0)]));
+    }
+
+    #[test]
+    fn no_break_space() {
+        let style = tab_stops_text_style(TabStops::default(), Alignment::Left);
+
+        let mut display = MockDisplay::new();
+        Text::new("A\u{a0}B", Point::zero())
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&draw_parts(&[("A B", 0)]));
+    }
+
+    #[test]
+    fn rotated_tab_and_space() {
+        let character_style = MonoTextStyleBuilder::new()
+            .font(Font6x9)
+            .text_color(BinaryColor::On)
+            .orientation(TextOrientation::TopToBottom)
+            .build();
+        let style = TextStyleBuilder::new()
+            .character_style(character_style)
+            .baseline(Baseline::Top)
+            .tab_stops(TabStops::Pixels(12))
+            .build();
+
+        let text = Text::new("A\tB C", Point::new(10, 0)).into_styled(style);
+
+        let mut display = MockDisplay::new();
+        let next = text.draw(&mut display).unwrap();
+
+        let mut expected = MockDisplay::new();
+        for (part, y) in &[("A", 0), ("B", 12), ("C", 24)] {
+            Text::new(part, Point::new(10, *y))
+                .into_styled(style)
+                .draw(&mut expected)
+                .unwrap();
+        }
+
+        display.assert_eq(&expected);
+        assert_eq!(next, Point::new(10, 30));
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::new(2, 0), Size::new(9, 30))
+        );
+    }
 }
@@ -1,10 +1,10 @@
 use embedded_graphics_core::text::CharacterStyle;
 
-use crate::text::{Alignment, Baseline, TextRenderer};
+use crate::text::{Alignment, Baseline, LineHeight, TabStops, TextRenderer, VerticalAlignment};
 
 /// Text style.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct TextStyle<S> {
+pub struct TextStyle<'a, S> {
     /// Character style.
     pub character_style: S,
 
@@ -13,17 +13,26 @@ pub struct TextStyle<S> {
 
     /// Text baseline.
     pub baseline: Baseline,
+
+    /// Vertical alignment of multi-line text.
+    pub vertical_alignment: VerticalAlignment,
+
+    /// Line height.
+    pub line_height: LineHeight,
+
+    /// Tab stops.
+    pub tab_stops: TabStops<'a>,
 }
 
-impl<S> TextStyle<S> {}
+impl<S> TextStyle<'_, S> {}
 
 /// Text style builder.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct TextStyleBuilder<S> {
-    style: TextStyle<S>,
+pub struct TextStyleBuilder<'a, S> {
+    style: TextStyle<'a, S>,
 }
 
-impl TextStyleBuilder<UndefinedCharacterStyle> {
+impl TextStyleBuilder<'_, UndefinedCharacterStyle> {
     /// Creates a new text style builder.
     pub fn new() -> Self {
         Self {
@@ -31,12 +40,15 @@ impl TextStyleBuilder<UndefinedCharacterStyle> {
                 character_style: UndefinedCharacterStyle,
                 alignment: Alignment::Left,
                 baseline: Baseline::Alphabetic,
+                vertical_alignment: VerticalAlignment::Top,
+                line_height: LineHeight::default(),
+                tab_stops: TabStops::default(),
             },
         }
     }
 }
 
-impl<S> TextStyleBuilder<S> {
+impl<'a, S> TextStyleBuilder<'a, S> {
     /// Sets the horizontal text alignment.
     pub fn alignment(mut self, alignment: Alignment) -> Self {
         self.style.alignment = alignment;
@@ -51,24 +63,50 @@ impl<S> TextStyleBuilder<S> {
         self
     }
 
+    /// Sets the vertical alignment of multi-line text.
+    pub fn vertical_alignment(mut self, vertical_alignment: VerticalAlignment) -> Self {
+        self.style.vertical_alignment = vertical_alignment;
+
+        self
+    }
+
+    /// Sets the line height.
+    ///
+    /// The line height overrides the default line height of the character style.
+    pub fn line_height(mut self, line_height: LineHeight) -> Self {
+        self.style.line_height = line_height;
+
+        self
+    }
+
+    /// Sets the tab stops.
+    pub fn tab_stops(mut self, tab_stops: TabStops<'a>) -> Self {
+        self.style.tab_stops = tab_stops;
+
+        self
+    }
+
     /// Sets the character style.
-    pub fn character_style<Style>(self, character_style: Style) -> TextStyleBuilder<Style> {
+    pub fn character_style<Style>(self, character_style: Style) -> TextStyleBuilder<'a, Style> {
         TextStyleBuilder {
             style: TextStyle {
                 character_style,
                 alignment: self.style.alignment,
                 baseline: self.style.baseline,
+                vertical_alignment: self.style.vertical_alignment,
+                line_height: self.style.line_height,
+                tab_stops: self.style.tab_stops,
             },
         }
     }
 }
 
-impl<S> TextStyleBuilder<S>
+impl<'a, S> TextStyleBuilder<'a, S>
 where
     S: CharacterStyle + TextRenderer,
 {
     /// Builds the text style.
-    pub fn build(self) -> TextStyle<S> {
+    pub fn build(self) -> TextStyle<'a, S> {
         self.style
     }
 }
@@ -102,4 +140,22 @@ mod tests {
         assert_eq!(text_style.alignment, Alignment::Right);
         assert_eq!(text_style.baseline, Baseline::Top);
     }
+
+    #[test]
+    fn builder_line_layout() {
+        let character_style = MonoTextStyleBuilder::<BinaryColor, _>::new()
+            .font(Font6x9)
+            .build();
+
+        let text_style = TextStyleBuilder::new()
+            .vertical_alignment(VerticalAlignment::Bottom)
+            .line_height(LineHeight::Pixels(12))
+            .tab_stops(TabStops::Pixels(20))
+            .character_style(character_style)
+            .build();
+
+        assert_eq!(text_style.vertical_alignment, VerticalAlignment::Bottom);
+        assert_eq!(text_style.line_height, LineHeight::Pixels(12));
+        assert_eq!(text_style.tab_stops, TabStops::Pixels(20));
+    }
 }
@@ -0,0 +1,536 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point},
+    pixelcolor::PixelColor,
+    primitives::Rectangle,
+    text::{
+        layout::{distance, draw_lines, measure_lines, Layout},
+        Alignment, TextMetrics, TextRenderer, TextStyle,
+    },
+    transform::Transform,
+    Drawable, Styled,
+};
+use core::iter;
+
+/// Position of the ellipsis in a truncated text.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Truncation {
+    /// The start of the text is replaced by the ellipsis.
+    Start,
+    /// The middle of the text is replaced by the ellipsis.
+    Middle,
+    /// The end of the text is replaced by the ellipsis.
+    End,
+}
+
+/// A single line of text which is truncated to a maximum width.
+///
+/// If the text is wider than `max_width` pixels, characters are removed from the text and replaced
+/// by an ellipsis until the text fits. The position of the removed characters is set by the
+/// [`Truncation`] mode. If not even the ellipsis fits into the maximum width, the ellipsis itself
+/// is truncated.
+///
+/// All widths are measured with [`TextRenderer::measure_string`], which makes truncated text
+/// usable with any text renderer. Drawing a truncated text returns the [`TextMetrics`] of the drawn
+/// text.
+///
+/// The text is always drawn as a single line. Tabs, soft hyphens and non-breaking spaces are
+/// interpreted in the same way as in [`Text`] and are taken into account when the text is
+/// truncated.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     mono_font::{ascii::Font6x9, MonoTextStyle},
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     text::{TruncatedText, Truncation},
+/// };
+/// # let mut display = embedded_graphics::mock_display::MockDisplay::new();
+///
+/// let style = MonoTextStyle::new(Font6x9, BinaryColor::On);
+///
+/// let metrics = TruncatedText::new("Network settings", Point::new(0, 6), 60)
+///     .truncation(Truncation::Middle)
+///     .into_styled(style)
+///     .draw(&mut display)?;
+///
+/// assert_eq!(metrics.bounding_box.size, Size::new(60, 9));
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Truncation`]: enum.Truncation.html
+/// [`Text`]: struct.Text.html
+/// [`TextRenderer::measure_string`]: trait.TextRenderer.html#tymethod.measure_string
+/// [`TextMetrics`]: struct.TextMetrics.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct TruncatedText<'a> {
+    /// The string.
+    pub text: &'a str,
+
+    /// The position.
+    pub position: Point,
+
+    /// The maximum width in pixels.
+    pub max_width: u32,
+
+    /// The truncation mode.
+    pub truncation: Truncation,
+
+    /// The string which replaces the removed characters.
+    pub ellipsis: &'a str,
+}
+
+impl<'a> TruncatedText<'a> {
+    /// Creates a truncated text.
+    ///
+    /// The text is truncated at the end and `...` is used as the ellipsis.
+    pub const fn new(text: &'a str, position: Point, max_width: u32) -> Self {
+        Self {
+            text,
+            position,
+            max_width,
+            truncation: Truncation::End,
+            ellipsis: "...",
+        }
+    }
+
+    /// Sets the truncation mode.
+    pub fn truncation(mut self, truncation: Truncation) -> Self {
+        self.truncation = truncation;
+
+        self
+    }
+
+    /// Sets the ellipsis.
+    pub fn ellipsis(mut self, ellipsis: &'a str) -> Self {
+        self.ellipsis = ellipsis;
+
+        self
+    }
+
+    /// Attaches a text style to the truncated text object.
+    pub fn into_styled<S>(self, style: S) -> Styled<Self, S> {
+        Styled::new(self, style)
+    }
+
+    /// Returns the parts of the truncated text.
+    ///
+    /// The visible text is the concatenation of the returned parts.
+    fn parts<S>(&self, renderer: &S, layout: Layout<'_>) -> [&'a str; 3]
+    where
+        S: TextRenderer,
+    {
+        let layout = Layout {
+            alignment: Alignment::Left,
+            ..layout
+        };
+
+        // The parts are measured together, to include the character spacing between them. The
+        // width is the length of the advance, which is vertical for rotated text.
+        let width = |parts: &[&str]| {
+            let metrics = measure_lines(iter::once(parts), Point::zero(), renderer, layout);
+
+            distance(Point::zero(), metrics.next_position).max(0) as u32
+        };
+
+        if width(&[self.text]) <= self.max_width {
+            return [self.text, "", ""];
+        }
+
+        let ellipsis_width = width(&[self.ellipsis]);
+        if ellipsis_width > self.max_width {
+            let ellipsis =
+                longest_prefix(self.ellipsis, |prefix| width(&[prefix]) <= self.max_width);
+
+            return [ellipsis, "", ""];
+        }
+
+        match self.truncation {
+            Truncation::Start => {
+                let suffix = longest_suffix(self.text, |suffix| {
+                    width(&[self.ellipsis, suffix]) <= self.max_width
+                });
+
+                ["", self.ellipsis, suffix]
+            }
+            Truncation::Middle => {
+                let available = self.max_width - ellipsis_width;
+                let prefix = longest_prefix(self.text, |prefix| {
+                    width(&[prefix, self.ellipsis]) <= self.max_width - available / 2
+                });
+                let suffix = longest_suffix(&self.text[prefix.len()..], |suffix| {
+                    width(&[prefix, self.ellipsis, suffix]) <= self.max_width
+                });
+
+                [prefix, self.ellipsis, suffix]
+            }
+            Truncation::End => {
+                let prefix = longest_prefix(self.text, |prefix| {
+                    width(&[prefix, self.ellipsis]) <= self.max_width
+                });
+
+                [prefix, self.ellipsis, ""]
+            }
+        }
+    }
+}
+
+impl Transform for TruncatedText<'_> {
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            position: self.position + by,
+            ..*self
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.position += by;
+
+        self
+    }
+}
+
+/// Returns the longest prefix of `text` which fits.
+fn longest_prefix<F>(text: &str, fits: F) -> &str
+where
+    F: Fn(&str) -> bool,
+{
+    text.char_indices()
+        .map(|(index, _)| index)
+        .skip(1)
+        .chain(iter::once(text.len()))
+        .take_while(|&end| fits(&text[..end]))
+        .last()
+        .map_or("", |end| &text[..end])
+}
+
+/// Returns the longest suffix of `text` which fits.
+fn longest_suffix<F>(text: &str, fits: F) -> &str
+where
+    F: Fn(&str) -> bool,
+{
+    text.char_indices()
+        .map(|(index, _)| index)
+        .find(|&start| fits(&text[start..]))
+        .map_or("", |start| &text[start..])
+}
+
+impl<C, S> Drawable for Styled<TruncatedText<'_>, S>
+where
+    C: PixelColor,
+    S: TextRenderer<Color = C>,
+{
+    type Color = C;
+    type Output = TextMetrics;
+
+    fn draw<D>(&self, target: &mut D) -> Result<TextMetrics, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        draw(&self.primitive, &self.style, Layout::default(), target)
+    }
+}
+
+impl<C, S> Drawable for Styled<TruncatedText<'_>, TextStyle<'_, S>>
+where
+    C: PixelColor,
+    S: TextRenderer<Color = C>,
+{
+    type Color = C;
+    type Output = TextMetrics;
+
+    fn draw<D>(&self, target: &mut D) -> Result<TextMetrics, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        draw(
+            &self.primitive,
+            &self.style.character_style,
+            Layout::new(&self.style),
+            target,
+        )
+    }
+}
+
+impl<C, S> Dimensions for Styled<TruncatedText<'_>, S>
+where
+    C: PixelColor,
+    S: TextRenderer<Color = C>,
+{
+    fn bounding_box(&self) -> Rectangle {
+        measure(&self.primitive, &self.style, Layout::default()).bounding_box
+    }
+}
+
+impl<C, S> Dimensions for Styled<TruncatedText<'_>, TextStyle<'_, S>>
+where
+    C: PixelColor,
+    S: TextRenderer<Color = C>,
+{
+    fn bounding_box(&self) -> Rectangle {
+        measure(
+            &self.primitive,
+            &self.style.character_style,
+            Layout::new(&self.style),
+        )
+        .bounding_box
+    }
+}
+
+fn draw<S, D>(
+    truncated_text: &TruncatedText<'_>,
+    renderer: &S,
+    layout: Layout<'_>,
+    target: &mut D,
+) -> Result<TextMetrics, D::Error>
+where
+    S: TextRenderer,
+    D: DrawTarget<Color = S::Color>,
+{
+    let parts = truncated_text.parts(renderer, layout);
+
+    draw_lines(
+        iter::once(&parts[..]),
+        truncated_text.position,
+        renderer,
+        layout,
+        target,
+    )?;
+
+    Ok(measure_lines(
+        iter::once(&parts[..]),
+        truncated_text.position,
+        renderer,
+        layout,
+    ))
+}
+
+fn measure<S>(truncated_text: &TruncatedText<'_>, renderer: &S, layout: Layout<'_>) -> TextMetrics
+where
+    S: TextRenderer,
+{
+    let parts = truncated_text.parts(renderer, layout);
+
+    measure_lines(
+        iter::once(&parts[..]),
+        truncated_text.position,
+        renderer,
+        layout,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Size,
+        mock_display::MockDisplay,
+        mono_font::{
+            ascii::Font6x9, MonoFont, MonoTextStyle, MonoTextStyleBuilder, TextOrientation,
+        },
+        pixelcolor::BinaryColor,
+        text::{Baseline, Text, TextStyleBuilder},
+    };
+
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    struct SpacedFont;
+
+    impl MonoFont for SpacedFont {
+        const FONT_IMAGE: &'static [u8] = Font6x9::FONT_IMAGE;
+        const FONT_IMAGE_WIDTH: u32 = Font6x9::FONT_IMAGE_WIDTH;
+        const CHARACTER_SIZE: Size = Font6x9::CHARACTER_SIZE;
+        const CHARACTER_SPACING: u32 = 2;
+
+        fn char_offset(c: char) -> u32 {
+            Font6x9::char_offset(c)
+        }
+    }
+
+    fn text_style(alignment: Alignment) -> TextStyle<'static, MonoTextStyle<BinaryColor, Font6x9>> {
+        let character_style = MonoTextStyleBuilder::new()
+            .font(Font6x9)
+            .text_color(BinaryColor::On)
+            .build();
+
+        TextStyleBuilder::new()
+            .character_style(character_style)
+            .alignment(alignment)
+            .baseline(Baseline::Top)
+            .build()
+    }
+
+    fn visible_text<'a>(truncated_text: TruncatedText<'a>) -> [&'a str; 3] {
+        let style = text_style(Alignment::Left);
+
+        truncated_text.parts(&style.character_style, Layout::new(&style))
+    }
+
+    #[test]
+    fn fits() {
+        let text = TruncatedText::new("ABCDE", Point::zero(), 30);
+
+        assert_eq!(visible_text(text), ["ABCDE", "", ""]);
+    }
+
+    #[test]
+    fn truncation_modes() {
+        let text = TruncatedText::new("ABCDEFGHIJ", Point::zero(), 40);
+
+        assert_eq!(
+            visible_text(text.truncation(Truncation::End)),
+            ["ABC", "...", ""]
+        );
+        assert_eq!(
+            visible_text(text.truncation(Truncation::Start)),
+            ["", "...", "HIJ"]
+        );
+        assert_eq!(
+            visible_text(text.truncation(Truncation::Middle)),
+            ["A", "...", "IJ"]
+        );
+        assert_eq!(
+            visible_text(text.truncation(Truncation::Middle).ellipsis("~")),
+            ["AB", "~", "HIJ"]
+        );
+    }
+
+    #[test]
+    fn ellipsis_too_wide() {
+        let text = TruncatedText::new("ABCDEFGHIJ", Point::zero(), 13);
+
+        assert_eq!(visible_text(text), ["..", "", ""]);
+    }
+
+    #[test]
+    fn draw_matches_text() {
+        let style = text_style(Alignment::Left);
+
+        let mut display = MockDisplay::new();
+        let metrics = TruncatedText::new("ABCDEFGHIJ", Point::new(1, 2), 40)
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        let mut expected = MockDisplay::new();
+        let text = Text::new("ABC...", Point::new(1, 2)).into_styled(style);
+        let next_position = text.draw(&mut expected).unwrap();
+
+        display.assert_eq(&expected);
+        assert_eq!(
+            metrics,
+            TextMetrics {
+                bounding_box: text.bounding_box(),
+                next_position,
+            }
+        );
+        assert_eq!(metrics.bounding_box.size, Size::new(36, 9));
+    }
+
+    #[test]
+    fn alignment() {
+        for &alignment in &[Alignment::Left, Alignment::Center, Alignment::Right] {
+            let style = text_style(alignment);
+
+            let truncated = TruncatedText::new("ABCDEFGHIJ", Point::new(30, 0), 30)
+                .truncation(Truncation::Start)
+                .into_styled(style);
+
+            let mut display = MockDisplay::new();
+            let metrics = truncated.draw(&mut display).unwrap();
+
+            let mut expected = MockDisplay::new();
+            let text = Text::new("...IJ", Point::new(30, 0)).into_styled(style);
+            text.draw(&mut expected).unwrap();
+
+            display.assert_eq(&expected);
+            assert_eq!(metrics.bounding_box, text.bounding_box(), "{:?}", alignment);
+            assert_eq!(truncated.bounding_box(), text.bounding_box());
+        }
+    }
+
+    #[test]
+    fn without_text_style() {
+        let style = MonoTextStyle::new(Font6x9, BinaryColor::On);
+
+        let truncated = TruncatedText::new("ABCDEFGHIJ", Point::new(0, 6), 40).into_styled(style);
+        let text = Text::new("ABC...", Point::new(0, 6)).into_styled(style);
+
+        assert_eq!(truncated.bounding_box(), text.bounding_box());
+    }
+
+    #[test]
+    fn character_spacing() {
+        let character_style = MonoTextStyleBuilder::new()
+            .font(SpacedFont)
+            .text_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .build();
+
+        let style = TextStyleBuilder::new()
+            .character_style(character_style)
+            .baseline(Baseline::Top)
+            .build();
+
+        for &(truncation, expected_text) in &[
+            (Truncation::End, "ABCD..."),
+            (Truncation::Start, "...GHIJ"),
+            (Truncation::Middle, "AB...IJ"),
+        ] {
+            let truncated = TruncatedText::new("ABCDEFGHIJ", Point::zero(), 60)
+                .truncation(truncation)
+                .into_styled(style);
+
+            let mut display = MockDisplay::new();
+            let metrics = truncated.draw(&mut display).unwrap();
+
+            let mut expected = MockDisplay::new();
+            let text = Text::new(expected_text, Point::zero()).into_styled(style);
+            let next_position = text.draw(&mut expected).unwrap();
+
+            display.assert_eq(&expected);
+            assert_eq!(
+                metrics,
+                TextMetrics {
+                    bounding_box: text.bounding_box(),
+                    next_position,
+                }
+            );
+            assert_eq!(metrics.bounding_box.size, Size::new(54, 9));
+        }
+    }
+
+    #[test]
+    fn rotated() {
+        let character_style = MonoTextStyleBuilder::new()
+            .font(Font6x9)
+            .text_color(BinaryColor::On)
+            .orientation(TextOrientation::TopToBottom)
+            .build();
+
+        let style = TextStyleBuilder::new()
+            .character_style(character_style)
+            .baseline(Baseline::Top)
+            .build();
+
+        let truncated = TruncatedText::new("ABCDEFGHIJ", Point::new(10, 0), 42).into_styled(style);
+
+        let mut display = MockDisplay::new();
+        let metrics = truncated.draw(&mut display).unwrap();
+
+        let mut expected = MockDisplay::new();
+        let text = Text::new("ABCD...", Point::new(10, 0)).into_styled(style);
+        let next_position = text.draw(&mut expected).unwrap();
+
+        display.assert_eq(&expected);
+        assert_eq!(next_position, Point::new(10, 42));
+        assert_eq!(
+            metrics,
+            TextMetrics {
+                bounding_box: text.bounding_box(),
+                next_position,
+            }
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use ab_glyph::{point, Font, FontRef, PxScale, ScaleFont};
+use anyhow::{anyhow, bail, Result};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Encoding {
+    Ascii,
+    Latin1,
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::result::Result<(), fmt::Error> {
+        match self {
+            Self::Ascii => f.write_str("ascii"),
+            Self::Latin1 => f.write_str("latin1"),
+        }
+    }
+}
+
+/// Number of glyphs per row in the generated image.
+const COLUMNS: usize = 16;
+
+/// Rasterizes a TTF or OTF font into the `GrayMonoFont` format.
+///
+/// `bits_per_pixel` must be 2 or 4.
+pub fn ttf_to_bitmap(
+    font_data: &[u8],
+    pixel_size: f32,
+    bits_per_pixel: u32,
+    encoding: Encoding,
+) -> Result<Bitmap> {
+    if bits_per_pixel != 2 && bits_per_pixel != 4 {
+        bail!("unsupported number of bits per pixel: {}", bits_per_pixel);
+    }
+
+    let font = FontRef::try_from_slice(font_data).map_err(|_| anyhow!("couldn't parse font"))?;
+    let font = font.as_scaled(PxScale::from(pixel_size));
+
+    let rows: Vec<u32> = match encoding {
+        Encoding::Ascii => (0x20..=0x7F).step_by(COLUMNS).collect(),
+        Encoding::Latin1 => (0x20..=0x7F).chain(0xA0..=0xFF).step_by(COLUMNS).collect(),
+    };
+    let chars = rows
+        .iter()
+        .flat_map(|start| {
+            (*start..*start + COLUMNS as u32).map(|c| std::char::from_u32(c).unwrap())
+        })
+        .collect::<Vec<_>>();
+
+    let fallback_glyph = font.glyph_id('?');
+    if fallback_glyph.0 == 0 {
+        bail!("font doesn't contain the fallback '?' glyph");
+    }
+
+    // Monospaced fonts use the same advance for all glyphs, but the maximum is used to make sure
+    // that proportional fonts don't overlap.
+    let glyph_width = chars
+        .iter()
+        .map(|c| font.h_advance(font.glyph_id(*c)))
+        .fold(0.0f32, f32::max)
+        .ceil() as usize;
+
+    let ascent = font.ascent().ceil();
+    let glyph_height = (ascent - font.descent().floor()) as usize;
+
+    let bitmap_width = glyph_width * COLUMNS;
+    let bitmap_height = glyph_height * rows.len();
+    let mut coverage = vec![0.0f32; bitmap_width * bitmap_height];
+
+    for (index, c) in chars.iter().enumerate() {
+        let id = match font.glyph_id(*c) {
+            id if id.0 == 0 => fallback_glyph,
+            id => id,
+        };
+
+        let origin_x = (index % COLUMNS) * glyph_width;
+        let origin_y = (index / COLUMNS) * glyph_height;
+
+        let glyph = id.with_scale_and_position(font.scale(), point(0.0, ascent));
+
+        let outline = match font.outline_glyph(glyph) {
+            Some(outline) => outline,
+            // Glyphs without an outline, like space, are left empty.
+            None => continue,
+        };
+
+        let bounds = outline.px_bounds();
+        outline.draw(|x, y, value| {
+            let x = bounds.min.x as i32 + x as i32;
+            let y = bounds.min.y as i32 + y as i32;
+
+            if x >= 0 && y >= 0 && (x as usize) < glyph_width && (y as usize) < glyph_height {
+                let index = origin_x + x as usize + (origin_y + y as usize) * bitmap_width;
+                coverage[index] = coverage[index].max(value);
+            }
+        });
+    }
+
+    let (data, width) = pack(&coverage, bitmap_width, bits_per_pixel);
+
+    Ok(Bitmap {
+        data,
+        width,
+        height: bitmap_height,
+        glyph_width,
+        glyph_height,
+        bits_per_pixel,
+        baseline: ascent as usize - 1,
+    })
+}
+
+/// Quantizes a coverage value in the range `0.0..=1.0` to `bits_per_pixel` bits.
+fn quantize(coverage: f32, bits_per_pixel: u32) -> u8 {
+    let max_value = (1u32 << bits_per_pixel) - 1;
+
+    (coverage.clamp(0.0, 1.0) * max_value as f32).round() as u8
+}
+
+/// Packs coverage values into bytes, starting with the most significant bits.
+///
+/// Each image row is padded to a whole number of bytes. Returns the packed data and the padded
+/// image width in pixels.
+fn pack(coverage: &[f32], width: usize, bits_per_pixel: u32) -> (Vec<u8>, usize) {
+    let pixels_per_byte = 8 / bits_per_pixel as usize;
+    let height = coverage.len() / width;
+
+    let bytes_per_row = (width + pixels_per_byte - 1) / pixels_per_byte;
+    let mut data = vec![0u8; bytes_per_row * height];
+
+    for y in 0..height {
+        for x in 0..width {
+            let value = quantize(coverage[x + y * width], bits_per_pixel);
+            let shift = 8 - bits_per_pixel as usize * (x % pixels_per_byte + 1);
+
+            data[x / pixels_per_byte + y * bytes_per_row] |= value << shift;
+        }
+    }
+
+    (data, bytes_per_row * pixels_per_byte)
+}
+
+pub struct Bitmap {
+    pub data: Vec<u8>,
+    pub width: usize,
+    pub height: usize,
+    pub glyph_width: usize,
+    pub glyph_height: usize,
+    pub bits_per_pixel: u32,
+    pub baseline: usize,
+}
+
+impl Bitmap {
+    /// Returns the coverage of a pixel.
+    pub fn pixel(&self, x: usize, y: usize) -> u8 {
+        let pixels_per_byte = 8 / self.bits_per_pixel as usize;
+        let shift = 8 - self.bits_per_pixel as usize * (x % pixels_per_byte + 1);
+        let mask = (1u8 << self.bits_per_pixel) - 1;
+
+        (self.data[x / pixels_per_byte + y * (self.width / pixels_per_byte)] >> shift) & mask
+    }
+
+    /// Returns a Rust source snippet which implements `GrayMonoFont` for this bitmap.
+    ///
+    /// The raw data is expected to be stored in `raw_file`, relative to the generated file.
+    pub fn rust_struct(&self, type_name: &str, raw_file: &str, encoding: Encoding) -> String {
+        let coverage_depth = match self.bits_per_pixel {
+            2 => "Bits2",
+            _ => "Bits4",
+        };
+
+        let latin1_range = match encoding {
+            Encoding::Ascii => "",
+            Encoding::Latin1 => "\n            0xA0..=0xFF => c - 0xA0 + 0x60,",
+        };
+
+        format!(
+            include_str!("../assets/font.tmpl"),
+            char_width = self.glyph_width,
+            char_height = self.glyph_height,
+            type_name = type_name,
+            raw_file = raw_file,
+            image_width = self.width,
+            coverage_depth = coverage_depth,
+            baseline = self.baseline,
+            latin1_range = latin1_range,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantize_coverage() {
+        assert_eq!(quantize(0.0, 2), 0);
+        assert_eq!(quantize(0.16, 2), 0);
+        assert_eq!(quantize(0.17, 2), 1);
+        assert_eq!(quantize(0.5, 2), 2);
+        assert_eq!(quantize(1.0, 2), 3);
+
+        assert_eq!(quantize(0.0, 4), 0);
+        assert_eq!(quantize(0.5, 4), 8);
+        assert_eq!(quantize(0.97, 4), 15);
+        assert_eq!(quantize(1.0, 4), 15);
+
+        // Out of range values, which can be caused by overlapping contours, are clamped.
+        assert_eq!(quantize(-0.1, 4), 0);
+        assert_eq!(quantize(1.2, 4), 15);
+    }
+
+    #[test]
+    fn pack_2_bits() {
+        let coverage = [
+            0.0, 0.33, 0.67, 1.0, 1.0, //
+            1.0, 0.0, 0.0, 0.0, 0.67, //
+        ];
+
+        let (data, width) = pack(&coverage, 5, 2);
+
+        assert_eq!(width, 8);
+        assert_eq!(
+            data,
+            [0b00_01_10_11, 0b11_00_00_00, 0b11_00_00_00, 0b10_00_00_00]
+        );
+    }
+
+    #[test]
+    fn pack_4_bits() {
+        let coverage = [
+            0.0, 0.2, 1.0, //
+            0.6, 1.0, 0.0, //
+        ];
+
+        let (data, width) = pack(&coverage, 3, 4);
+
+        assert_eq!(width, 4);
+        assert_eq!(data, [0x03, 0xF0, 0x9F, 0x00]);
+    }
+
+    #[test]
+    fn pixel_reads_packed_data() {
+        let coverage = [
+            0.0, 0.2, 1.0, 0.4, 0.8, //
+            0.6, 1.0, 0.0, 0.2, 0.4, //
+        ];
+
+        for &bits_per_pixel in &[2, 4] {
+            let (data, width) = pack(&coverage, 5, bits_per_pixel);
+            let bitmap = Bitmap {
+                data,
+                width,
+                height: 2,
+                glyph_width: 5,
+                glyph_height: 2,
+                bits_per_pixel,
+                baseline: 1,
+            };
+
+            for y in 0..2 {
+                for x in 0..5 {
+                    assert_eq!(
+                        bitmap.pixel(x, y),
+                        quantize(coverage[x + y * 5], bits_per_pixel),
+                        "x={}, y={}, bpp={}",
+                        x,
+                        y,
+                        bits_per_pixel
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unsupported_bits_per_pixel() {
+        assert!(ttf_to_bitmap(&[], 10.0, 3, Encoding::Ascii).is_err());
+    }
+}
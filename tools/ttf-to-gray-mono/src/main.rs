@@ -0,0 +1,54 @@
+use anyhow::{anyhow, bail, Context, Result};
+use std::{fs, path::Path};
+use ttf_to_gray_mono::{ttf_to_bitmap, Encoding};
+
+const USAGE: &str =
+    "usage: ttf-to-gray-mono <FONT FILE> <PIXEL SIZE> <OUTPUT NAME> [2|4] [ascii|latin1]";
+
+fn main() -> Result<()> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.len() < 3 || args.len() > 5 {
+        bail!(USAGE);
+    }
+
+    let font_file = &args[0];
+    let pixel_size = args[1]
+        .parse::<f32>()
+        .map_err(|_| anyhow!("invalid pixel size: {}", args[1]))?;
+    let name = &args[2];
+    let bits_per_pixel = match args.get(3).map(String::as_str) {
+        None | Some("4") => 4,
+        Some("2") => 2,
+        Some(other) => bail!("invalid number of bits per pixel: {}", other),
+    };
+    let encoding = match args.get(4).map(String::as_str) {
+        None | Some("ascii") => Encoding::Ascii,
+        Some("latin1") => Encoding::Latin1,
+        Some(other) => bail!("invalid encoding: {}", other),
+    };
+
+    let font_data = fs::read(font_file).with_context(|| format!("couldn't read {}", font_file))?;
+    let bitmap = ttf_to_bitmap(&font_data, pixel_size, bits_per_pixel, encoding)?;
+
+    let raw_file = Path::new(name).with_extension("raw");
+    fs::write(&raw_file, &bitmap.data)?;
+
+    let rs_file = Path::new(name).with_extension("rs");
+    let type_name = format!("Font{}", name);
+    let raw_file_name = raw_file.file_name().unwrap().to_string_lossy();
+    fs::write(
+        &rs_file,
+        bitmap.rust_struct(&type_name, &raw_file_name, encoding),
+    )?;
+
+    println!(
+        "Generated {}x{} pixel font {} in {} and {}",
+        bitmap.glyph_width,
+        bitmap.glyph_height,
+        type_name,
+        rs_file.display(),
+        raw_file.display()
+    );
+
+    Ok(())
+}